@@ -8,15 +8,17 @@ use std::{
 use unicode_segmentation::GraphemeCursor;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use fxhash::FxHashSet;
 use typed_builder::*;
 
 use data_farmer::*;
 use data_harvester::temperature;
+use keybindings::{Action, KeyBindings, KeyChord, KeyLookup};
 use layout_manager::*;
 pub use states::*;
 
 use crate::{
-    components::text_table::SortState,
+    components::text_table::{SortState, TableComponentState},
     constants,
     data_conversion::ConvertedData,
     options::Config,
@@ -31,8 +33,11 @@ use self::widgets::{ProcWidget, ProcWidgetMode};
 
 pub mod data_farmer;
 pub mod data_harvester;
+pub mod keybindings;
 pub mod layout_manager;
+mod process_affinity;
 mod process_killer;
+mod process_priority;
 pub mod query;
 pub mod states;
 pub mod widgets;
@@ -65,11 +70,14 @@ pub struct AppConfigFields {
     pub disable_click: bool,
     pub no_write: bool,
     pub show_table_scroll_position: bool,
+    pub show_pss: bool,
     pub is_advanced_kill: bool,
     // TODO: Remove these, move network details state-side.
     pub network_unit_type: DataUnit,
     pub network_scale_type: AxisScaling,
     pub network_use_binary_prefix: bool,
+    /// The user's own warning and critical temperatures, from the `temp_threshold` config entries.
+    pub temp_thresholds: Vec<temperature::TempThreshold>,
 }
 
 /// For filtering out information
@@ -79,6 +87,7 @@ pub struct DataFilters {
     pub mount_filter: Option<Filter>,
     pub temp_filter: Option<Filter>,
     pub net_filter: Option<Filter>,
+    pub sensor_filter: Option<Filter>,
 }
 
 #[derive(Debug, Clone)]
@@ -89,11 +98,9 @@ pub struct Filter {
 
 #[derive(TypedBuilder)]
 pub struct App {
-    #[builder(default = false, setter(skip))]
-    awaiting_second_char: bool,
-
+    /// The first key of a key binding sequence like `gg`, if it was just pressed.
     #[builder(default, setter(skip))]
-    second_char: Option<char>,
+    pending_key: Option<KeyChord>,
 
     #[builder(default, setter(skip))]
     pub dd_err: Option<String>,
@@ -101,9 +108,16 @@ pub struct App {
     #[builder(default, setter(skip))]
     to_delete_process_list: Option<(String, Vec<Pid>)>,
 
+    /// Why the priority or affinity dialog failed, which is shown in place of the dialog until it is closed.
+    #[builder(default, setter(skip))]
+    pub dialog_err: Option<String>,
+
     #[builder(default = false, setter(skip))]
     pub is_frozen: bool,
 
+    #[builder(default = false, setter(skip))]
+    pub is_replaying: bool,
+
     #[builder(default = Instant::now(), setter(skip))]
     last_key_press: Instant,
 
@@ -119,6 +133,15 @@ pub struct App {
     #[builder(default, setter(skip))]
     pub help_dialog_state: AppHelpDialogState,
 
+    #[builder(default, setter(skip))]
+    pub priority_dialog_state: AppPriorityDialogState,
+
+    #[builder(default, setter(skip))]
+    pub affinity_dialog_state: AppAffinityDialogState,
+
+    #[builder(default, setter(skip))]
+    pub process_details_state: AppProcessDetailsState,
+
     #[builder(default = false, setter(skip))]
     pub is_expanded: bool,
 
@@ -141,6 +164,13 @@ pub struct App {
     pub proc_state: ProcState,
     pub temp_state: TempState,
     pub disk_state: DiskState,
+    pub disk_io_state: DiskIoState,
+    pub temp_graph_state: TempGraphState,
+    pub net_interface_state: NetInterfaceState,
+    pub cgroup_state: CgroupState,
+    pub sensor_state: SensorState,
+    pub pressure_state: PressureState,
+    pub cpu_freq_state: CpuFreqState,
     pub battery_state: BatteryState,
     pub basic_table_widget_state: Option<BasicTableWidgetState>,
     pub app_config_fields: AppConfigFields,
@@ -148,6 +178,7 @@ pub struct App {
     pub current_widget: BottomWidget,
     pub used_widgets: UsedWidgets,
     pub filters: DataFilters,
+    pub key_bindings: KeyBindings,
     pub config: Config,               //  TODO: Is this even used...?
     pub config_path: Option<PathBuf>, //  TODO: Is this even used...?
 }
@@ -159,6 +190,31 @@ const MAX_SIGNAL: usize = 64;
 #[cfg(target_os = "macos")]
 const MAX_SIGNAL: usize = 31;
 
+/// Runs `action` on every PID, carrying on past any that fail so that as many processes as possible are acted on.
+/// The failures are then reported together, saying they could not be `done`.
+fn act_on_pids(pids: &[Pid], done: &str, mut action: impl FnMut(Pid) -> Result<()>) -> Result<()> {
+    let failures = pids
+        .iter()
+        .filter_map(|pid| {
+            action(*pid)
+                .err()
+                .map(|err| format!("PID {}: {}", pid, err))
+        })
+        .collect::<Vec<_>>();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(BottomError::GenericError(format!(
+            "{} of {} processes could not be {}. {}",
+            failures.len(),
+            pids.len(),
+            done,
+            failures.join(" ")
+        )))
+    }
+}
+
 impl App {
     pub fn reset(&mut self) {
         // Reset multi
@@ -167,6 +223,9 @@ impl App {
         // Reset dialog state
         self.help_dialog_state.is_showing_help = false;
         self.delete_dialog_state.is_showing_dd = false;
+        self.close_priority_dialog();
+        self.close_affinity_dialog();
+        self.close_process_details();
 
         // Close all searches and reset it
         self.proc_state
@@ -204,12 +263,33 @@ impl App {
         self.dd_err = None;
     }
 
+    fn close_priority_dialog(&mut self) {
+        self.priority_dialog_state = AppPriorityDialogState::default();
+        self.dialog_err = None;
+    }
+
+    fn close_process_details(&mut self) {
+        self.process_details_state = AppProcessDetailsState::default();
+        self.data_collection.process_details = None;
+    }
+
+    fn close_affinity_dialog(&mut self) {
+        self.affinity_dialog_state = AppAffinityDialogState::default();
+        self.dialog_err = None;
+    }
+
     pub fn on_esc(&mut self) {
         self.reset_multi_tap_keys();
         if self.is_in_dialog() {
             if self.help_dialog_state.is_showing_help {
                 self.help_dialog_state.is_showing_help = false;
                 self.help_dialog_state.scroll_state.current_scroll_index = 0;
+            } else if self.priority_dialog_state.is_showing_priority {
+                self.close_priority_dialog();
+            } else if self.affinity_dialog_state.is_showing_affinity {
+                self.close_affinity_dialog();
+            } else if self.process_details_state.is_showing_details {
+                self.close_process_details();
             } else {
                 self.close_dd();
             }
@@ -274,12 +354,40 @@ impl App {
     }
 
     fn reset_multi_tap_keys(&mut self) {
-        self.awaiting_second_char = false;
-        self.second_char = None;
+        self.pending_key = None;
+    }
+
+    /// Resolves a key press to the action it's bound to, if any. While a dialog is open, only the actions
+    /// that navigate it are allowed.
+    pub fn get_key_action(&mut self, chord: KeyChord) -> Option<Action> {
+        let current_key_press_inst = Instant::now();
+        if current_key_press_inst
+            .duration_since(self.last_key_press)
+            .as_millis()
+            > constants::MAX_KEY_TIMEOUT_IN_MILLISECONDS.into()
+        {
+            self.reset_multi_tap_keys();
+        }
+        self.last_key_press = current_key_press_inst;
+
+        match self.key_bindings.get(self.pending_key.take(), chord) {
+            KeyLookup::Action(action) if !self.is_in_dialog() || action.is_allowed_in_dialog() => {
+                Some(action)
+            }
+            KeyLookup::Pending => {
+                self.pending_key = Some(chord);
+                None
+            }
+            _ => None,
+        }
     }
 
-    fn is_in_dialog(&self) -> bool {
-        self.help_dialog_state.is_showing_help || self.delete_dialog_state.is_showing_dd
+    pub fn is_in_dialog(&self) -> bool {
+        self.help_dialog_state.is_showing_help
+            || self.delete_dialog_state.is_showing_dd
+            || self.priority_dialog_state.is_showing_priority
+            || self.affinity_dialog_state.is_showing_affinity
+            || self.process_details_state.is_showing_details
     }
 
     fn ignore_normal_keybinds(&self) -> bool {
@@ -607,17 +715,77 @@ impl App {
                 self.delete_dialog_state.is_showing_dd = false;
             }
             self.is_force_redraw = true;
+        } else if self.priority_dialog_state.is_showing_priority {
+            if self.dialog_err.is_some() {
+                self.close_priority_dialog();
+            } else if let Err(priority_err) = self.change_priority() {
+                // Check if there was an issue... if so, inform the user.
+                self.dialog_err = Some(priority_err.to_string());
+            } else {
+                self.close_priority_dialog();
+            }
+            self.is_force_redraw = true;
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            if self.dialog_err.is_some() {
+                self.close_affinity_dialog();
+            } else if let Err(affinity_err) = self.change_affinity() {
+                // Check if there was an issue... if so, inform the user.
+                self.dialog_err = Some(affinity_err.to_string());
+            } else {
+                self.close_affinity_dialog();
+            }
+            self.is_force_redraw = true;
         } else if !self.is_in_dialog() {
-            if let BottomWidgetType::ProcSort = self.current_widget.widget_type {
-                if let Some(proc_widget_state) = self
-                    .proc_state
-                    .widget_states
-                    .get_mut(&(self.current_widget.widget_id - 2))
-                {
-                    proc_widget_state.use_sort_table_value();
-                    self.move_widget_selection(&WidgetDirection::Right);
-                    self.is_force_redraw = true;
+            match self.current_widget.widget_type {
+                BottomWidgetType::ProcSort => {
+                    if let Some(proc_widget_state) = self
+                        .proc_state
+                        .widget_states
+                        .get_mut(&(self.current_widget.widget_id - 2))
+                    {
+                        proc_widget_state.use_sort_table_value();
+                        self.move_widget_selection(&WidgetDirection::Right);
+                        self.is_force_redraw = true;
+                    }
                 }
+                BottomWidgetType::NetInterfaces => self.toggle_graphing_selected_interface(),
+                BottomWidgetType::Cgroups => self.toggle_collapsing_cgroup_branch(),
+                BottomWidgetType::CpuLegend => self.toggle_expanding_cpu_group(),
+                BottomWidgetType::TempGraphLegend => self.toggle_selected_temp_sensor(),
+                _ => {}
+            }
+        }
+    }
+
+    /// Graphs the selected interface in the network graph, or stops graphing it if it already is.
+    fn toggle_graphing_selected_interface(&mut self) {
+        if let Some(net_interface_widget_state) = self
+            .net_interface_state
+            .get_widget_state(self.current_widget.widget_id)
+        {
+            if let Some(interface) = self.converted_data.net_interface_names.get(
+                net_interface_widget_state
+                    .table_state
+                    .current_scroll_position,
+            ) {
+                self.net_state.toggle_graphed_interface(interface);
+                self.net_state.force_update = Some(self.current_widget.widget_id);
+            }
+        }
+    }
+
+    /// Hides the selected sensor's line in the temperature graph, or shows it again if it is already hidden.
+    fn toggle_selected_temp_sensor(&mut self) {
+        if let Some(temp_graph_widget_state) = self
+            .temp_graph_state
+            .get_mut_widget_state(self.current_widget.widget_id - 1)
+        {
+            if let Some(sensor) = self
+                .converted_data
+                .temp_graph_data
+                .get(temp_graph_widget_state.table_state.current_scroll_position)
+            {
+                temp_graph_widget_state.toggle_sensor(&sensor.name);
             }
         }
     }
@@ -765,6 +933,12 @@ impl App {
             self.decrement_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.priority_dialog_state.is_showing_priority {
+            self.priority_dialog_state.move_selection(-1);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.move_row(-1);
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll(-1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_right_key();
@@ -786,6 +960,12 @@ impl App {
             self.increment_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.priority_dialog_state.is_showing_priority {
+            self.priority_dialog_state.move_selection(1);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.move_row(1);
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll(1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_left_key();
@@ -845,6 +1025,10 @@ impl App {
                 }
                 _ => {}
             }
+        } else if self.priority_dialog_state.is_showing_priority {
+            self.priority_dialog_state.change_selected(-1);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.move_selection(-1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_family = "unix")]
             {
@@ -918,6 +1102,10 @@ impl App {
                 }
                 _ => {}
             }
+        } else if self.priority_dialog_state.is_showing_priority {
+            self.priority_dialog_state.change_selected(1);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.move_selection(1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_family = "unix")]
             {
@@ -957,6 +1145,9 @@ impl App {
                 0 => KillSignal::Cancel,
                 sig => KillSignal::Kill(sig),
             };
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state
+                .scroll(-AppProcessDetailsState::PAGE_LINES);
         } else if self.current_widget.widget_type.is_widget_table() {
             if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
                 &self.current_widget.top_left_corner,
@@ -980,6 +1171,9 @@ impl App {
                 new_signal += 2;
             }
             self.delete_dialog_state.selected_signal = KillSignal::Kill(new_signal);
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state
+                .scroll(AppProcessDetailsState::PAGE_LINES);
         } else if self.current_widget.widget_type.is_widget_table() {
             if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
                 &self.current_widget.top_left_corner,
@@ -1145,39 +1339,276 @@ impl App {
     pub fn start_killing_process(&mut self) {
         self.reset_multi_tap_keys();
 
-        if let Some(pws) = self
-            .proc_state
-            .widget_states
-            .get(&self.current_widget.widget_id)
-        {
-            if let Some(table_row) = pws
+        if let Some((name, pids, selection)) = self.get_selected_process() {
+            self.to_delete_process_list = Some((name, pids));
+            self.delete_dialog_state.action = ProcessAction::Kill;
+            self.delete_dialog_state.selection = selection;
+            self.delete_dialog_state.is_showing_dd = true;
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    /// Gets the name and PIDs of the processes an action in the current process widget applies to, along with how
+    /// they were picked. Marked processes take priority over the selected row, and have no name.
+    fn get_selected_process(&self) -> Option<(String, Vec<Pid>, ProcessSelection)> {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            let pws = self
+                .proc_state
+                .widget_states
+                .get(&self.current_widget.widget_id)?;
+            if !pws.marked_pids.is_empty() {
+                let mut pids: Vec<Pid> = pws.marked_pids.iter().copied().collect();
+                pids.sort_unstable();
+                return Some((String::new(), pids, ProcessSelection::Marked));
+            }
+        }
+
+        self.get_selected_row_process()
+    }
+
+    /// Gets the name and PIDs of the process in the selected row of the current process widget, along with whether
+    /// it is a group of processes.
+    fn get_selected_row_process(&self) -> Option<(String, Vec<Pid>, ProcessSelection)> {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            let pws = self
+                .proc_state
+                .widget_states
+                .get(&self.current_widget.widget_id)?;
+            let table_row = pws
                 .table_data
                 .data
-                .get(pws.table_state.current_scroll_position)
+                .get(pws.table_state.current_scroll_position)?;
+            let group_key = pws.group_key();
+            let name = table_row
+                .row()
+                .get(ProcWidget::PROC_NAME_OR_CMD)?
+                .main_text()
+                .to_string();
+            let pids = if let Some(group_key) = group_key {
+                group_key
+                    .pid_map(&self.data_collection.process_data)
+                    .get(&name)?
+                    .clone()
+            } else {
+                vec![table_row
+                    .row()
+                    .get(ProcWidget::PID_OR_COUNT)?
+                    .main_text()
+                    .parse::<Pid>()
+                    .ok()?]
+            };
+
+            if !pids.is_empty() {
+                let selection = if group_key.is_some() {
+                    ProcessSelection::Grouped
+                } else {
+                    ProcessSelection::Single
+                };
+                return Some((name, pids, selection));
+            }
+        }
+
+        None
+    }
+
+    pub fn start_killing_process_tree(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some((name, pids, selection)) = self.get_selected_process() {
+            let pids = self.data_collection.process_data.subtrees_leaf_first(&pids);
+
+            self.to_delete_process_list = Some((name, pids));
+            self.delete_dialog_state.action = ProcessAction::KillTree;
+            self.delete_dialog_state.selection = selection;
+            self.delete_dialog_state.is_showing_dd = true;
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    /// Pauses or resumes the selected process(es), showing any failure in the kill dialog.
+    fn set_selected_process_stopped(&mut self, stop: bool) {
+        self.reset_multi_tap_keys();
+
+        if let Some((name, pids, selection)) = self.get_selected_process() {
+            let done = if stop { "stopped" } else { "continued" };
+            let result = act_on_pids(&pids, done, |pid| {
+                process_killer::set_process_stopped(pid, stop)
+            });
+
+            if let Err(err) = result {
+                self.to_delete_process_list = Some((name, pids));
+                self.dd_err = Some(err.to_string());
+                self.delete_dialog_state.action = if stop {
+                    ProcessAction::Stop
+                } else {
+                    ProcessAction::Continue
+                };
+                self.delete_dialog_state.selection = selection;
+                self.delete_dialog_state.is_showing_dd = true;
+                self.is_determining_widget_boundary = true;
+            }
+
+            if let Some(pws) = self
+                .proc_state
+                .widget_states
+                .get_mut(&self.current_widget.widget_id)
             {
-                if let Some(col_value) = table_row.row().get(ProcWidget::PROC_NAME_OR_CMD) {
-                    let val = col_value.main_text().to_string();
-                    if pws.is_using_command() {
-                        if let Some(pids) = self.data_collection.process_data.cmd_pid_map.get(&val)
-                        {
-                            let current_process = (val, pids.clone());
+                pws.force_data_update();
+            }
+        }
+    }
 
-                            self.to_delete_process_list = Some(current_process);
-                            self.delete_dialog_state.is_showing_dd = true;
-                            self.is_determining_widget_boundary = true;
-                        }
-                    } else if let Some(pids) =
-                        self.data_collection.process_data.name_pid_map.get(&val)
-                    {
-                        let current_process = (val, pids.clone());
+    /// Opens the details of the process in the selected row. A group can only be shown if it is a single process.
+    pub fn start_showing_process_details(&mut self) {
+        self.reset_multi_tap_keys();
 
-                        self.to_delete_process_list = Some(current_process);
-                        self.delete_dialog_state.is_showing_dd = true;
-                        self.is_determining_widget_boundary = true;
-                    }
-                }
+        if let Some((name, pids, _)) = self.get_selected_row_process() {
+            if let [pid] = pids[..] {
+                self.process_details_state = AppProcessDetailsState {
+                    is_showing_details: true,
+                    process: Some((name, pid)),
+                    ..AppProcessDetailsState::default()
+                };
+                self.data_collection.process_details = None;
+                self.is_determining_widget_boundary = true;
+            }
+        }
+    }
+
+    /// Marks the selected process(es), or unmarks them if they are already marked.
+    pub fn toggle_mark(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some((_, pids, _)) = self.get_selected_row_process() {
+            // Thread rows show a thread ID instead, which can't be acted on as a process.
+            let process_harvest = &self.data_collection.process_data.process_harvest;
+            let pids = pids
+                .into_iter()
+                .filter(|pid| process_harvest.contains_key(pid))
+                .collect::<Vec<_>>();
+
+            if let Some(pws) = self
+                .proc_state
+                .widget_states
+                .get_mut(&self.current_widget.widget_id)
+            {
+                pws.toggle_marked(&pids);
+            }
+        }
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(pws) = self
+                .proc_state
+                .widget_states
+                .get_mut(&self.current_widget.widget_id)
+            {
+                pws.clear_marks();
+            }
+        }
+    }
+
+    pub fn start_changing_priority(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some((name, pids, selection)) = self.get_selected_process() {
+            self.priority_dialog_state = AppPriorityDialogState {
+                is_showing_priority: true,
+                nice: process_priority::get_nice(pids[0]).clamp(
+                    AppPriorityDialogState::MIN_NICE,
+                    AppPriorityDialogState::MAX_NICE,
+                ),
+                io_level: 4,
+                process: Some((name, pids)),
+                selection,
+                ..AppPriorityDialogState::default()
+            };
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    /// Applies the settings of the priority dialog that were changed to every process it targets, carrying on
+    /// past any process that fails.
+    fn change_priority(&self) -> Result<()> {
+        let state = &self.priority_dialog_state;
+        if let Some((_, pids)) = &state.process {
+            let pids = if state.include_children && state.selection != ProcessSelection::Grouped {
+                self.data_collection.process_data.subtrees_leaf_first(pids)
+            } else {
+                pids.clone()
+            };
+
+            act_on_pids(&pids, "changed", |pid| {
+                let nice_result = match state.changed_nice() {
+                    Some(nice) => process_priority::set_nice(pid, nice),
+                    None => Ok(()),
+                };
+                let io_result =
+                    process_priority::set_io_priority(pid, state.io_class, state.io_level);
+
+                nice_result.and(io_result)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn start_changing_affinity(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some((name, pids, selection)) = self.get_selected_process() {
+            // The cores are named the same way as in the CPU legend, but set by the kernel's ID for them.
+            let allowed_cores = process_affinity::get_affinity(pids[0]);
+            let cores = self
+                .data_collection
+                .cpu_harvest
+                .iter()
+                .filter_map(|cpu| match (cpu.cpu_count, cpu.cpu_id) {
+                    (Some(cpu_count), Some(cpu_id)) => Some(AffinityCore {
+                        name: format!("{}{}", cpu.cpu_prefix, cpu_count),
+                        id: cpu_id,
+                        is_allowed: allowed_cores
+                            .as_ref()
+                            .map(|allowed_cores| allowed_cores.contains(&cpu_id))
+                            .unwrap_or(false),
+                    }),
+                    _ => None,
+                })
+                .collect();
+
+            self.dialog_err = allowed_cores.err().map(|err| err.to_string());
+            self.affinity_dialog_state = AppAffinityDialogState {
+                is_showing_affinity: true,
+                process: Some((name, pids)),
+                selection,
+                cores,
+                ..AppAffinityDialogState::default()
+            };
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    /// Applies the cores picked in the affinity dialog to every process it targets, carrying on past any process
+    /// that fails.
+    fn change_affinity(&self) -> Result<()> {
+        let state = &self.affinity_dialog_state;
+        if let Some((_, pids)) = &state.process {
+            let allowed_cores = state.allowed_cores();
+            if allowed_cores.is_empty() {
+                return Err(BottomError::GenericError(
+                    "At least one core must be selected.".to_string(),
+                ));
             }
+
+            act_on_pids(pids, "changed", |pid| {
+                process_affinity::set_affinity(pid, &allowed_cores, state.include_threads)
+            })?;
         }
+
+        Ok(())
     }
 
     pub fn on_char_key(&mut self, caught_char: char) {
@@ -1188,16 +1619,6 @@ impl App {
 
         // Forbid any char key presses when showing a dialog box...
         if !self.ignore_normal_keybinds() {
-            let current_key_press_inst = Instant::now();
-            if current_key_press_inst
-                .duration_since(self.last_key_press)
-                .as_millis()
-                > constants::MAX_KEY_TIMEOUT_IN_MILLISECONDS.into()
-            {
-                self.reset_multi_tap_keys();
-            }
-            self.last_key_press = current_key_press_inst;
-
             if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
                 let is_in_search_widget = self.is_in_search_widget();
                 if let Some(proc_widget_state) = self
@@ -1243,12 +1664,9 @@ impl App {
                         proc_widget_state.update_query();
                         proc_widget_state.proc_search.search_state.cursor_direction =
                             CursorDirection::Right;
-
-                        return;
                     }
                 }
             }
-            self.handle_char(caught_char);
         } else if self.help_dialog_state.is_showing_help {
             match caught_char {
                 '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
@@ -1262,85 +1680,56 @@ impl App {
                         }
                     }
                 }
-                'j' | 'k' | 'g' | 'G' => self.handle_char(caught_char),
+                _ => {}
+            }
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            match caught_char {
+                ' ' => self.affinity_dialog_state.toggle_selected(),
+                'a' => self.affinity_dialog_state.toggle_all(),
+                't' => {
+                    self.affinity_dialog_state.include_threads =
+                        !self.affinity_dialog_state.include_threads
+                }
                 _ => {}
             }
         } else if self.delete_dialog_state.is_showing_dd {
             match caught_char {
-                'h' => self.on_left_key(),
-                'j' => self.on_down_key(),
-                'k' => self.on_up_key(),
-                'l' => self.on_right_key(),
                 #[cfg(target_family = "unix")]
                 '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
                     self.on_number(caught_char)
                 }
-                'g' => {
-                    let mut is_first_g = true;
-                    if let Some(second_char) = self.second_char {
-                        if self.awaiting_second_char && second_char == 'g' {
-                            is_first_g = false;
-                            self.awaiting_second_char = false;
-                            self.second_char = None;
-                            self.skip_to_first();
-                        }
-                    }
-
-                    if is_first_g {
-                        self.awaiting_second_char = true;
-                        self.second_char = Some('g');
-                    }
-                }
-                'G' => self.skip_to_last(),
                 _ => {}
             }
         }
     }
 
-    fn handle_char(&mut self, caught_char: char) {
-        match caught_char {
-            '/' => {
-                self.on_slash();
-            }
-            'd' => {
+    /// Performs an action that a key was bound to. Quitting, resetting, and controlling a replay are left to
+    /// the caller, since they reach beyond the app state.
+    pub fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Search => self.on_slash(),
+            Action::KillProcess => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
-                    let mut is_first_d = true;
-                    if let Some(second_char) = self.second_char {
-                        if self.awaiting_second_char && second_char == 'd' {
-                            is_first_d = false;
-                            self.awaiting_second_char = false;
-                            self.second_char = None;
-
-                            self.start_killing_process();
-                        }
-                    }
-
-                    if is_first_d {
-                        self.awaiting_second_char = true;
-                        self.second_char = Some('d');
-                    }
-                }
-            }
-            'g' => {
-                let mut is_first_g = true;
-                if let Some(second_char) = self.second_char {
-                    if self.awaiting_second_char && second_char == 'g' {
-                        is_first_g = false;
-                        self.awaiting_second_char = false;
-                        self.second_char = None;
-                        self.skip_to_first();
-                    }
-                }
-
-                if is_first_g {
-                    self.awaiting_second_char = true;
-                    self.second_char = Some('g');
-                }
-            }
-            'G' => self.skip_to_last(),
-            'k' => self.on_up_key(),
-            'j' => self.on_down_key(),
-            'f' => {
+                    self.start_killing_process();
+                }
+            }
+            Action::KillProcessTree => self.start_killing_process_tree(),
+            Action::StopProcess => self.set_selected_process_stopped(true),
+            Action::ContinueProcess => self.set_selected_process_stopped(false),
+            Action::ChangePriority => self.start_changing_priority(),
+            Action::ChangeAffinity => self.start_changing_affinity(),
+            Action::ShowProcessDetails => self.start_showing_process_details(),
+            Action::ToggleMark => self.toggle_mark(),
+            Action::ClearMarks => self.clear_marks(),
+            Action::SkipToFirst => self.skip_to_first(),
+            Action::SkipToLast => self.skip_to_last(),
+            Action::Left => self.on_left_key(),
+            Action::Right => self.on_right_key(),
+            Action::Up => self.on_up_key(),
+            Action::Down => self.on_down_key(),
+            Action::PageUp => self.on_page_up(),
+            Action::PageDown => self.on_page_down(),
+            Action::Freeze => {
                 self.is_frozen = !self.is_frozen;
                 if self.is_frozen {
                     self.data_collection.freeze();
@@ -1348,7 +1737,7 @@ impl App {
                     self.data_collection.thaw();
                 }
             }
-            'c' => {
+            Action::SortByCpu => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
@@ -1358,7 +1747,7 @@ impl App {
                     }
                 }
             }
-            'm' => {
+            Action::SortByMem => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
@@ -1368,7 +1757,7 @@ impl App {
                     }
                 }
             }
-            'p' => {
+            Action::SortByPid => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
@@ -1378,7 +1767,7 @@ impl App {
                     }
                 }
             }
-            'P' => {
+            Action::ToggleCommand => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
@@ -1388,7 +1777,7 @@ impl App {
                     }
                 }
             }
-            'n' => {
+            Action::SortByName => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
@@ -1398,29 +1787,32 @@ impl App {
                     }
                 }
             }
-            '?' => {
+            Action::Help => {
                 self.help_dialog_state.is_showing_help = true;
                 self.is_force_redraw = true;
             }
-            'H' | 'A' => self.move_widget_selection(&WidgetDirection::Left),
-            'L' | 'D' => self.move_widget_selection(&WidgetDirection::Right),
-            'K' | 'W' => self.move_widget_selection(&WidgetDirection::Up),
-            'J' | 'S' => self.move_widget_selection(&WidgetDirection::Down),
-            't' => self.toggle_tree_mode(),
-            '+' => self.on_plus(),
-            '-' => self.on_minus(),
-            '=' => self.reset_zoom(),
-            'e' => self.toggle_expand_widget(),
-            's' => self.toggle_sort(),
-            'I' => self.invert_sort(),
-            '%' => self.toggle_percentages(),
-            _ => {}
-        }
-
-        if let Some(second_char) = self.second_char {
-            if self.awaiting_second_char && caught_char != second_char {
-                self.awaiting_second_char = false;
-            }
+            Action::MoveWidgetLeft => self.move_widget_selection(&WidgetDirection::Left),
+            Action::MoveWidgetRight => self.move_widget_selection(&WidgetDirection::Right),
+            Action::MoveWidgetUp => self.move_widget_selection(&WidgetDirection::Up),
+            Action::MoveWidgetDown => self.move_widget_selection(&WidgetDirection::Down),
+            Action::ToggleTree => self.toggle_tree_mode(),
+            Action::ToggleThreads => self.toggle_process_threads(),
+            Action::ToggleCgroupGrouping => self.toggle_cgroup_grouping(),
+            Action::CycleCpuGrouping => self.cycle_cpu_grouping(),
+            Action::ZoomIn => self.on_plus(),
+            Action::ZoomOut => self.on_minus(),
+            Action::ResetZoom => self.reset_zoom(),
+            Action::Expand => self.toggle_expand_widget(),
+            Action::Sort => self.toggle_sort(),
+            Action::InvertSort => self.invert_sort(),
+            Action::TogglePercentages => self.toggle_percentages(),
+            Action::Quit
+            | Action::Reset
+            | Action::ReplayPause
+            | Action::ReplaySlowDown
+            | Action::ReplaySpeedUp
+            | Action::ReplaySeekBackward
+            | Action::ReplaySeekForward => {}
         }
     }
 
@@ -1432,16 +1824,28 @@ impl App {
                     KillSignal::Kill(sig) => sig,
                     KillSignal::Cancel => 15, // should never happen, so just TERM
                 };
-                for pid in &current_selected_processes.1 {
+                let kill = |pid: Pid| {
                     #[cfg(target_family = "unix")]
                     {
-                        process_killer::kill_process_given_pid(*pid, signal)?;
+                        process_killer::kill_process_given_pid(pid, signal)
                     }
                     #[cfg(target_os = "windows")]
                     {
-                        process_killer::kill_process_given_pid(*pid)?;
+                        process_killer::kill_process_given_pid(pid)
                     }
-                }
+                };
+
+                // Keep going on failure, so as much as possible is killed. Processes may exit on their own in the
+                // meantime, like children once their parent is gone, which is not a failure.
+                act_on_pids(&current_selected_processes.1, "signalled", |pid| {
+                    kill(pid).or_else(|err| {
+                        if process_killer::has_exited(pid) {
+                            Ok(())
+                        } else {
+                            Err(err)
+                        }
+                    })
+                })?;
             }
             self.to_delete_process_list = None;
             Ok(())
@@ -1606,25 +2010,21 @@ impl App {
                                         WidgetDirection::Down => new_widget.down_neighbour,
                                     };
                                     match &new_widget.widget_type {
-                                        BottomWidgetType::CpuLegend => {
-                                            if let Some(cpu_widget_state) = self
-                                                .cpu_state
-                                                .widget_states
-                                                .get(&(new_widget_id - *offset))
+                                        BottomWidgetType::CpuLegend
+                                        | BottomWidgetType::DiskIoLegend
+                                        | BottomWidgetType::TempGraphLegend
+                                            if self.is_graph_legend_hidden(
+                                                new_widget_id - *offset,
+                                            ) =>
+                                        {
+                                            if let Some(next_neighbour_id) =
+                                                option_next_neighbour_id
                                             {
-                                                if cpu_widget_state.is_legend_hidden {
-                                                    if let Some(next_neighbour_id) =
-                                                        option_next_neighbour_id
-                                                    {
-                                                        if let Some(next_neighbour_widget) =
-                                                            self.widget_map.get(&next_neighbour_id)
-                                                        {
-                                                            self.current_widget =
-                                                                next_neighbour_widget.clone();
-                                                        }
-                                                    }
-                                                } else {
-                                                    self.current_widget = new_widget.clone();
+                                                if let Some(next_neighbour_widget) =
+                                                    self.widget_map.get(&next_neighbour_id)
+                                                {
+                                                    self.current_widget =
+                                                        next_neighbour_widget.clone();
                                                 }
                                             }
                                         }
@@ -1687,23 +2087,17 @@ impl App {
                                 } else {
                                     // Reflect
                                     match &new_widget.widget_type {
-                                        BottomWidgetType::CpuLegend => {
-                                            if let Some(cpu_widget_state) = self
-                                                .cpu_state
-                                                .widget_states
-                                                .get(&(new_widget_id - *offset))
+                                        BottomWidgetType::CpuLegend
+                                        | BottomWidgetType::DiskIoLegend
+                                        | BottomWidgetType::TempGraphLegend
+                                            if self.is_graph_legend_hidden(
+                                                new_widget_id - *offset,
+                                            ) =>
+                                        {
+                                            if let Some(parent_graph_widget) =
+                                                self.widget_map.get(&(new_widget_id - *offset))
                                             {
-                                                if cpu_widget_state.is_legend_hidden {
-                                                    if let Some(parent_cpu_widget) = self
-                                                        .widget_map
-                                                        .get(&(new_widget_id - *offset))
-                                                    {
-                                                        self.current_widget =
-                                                            parent_cpu_widget.clone();
-                                                    }
-                                                } else {
-                                                    self.current_widget = new_widget.clone();
-                                                }
+                                                self.current_widget = parent_graph_widget.clone();
                                             }
                                         }
                                         BottomWidgetType::ProcSearch
@@ -1765,16 +2159,14 @@ impl App {
                     if let Some((parent_direction, offset)) = &self.current_widget.parent_reflector
                     {
                         match &self.current_widget.widget_type {
-                            BottomWidgetType::CpuLegend => {
-                                if let Some(cpu_widget_state) = self
-                                    .cpu_state
-                                    .widget_states
-                                    .get(&(self.current_widget.widget_id - *offset))
-                                {
-                                    if cpu_widget_state.is_legend_hidden {
-                                        reflection_dir = Some(parent_direction.clone());
-                                    }
-                                }
+                            BottomWidgetType::CpuLegend
+                            | BottomWidgetType::DiskIoLegend
+                            | BottomWidgetType::TempGraphLegend
+                                if self.is_graph_legend_hidden(
+                                    self.current_widget.widget_id - *offset,
+                                ) =>
+                            {
+                                reflection_dir = Some(parent_direction.clone());
                             }
                             BottomWidgetType::ProcSearch | BottomWidgetType::ProcSort => {
                                 if let Some(proc_widget_state) = self
@@ -1866,24 +2258,23 @@ impl App {
                 }
             }
         } else if self.app_config_fields.left_legend {
-            if let BottomWidgetType::Cpu = self.current_widget.widget_type {
+            if let BottomWidgetType::Cpu | BottomWidgetType::DiskIo | BottomWidgetType::TempGraph =
+                self.current_widget.widget_type
+            {
                 if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
-                    if let Some(cpu_widget_state) = self
-                        .cpu_state
-                        .widget_states
-                        .get(&self.current_widget.widget_id)
-                    {
-                        if !cpu_widget_state.is_legend_hidden {
-                            if let Some(new_widget_id) = current_widget.left_neighbour {
-                                if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
-                                    self.current_widget = new_widget.clone();
-                                }
+                    if !self.is_graph_legend_hidden(self.current_widget.widget_id) {
+                        if let Some(new_widget_id) = current_widget.left_neighbour {
+                            if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
+                                self.current_widget = new_widget.clone();
                             }
                         }
                     }
                 }
             }
-        } else if let BottomWidgetType::CpuLegend = self.current_widget.widget_type {
+        } else if let BottomWidgetType::CpuLegend
+        | BottomWidgetType::DiskIoLegend
+        | BottomWidgetType::TempGraphLegend = self.current_widget.widget_type
+        {
             if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
                 if let Some(new_widget_id) = current_widget.left_neighbour {
                     if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
@@ -1902,7 +2293,10 @@ impl App {
                 }
             }
         } else if self.app_config_fields.left_legend {
-            if let BottomWidgetType::CpuLegend = self.current_widget.widget_type {
+            if let BottomWidgetType::CpuLegend
+            | BottomWidgetType::DiskIoLegend
+            | BottomWidgetType::TempGraphLegend = self.current_widget.widget_type
+            {
                 if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
                     if let Some(new_widget_id) = current_widget.right_neighbour {
                         if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
@@ -1911,18 +2305,15 @@ impl App {
                     }
                 }
             }
-        } else if let BottomWidgetType::Cpu = self.current_widget.widget_type {
+        } else if let BottomWidgetType::Cpu
+        | BottomWidgetType::DiskIo
+        | BottomWidgetType::TempGraph = self.current_widget.widget_type
+        {
             if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
-                if let Some(cpu_widget_state) = self
-                    .cpu_state
-                    .widget_states
-                    .get(&self.current_widget.widget_id)
-                {
-                    if !cpu_widget_state.is_legend_hidden {
-                        if let Some(new_widget_id) = current_widget.right_neighbour {
-                            if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
-                                self.current_widget = new_widget.clone();
-                            }
+                if !self.is_graph_legend_hidden(self.current_widget.widget_id) {
+                    if let Some(new_widget_id) = current_widget.right_neighbour {
+                        if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
+                            self.current_widget = new_widget.clone();
                         }
                     }
                 }
@@ -1930,6 +2321,73 @@ impl App {
         }
     }
 
+    /// Whether the legend of the CPU, disk I/O, or temperature graph with the given ID is hidden. Graphs
+    /// without a legend count as hidden.
+    fn is_graph_legend_hidden(&self, widget_id: u64) -> bool {
+        if let Some(cpu_widget_state) = self.cpu_state.get_widget_state(widget_id) {
+            cpu_widget_state.is_legend_hidden
+        } else if let Some(disk_io_widget_state) = self.disk_io_state.get_widget_state(widget_id) {
+            disk_io_widget_state.is_legend_hidden
+        } else if let Some(temp_graph_widget_state) =
+            self.temp_graph_state.get_widget_state(widget_id)
+        {
+            temp_graph_widget_state.is_legend_hidden
+        } else {
+            true
+        }
+    }
+
+    /// Sets whether the legend of the CPU, disk I/O, or temperature graph with the given ID is hidden.
+    pub fn set_graph_legend_hidden(&mut self, widget_id: u64, is_legend_hidden: bool) {
+        if let Some(cpu_widget_state) = self.cpu_state.get_mut_widget_state(widget_id) {
+            cpu_widget_state.is_legend_hidden = is_legend_hidden;
+        } else if let Some(disk_io_widget_state) =
+            self.disk_io_state.get_mut_widget_state(widget_id)
+        {
+            disk_io_widget_state.is_legend_hidden = is_legend_hidden;
+        } else if let Some(temp_graph_widget_state) =
+            self.temp_graph_state.get_mut_widget_state(widget_id)
+        {
+            temp_graph_widget_state.is_legend_hidden = is_legend_hidden;
+        }
+    }
+
+    /// The table state of the legend of the CPU, disk I/O, or temperature graph with the given ID, along with how
+    /// many rows the legend has.
+    fn graph_legend_table_state_mut(
+        &mut self, widget_id: u64,
+    ) -> Option<(&mut TableComponentState, usize)> {
+        let App {
+            cpu_state,
+            disk_io_state,
+            temp_graph_state,
+            converted_data,
+            ..
+        } = self;
+
+        if let Some(cpu_widget_state) = cpu_state.get_mut_widget_state(widget_id) {
+            Some((
+                &mut cpu_widget_state.table_state,
+                converted_data.cpu_data.len(),
+            ))
+        } else if let Some(disk_io_widget_state) = disk_io_state.get_mut_widget_state(widget_id) {
+            // The first row of the legend is for all disks.
+            Some((
+                &mut disk_io_widget_state.table_state,
+                converted_data.disk_io_data.len() + 1,
+            ))
+        } else {
+            temp_graph_state
+                .get_mut_widget_state(widget_id)
+                .map(|temp_graph_widget_state| {
+                    (
+                        &mut temp_graph_widget_state.table_state,
+                        converted_data.temp_graph_data.len(),
+                    )
+                })
+        }
+    }
+
     pub fn skip_to_first(&mut self) {
         if !self.ignore_normal_keybinds() {
             match self.current_widget.widget_type {
@@ -1969,21 +2427,59 @@ impl App {
                         disk_widget_state.table_state.scroll_direction = ScrollDirection::Up;
                     }
                 }
-                BottomWidgetType::CpuLegend => {
-                    if let Some(cpu_widget_state) = self
-                        .cpu_state
-                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                BottomWidgetType::NetInterfaces => {
+                    if let Some(net_interface_widget_state) = self
+                        .net_interface_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
                     {
-                        cpu_widget_state.table_state.current_scroll_position = 0;
-                        cpu_widget_state.table_state.scroll_direction = ScrollDirection::Up;
+                        net_interface_widget_state
+                            .table_state
+                            .current_scroll_position = 0;
+                        net_interface_widget_state.table_state.scroll_direction =
+                            ScrollDirection::Up;
+                    }
+                }
+                BottomWidgetType::Cgroups => {
+                    if let Some(cgroup_widget_state) = self
+                        .cgroup_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        cgroup_widget_state.table_state.current_scroll_position = 0;
+                        cgroup_widget_state.table_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
+                BottomWidgetType::Sensors => {
+                    if let Some(sensor_widget_state) = self
+                        .sensor_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        sensor_widget_state.table_state.current_scroll_position = 0;
+                        sensor_widget_state.table_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
+                BottomWidgetType::CpuLegend
+                | BottomWidgetType::DiskIoLegend
+                | BottomWidgetType::TempGraphLegend => {
+                    if let Some((table_state, num_rows)) =
+                        self.graph_legend_table_state_mut(self.current_widget.widget_id - 1)
+                    {
+                        if num_rows > 0 {
+                            table_state.current_scroll_position = num_rows - 1;
+                            table_state.scroll_direction = ScrollDirection::Down;
+                        }
                     }
                 }
-
                 _ => {}
             }
             self.reset_multi_tap_keys();
         } else if self.help_dialog_state.is_showing_help {
             self.help_dialog_state.scroll_state.current_scroll_index = 0;
+        } else if self.priority_dialog_state.is_showing_priority {
+            self.priority_dialog_state.move_selection(isize::MIN);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.move_selection(isize::MIN);
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll(i64::MIN);
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Cancel;
         }
@@ -2036,6 +2532,47 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::NetInterfaces => {
+                    if let Some(net_interface_widget_state) = self
+                        .net_interface_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.converted_data.net_interface_data.data.is_empty() {
+                            net_interface_widget_state
+                                .table_state
+                                .current_scroll_position =
+                                self.converted_data.net_interface_data.data.len() - 1;
+                            net_interface_widget_state.table_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
+                BottomWidgetType::Cgroups => {
+                    if let Some(cgroup_widget_state) = self
+                        .cgroup_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.converted_data.cgroup_data.data.is_empty() {
+                            cgroup_widget_state.table_state.current_scroll_position =
+                                self.converted_data.cgroup_data.data.len() - 1;
+                            cgroup_widget_state.table_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
+                BottomWidgetType::Sensors => {
+                    if let Some(sensor_widget_state) = self
+                        .sensor_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.converted_data.sensor_data.data.is_empty() {
+                            sensor_widget_state.table_state.current_scroll_position =
+                                self.converted_data.sensor_data.data.len() - 1;
+                            sensor_widget_state.table_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .cpu_state
@@ -2048,6 +2585,28 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::DiskIoLegend => {
+                    if let Some(disk_io_widget_state) = self
+                        .disk_io_state
+                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                    {
+                        // The first row of the legend is for all disks.
+                        disk_io_widget_state.table_state.current_scroll_position =
+                            self.converted_data.disk_io_data.len();
+                        disk_io_widget_state.table_state.scroll_direction = ScrollDirection::Down;
+                    }
+                }
+                BottomWidgetType::TempGraphLegend => {
+                    if let Some(temp_graph_widget_state) = self
+                        .temp_graph_state
+                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                    {
+                        temp_graph_widget_state.table_state.current_scroll_position =
+                            self.converted_data.temp_graph_data.len().saturating_sub(1);
+                        temp_graph_widget_state.table_state.scroll_direction =
+                            ScrollDirection::Down;
+                    }
+                }
                 _ => {}
             }
             self.reset_multi_tap_keys();
@@ -2057,6 +2616,12 @@ impl App {
                 .scroll_state
                 .max_scroll_index
                 .saturating_sub(1);
+        } else if self.priority_dialog_state.is_showing_priority {
+            self.priority_dialog_state.move_selection(isize::MAX);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.move_selection(isize::MAX);
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll(i64::MAX);
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Kill(MAX_SIGNAL);
         }
@@ -2079,7 +2644,12 @@ impl App {
                 BottomWidgetType::ProcSort => self.change_process_sort_position(amount),
                 BottomWidgetType::Temp => self.change_temp_position(amount),
                 BottomWidgetType::Disk => self.change_disk_position(amount),
-                BottomWidgetType::CpuLegend => self.change_cpu_legend_position(amount),
+                BottomWidgetType::NetInterfaces => self.change_net_interface_position(amount),
+                BottomWidgetType::Cgroups => self.change_cgroup_position(amount),
+                BottomWidgetType::Sensors => self.change_sensor_position(amount),
+                BottomWidgetType::CpuLegend
+                | BottomWidgetType::DiskIoLegend
+                | BottomWidgetType::TempGraphLegend => self.change_graph_legend_position(amount),
                 _ => {}
             }
         }
@@ -2097,15 +2667,11 @@ impl App {
         }
     }
 
-    fn change_cpu_legend_position(&mut self, num_to_change_by: i64) {
-        if let Some(cpu_widget_state) = self
-            .cpu_state
-            .widget_states
-            .get_mut(&(self.current_widget.widget_id - 1))
+    fn change_graph_legend_position(&mut self, num_to_change_by: i64) {
+        if let Some((table_state, num_rows)) =
+            self.graph_legend_table_state_mut(self.current_widget.widget_id - 1)
         {
-            cpu_widget_state
-                .table_state
-                .update_position(num_to_change_by, self.converted_data.cpu_data.len());
+            table_state.update_position(num_to_change_by, num_rows);
         }
     }
 
@@ -2148,6 +2714,43 @@ impl App {
         }
     }
 
+    fn change_net_interface_position(&mut self, num_to_change_by: i64) {
+        if let Some(net_interface_widget_state) = self
+            .net_interface_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            net_interface_widget_state.table_state.update_position(
+                num_to_change_by,
+                self.converted_data.net_interface_data.data.len(),
+            );
+        }
+    }
+
+    fn change_cgroup_position(&mut self, num_to_change_by: i64) {
+        if let Some(cgroup_widget_state) = self
+            .cgroup_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            cgroup_widget_state
+                .table_state
+                .update_position(num_to_change_by, self.converted_data.cgroup_data.data.len());
+        }
+    }
+
+    fn change_sensor_position(&mut self, num_to_change_by: i64) {
+        if let Some(sensor_widget_state) = self
+            .sensor_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            sensor_widget_state
+                .table_state
+                .update_position(num_to_change_by, self.converted_data.sensor_data.data.len());
+        }
+    }
+
     fn help_scroll_up(&mut self) {
         if self.help_dialog_state.scroll_state.current_scroll_index > 0 {
             self.help_dialog_state.scroll_state.current_scroll_index -= 1;
@@ -2181,6 +2784,11 @@ impl App {
         }
         if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.priority_dialog_state.is_showing_priority
+            || self.affinity_dialog_state.is_showing_affinity
+            || self.process_details_state.is_showing_details
+        {
+            self.on_up_key();
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_in();
         } else if self.current_widget.widget_type.is_widget_table() {
@@ -2198,6 +2806,11 @@ impl App {
         }
         if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.priority_dialog_state.is_showing_priority
+            || self.affinity_dialog_state.is_showing_affinity
+            || self.process_details_state.is_showing_details
+        {
+            self.on_down_key();
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_out();
         } else if self.current_widget.widget_type.is_widget_table() {
@@ -2206,20 +2819,108 @@ impl App {
     }
 
     fn on_plus(&mut self) {
-        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+        match self.current_widget.widget_type {
             // Toggle collapsing if tree
-            self.toggle_collapsing_process_branch();
-        } else {
-            self.zoom_in();
+            BottomWidgetType::Proc => self.toggle_collapsing_process_branch(),
+            BottomWidgetType::Cgroups => self.toggle_collapsing_cgroup_branch(),
+            _ => self.zoom_in(),
         }
     }
 
     fn on_minus(&mut self) {
-        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+        match self.current_widget.widget_type {
             // Toggle collapsing if tree
-            self.toggle_collapsing_process_branch();
-        } else {
-            self.zoom_out();
+            BottomWidgetType::Proc => self.toggle_collapsing_process_branch(),
+            BottomWidgetType::Cgroups => self.toggle_collapsing_cgroup_branch(),
+            _ => self.zoom_out(),
+        }
+    }
+
+    /// Hides or shows the children of the selected cgroup.
+    fn toggle_collapsing_cgroup_branch(&mut self) {
+        if let Some(cgroup_widget_state) = self
+            .cgroup_state
+            .get_widget_state(self.current_widget.widget_id)
+        {
+            if let Some(path) = self
+                .converted_data
+                .cgroup_paths
+                .get(cgroup_widget_state.table_state.current_scroll_position)
+                .cloned()
+            {
+                self.cgroup_state.toggle_collapsed_path(&path);
+                self.cgroup_state.force_update = true;
+            }
+        }
+    }
+
+    /// Shows or hides the CPUs of the selected group in the CPU legend.
+    fn toggle_expanding_cpu_group(&mut self) {
+        if let Some(cpu_widget_state) = self
+            .cpu_state
+            .get_widget_state(self.current_widget.widget_id - 1)
+        {
+            if let Some(group_name) = self
+                .converted_data
+                .cpu_data
+                .get(cpu_widget_state.table_state.current_scroll_position)
+                .and_then(|cpu| cpu.group_name.clone())
+            {
+                self.cpu_state.toggle_expanded_group(&group_name);
+                self.cpu_state.force_update = Some(self.current_widget.widget_id - 1);
+            }
+        }
+    }
+
+    /// Switches to the next way of grouping CPUs in the CPU widgets. Groups start out collapsed, and the legends
+    /// go back to showing all CPUs.
+    fn cycle_cpu_grouping(&mut self) {
+        if let BottomWidgetType::Cpu | BottomWidgetType::CpuLegend | BottomWidgetType::BasicCpu =
+            self.current_widget.widget_type
+        {
+            self.cpu_state.grouping = self.cpu_state.grouping.next();
+            self.cpu_state.expanded_groups.clear();
+            for cpu_widget_state in self.cpu_state.widget_states.values_mut() {
+                cpu_widget_state.table_state.current_scroll_position = 0;
+                cpu_widget_state.table_state.scroll_direction = ScrollDirection::Up;
+            }
+            self.cpu_state.force_update = Some(self.current_widget.widget_id);
+        }
+    }
+
+    /// The PIDs of every process whose threads are shown in any process widget.
+    pub fn expanded_thread_pids(&self) -> FxHashSet<Pid> {
+        self.proc_state
+            .widget_states
+            .values()
+            .flat_map(|pws| pws.expanded_thread_pids.iter().copied())
+            .collect()
+    }
+
+    fn toggle_process_threads(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(pws) = self
+                .proc_state
+                .widget_states
+                .get_mut(&self.current_widget.widget_id)
+            {
+                pws.toggle_threads(&self.data_collection.process_data);
+            }
+        }
+    }
+
+    /// Toggles grouping processes by their container or systemd unit. Cgroups are only collected on Linux.
+    fn toggle_cgroup_grouping(&mut self) {
+        if cfg!(target_os = "linux") {
+            if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                if let Some(pws) = self
+                    .proc_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    pws.toggle_cgroup_grouping();
+                }
+            }
         }
     }
 
@@ -2285,6 +2986,58 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::Pressure => {
+                if let Some(pressure_widget_state) = self
+                    .pressure_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = pressure_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        pressure_widget_state.current_display_time = new_time;
+                        self.pressure_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if pressure_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        pressure_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.pressure_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::CpuFreq => {
+                if let Some(cpu_freq_widget_state) = self
+                    .cpu_freq_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = cpu_freq_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        cpu_freq_widget_state.current_display_time = new_time;
+                        self.cpu_freq_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            cpu_freq_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if cpu_freq_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        cpu_freq_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.cpu_freq_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            cpu_freq_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             BottomWidgetType::Net => {
                 if let Some(net_widget_state) = self
                     .net_state
@@ -2310,6 +3063,58 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::DiskIo => {
+                if let Some(disk_io_widget_state) = self
+                    .disk_io_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = disk_io_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        disk_io_widget_state.current_display_time = new_time;
+                        self.disk_io_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if disk_io_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        disk_io_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.disk_io_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::TempGraph => {
+                if let Some(temp_graph_widget_state) = self
+                    .temp_graph_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = temp_graph_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        temp_graph_widget_state.current_display_time = new_time;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if temp_graph_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        temp_graph_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -2366,6 +3171,58 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::Pressure => {
+                if let Some(pressure_widget_state) = self
+                    .pressure_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = pressure_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        pressure_widget_state.current_display_time = new_time;
+                        self.pressure_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if pressure_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        pressure_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.pressure_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::CpuFreq => {
+                if let Some(cpu_freq_widget_state) = self
+                    .cpu_freq_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = cpu_freq_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        cpu_freq_widget_state.current_display_time = new_time;
+                        self.cpu_freq_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            cpu_freq_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if cpu_freq_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        cpu_freq_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.cpu_freq_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            cpu_freq_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             BottomWidgetType::Net => {
                 if let Some(net_widget_state) = self
                     .net_state
@@ -2391,6 +3248,58 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::DiskIo => {
+                if let Some(disk_io_widget_state) = self
+                    .disk_io_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = disk_io_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        disk_io_widget_state.current_display_time = new_time;
+                        self.disk_io_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if disk_io_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        disk_io_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.disk_io_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::TempGraph => {
+                if let Some(temp_graph_widget_state) = self
+                    .temp_graph_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = temp_graph_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        temp_graph_widget_state.current_display_time = new_time;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if temp_graph_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        temp_graph_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -2423,6 +3332,34 @@ impl App {
         }
     }
 
+    fn reset_pressure_zoom(&mut self) {
+        if let Some(pressure_widget_state) = self
+            .pressure_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            pressure_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.pressure_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                pressure_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_cpu_freq_zoom(&mut self) {
+        if let Some(cpu_freq_widget_state) = self
+            .cpu_freq_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            cpu_freq_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.cpu_freq_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                cpu_freq_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
     fn reset_net_zoom(&mut self) {
         if let Some(net_widget_state) = self
             .net_state
@@ -2437,11 +3374,44 @@ impl App {
         }
     }
 
+    fn reset_disk_io_zoom(&mut self) {
+        if let Some(disk_io_widget_state) = self
+            .disk_io_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            disk_io_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.disk_io_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                disk_io_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_temp_graph_zoom(&mut self) {
+        if let Some(temp_graph_widget_state) = self
+            .temp_graph_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            temp_graph_widget_state.current_display_time =
+                self.app_config_fields.default_time_value;
+            self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                temp_graph_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
     fn reset_zoom(&mut self) {
         match self.current_widget.widget_type {
             BottomWidgetType::Cpu => self.reset_cpu_zoom(),
             BottomWidgetType::Mem => self.reset_mem_zoom(),
+            BottomWidgetType::Pressure => self.reset_pressure_zoom(),
+            BottomWidgetType::CpuFreq => self.reset_cpu_freq_zoom(),
             BottomWidgetType::Net => self.reset_net_zoom(),
+            BottomWidgetType::DiskIo => self.reset_disk_io_zoom(),
+            BottomWidgetType::TempGraph => self.reset_temp_graph_zoom(),
             _ => {}
         }
     }
@@ -2592,8 +3562,13 @@ impl App {
                     BottomWidgetType::Proc
                     | BottomWidgetType::ProcSort
                     | BottomWidgetType::CpuLegend
+                    | BottomWidgetType::DiskIoLegend
+                    | BottomWidgetType::TempGraphLegend
                     | BottomWidgetType::Temp
-                    | BottomWidgetType::Disk => {
+                    | BottomWidgetType::Disk
+                    | BottomWidgetType::NetInterfaces
+                    | BottomWidgetType::Cgroups
+                    | BottomWidgetType::Sensors => {
                         // Get our index...
                         let clicked_entry = y - *tlc_y;
                         let header_offset = self.header_offset(&self.current_widget);
@@ -2653,18 +3628,20 @@ impl App {
                                         }
                                     }
                                 }
-                                BottomWidgetType::CpuLegend => {
-                                    if let Some(cpu_widget_state) = self
-                                        .cpu_state
-                                        .get_widget_state(self.current_widget.widget_id - 1)
+                                BottomWidgetType::CpuLegend
+                                | BottomWidgetType::DiskIoLegend
+                                | BottomWidgetType::TempGraphLegend => {
+                                    if let Some(visual_index) = self
+                                        .graph_legend_table_state_mut(
+                                            self.current_widget.widget_id - 1,
+                                        )
+                                        .and_then(|(table_state, _)| {
+                                            table_state.table_state.selected()
+                                        })
                                     {
-                                        if let Some(visual_index) =
-                                            cpu_widget_state.table_state.table_state.selected()
-                                        {
-                                            self.change_cpu_legend_position(
-                                                offset_clicked_entry as i64 - visual_index as i64,
-                                            );
-                                        }
+                                        self.change_graph_legend_position(
+                                            offset_clicked_entry as i64 - visual_index as i64,
+                                        );
                                     }
                                 }
                                 BottomWidgetType::Temp => {
@@ -2695,6 +3672,50 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::NetInterfaces => {
+                                    if let Some(net_interface_widget_state) = self
+                                        .net_interface_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) = net_interface_widget_state
+                                            .table_state
+                                            .table_state
+                                            .selected()
+                                        {
+                                            self.change_net_interface_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::Cgroups => {
+                                    if let Some(cgroup_widget_state) = self
+                                        .cgroup_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            cgroup_widget_state.table_state.table_state.selected()
+                                        {
+                                            self.change_cgroup_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::Sensors => {
+                                    if let Some(sensor_widget_state) = self
+                                        .sensor_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            sensor_widget_state.table_state.table_state.selected()
+                                        {
+                                            self.change_sensor_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 _ => {}
                             }
                         } else {
@@ -13,22 +13,23 @@
 //! memory usage and higher CPU usage - you will be trying to process more and
 //! more points as this is used!
 
-use once_cell::sync::Lazy;
-
-use fxhash::FxHashMap;
+use fxhash::{FxHashMap, FxHashSet};
 use itertools::Itertools;
 
-use std::{time::Instant, vec::Vec};
+use std::{collections::VecDeque, time::Instant, vec::Vec};
 
 #[cfg(feature = "battery")]
 use crate::data_harvester::batteries;
 
 use crate::{
-    data_harvester::{cpu, disks, memory, network, processes::ProcessHarvest, temperature, Data},
+    data_harvester::{
+        cgroups, cpu, disks, memory, network, pressure,
+        processes::{ProcessDetails, ProcessHarvest},
+        sensors, temperature, Data,
+    },
     utils::gen_util::{get_decimal_bytes, GIGA_LIMIT},
     Pid,
 };
-use regex::Regex;
 
 pub type TimeOffset = f64;
 pub type Value = f64;
@@ -37,14 +38,56 @@ pub type Value = f64;
 pub struct TimedData {
     pub rx_data: Value,
     pub tx_data: Value,
+    /// The RX and TX of each interface, by name.
+    pub interface_data: FxHashMap<String, (Value, Value)>,
+    /// The read and write rates of each disk, in bytes per second, by name.
+    pub io_data: FxHashMap<String, (Value, Value)>,
+    /// The temperature of each sensor, by name.
+    pub temp_data: FxHashMap<String, Value>,
     pub cpu_data: Vec<Value>,
+    /// How the time of each CPU was spent, in the same order as `cpu_data`.
+    pub cpu_breakdown_data: Vec<Option<cpu::CpuTimeBreakdown>>,
+    /// The frequency of each CPU in kHz, in the same order as `cpu_data`.
+    pub cpu_frequency_data: Vec<Option<Value>>,
     pub load_avg_data: [f32; 3],
     pub mem_data: Option<Value>,
+    /// The buffers and cache, as percentages of the total RAM.
+    pub mem_breakdown_data: Option<(Value, Value)>,
     pub swap_data: Option<Value>,
+    pub pressure_data: Option<pressure::PressureHarvest>,
 }
 
 pub type StringPidMap = FxHashMap<String, Vec<Pid>>;
 
+/// The usage of a single process at one point in time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProcessSample {
+    pub instant: Instant,
+    pub cpu_usage_percent: f64,
+    pub mem_usage_bytes: u64,
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+}
+
+impl ProcessSample {
+    fn new(instant: Instant, process: &ProcessHarvest) -> Self {
+        ProcessSample {
+            instant,
+            cpu_usage_percent: process.cpu_usage_percent,
+            mem_usage_bytes: process.mem_usage_bytes,
+            read_bytes_per_sec: process.read_bytes_per_sec,
+            write_bytes_per_sec: process.write_bytes_per_sec,
+        }
+    }
+
+    fn add(&mut self, other: &ProcessSample) {
+        self.cpu_usage_percent += other.cpu_usage_percent;
+        self.mem_usage_bytes += other.mem_usage_bytes;
+        self.read_bytes_per_sec += other.read_bytes_per_sec;
+        self.write_bytes_per_sec += other.write_bytes_per_sec;
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct ProcessData {
     /// A PID to process data map.
@@ -56,18 +99,29 @@ pub struct ProcessData {
     /// A mapping from a process command to any PID with that name.
     pub cmd_pid_map: StringPidMap,
 
+    /// A mapping from a process' [cgroup label](ProcessHarvest::cgroup_label) to any PID with that label.
+    pub cgroup_pid_map: StringPidMap,
+
     /// A mapping between a process PID to any children process PIDs.
     pub process_parent_mapping: FxHashMap<Pid, Vec<Pid>>,
 
     /// PIDs corresponding to processes that have no parents.
     pub orphan_pids: Vec<Pid>,
+
+    /// The recent usage of each process, oldest first. Each is capped at [`ProcessData::MAX_HISTORY`] samples,
+    /// and samples go stale at the same time as the rest of the data.
+    pub history: FxHashMap<Pid, VecDeque<ProcessSample>>,
 }
 
 impl ProcessData {
-    fn ingest(&mut self, list_of_processes: Vec<ProcessHarvest>) {
+    /// How many samples are kept per process.
+    pub const MAX_HISTORY: usize = 120;
+
+    fn ingest(&mut self, list_of_processes: Vec<ProcessHarvest>, harvested_time: Instant) {
         // TODO: [Optimization] Probably more efficient to all of this in the data collection step, but it's fine for now.
         self.name_pid_map.clear();
         self.cmd_pid_map.clear();
+        self.cgroup_pid_map.clear();
         self.process_parent_mapping.clear();
 
         // Reverse as otherwise the pid mappings are in the wrong order.
@@ -88,6 +142,14 @@ impl ProcessData {
                 );
             }
 
+            let cgroup_label = process_harvest.cgroup_label();
+            if let Some(entry) = self.cgroup_pid_map.get_mut(cgroup_label) {
+                entry.push(process_harvest.pid);
+            } else {
+                self.cgroup_pid_map
+                    .insert(cgroup_label.to_string(), vec![process_harvest.pid]);
+            }
+
             if let Some(parent_pid) = process_harvest.parent_pid {
                 if let Some(entry) = self.process_parent_mapping.get_mut(&parent_pid) {
                     entry.push(process_harvest.pid);
@@ -100,6 +162,7 @@ impl ProcessData {
 
         self.name_pid_map.shrink_to_fit();
         self.cmd_pid_map.shrink_to_fit();
+        self.cgroup_pid_map.shrink_to_fit();
         self.process_parent_mapping.shrink_to_fit();
 
         let process_pid_map = list_of_processes
@@ -108,6 +171,17 @@ impl ProcessData {
             .collect();
         self.process_harvest = process_pid_map;
 
+        let process_harvest = &self.process_harvest;
+        self.history
+            .retain(|pid, _| process_harvest.contains_key(pid));
+        for (pid, process) in process_harvest {
+            let samples = self.history.entry(*pid).or_default();
+            if samples.len() == Self::MAX_HISTORY {
+                samples.pop_front();
+            }
+            samples.push_back(ProcessSample::new(harvested_time, process));
+        }
+
         // This also needs a quick sort + reverse to be in the correct order.
         self.orphan_pids = {
             let mut res: Vec<Pid> = self
@@ -132,6 +206,91 @@ impl ProcessData {
             res
         }
     }
+
+    /// Returns the summed history of the given processes, oldest first, keeping at most the `len` most recent
+    /// samples. Samples of different processes are combined if they were collected at the same time.
+    pub fn combined_history(&self, pids: &[Pid], len: usize) -> Vec<ProcessSample> {
+        let recent = |samples: &VecDeque<ProcessSample>| {
+            samples
+                .iter()
+                .skip(samples.len().saturating_sub(len))
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        match pids {
+            [pid] => self.history.get(pid).map(recent).unwrap_or_default(),
+            pids => {
+                let mut combined: Vec<ProcessSample> = pids
+                    .iter()
+                    .filter_map(|pid| self.history.get(pid))
+                    .flat_map(recent)
+                    .sorted_by_key(|sample| sample.instant)
+                    .collect();
+                combined.dedup_by(|sample, prev| {
+                    if sample.instant == prev.instant {
+                        prev.add(sample);
+                        true
+                    } else {
+                        false
+                    }
+                });
+
+                combined
+            }
+        }
+    }
+
+    /// Drops any samples collected before `oldest`.
+    fn clean_history(&mut self, oldest: Instant) {
+        self.history.retain(|_, samples| {
+            while samples
+                .front()
+                .map(|sample| sample.instant < oldest)
+                .unwrap_or(false)
+            {
+                samples.pop_front();
+            }
+            !samples.is_empty()
+        });
+    }
+
+    /// Returns a PID along with the PIDs of all of its descendants, parents first.
+    pub fn subtree_pids(&self, pid: Pid) -> Vec<Pid> {
+        let mut pids = vec![pid];
+        // Guard against cycles, in case a PID was reused while collecting.
+        let mut seen: FxHashSet<Pid> = pids.iter().copied().collect();
+        let mut index = 0;
+        while let Some(current_pid) = pids.get(index) {
+            if let Some(children) = self.process_parent_mapping.get(current_pid) {
+                let children = children
+                    .iter()
+                    .filter(|child| seen.insert(**child))
+                    .copied()
+                    .collect::<Vec<_>>();
+                pids.extend(children);
+            }
+            index += 1;
+        }
+
+        pids
+    }
+
+    /// Returns the given PIDs along with all of their descendants, ordered so that every process comes before
+    /// its parent.
+    pub fn subtrees_leaf_first(&self, roots: &[Pid]) -> Vec<Pid> {
+        let mut pids: Vec<Pid> = Vec::new();
+        let mut seen: FxHashSet<Pid> = FxHashSet::default();
+        for root in roots {
+            for pid in self.subtree_pids(*root).into_iter().rev() {
+                if seen.insert(pid) {
+                    pids.push(pid);
+                }
+            }
+        }
+
+        pids
+    }
 }
 
 /// AppCollection represents the pooled data stored within the main app
@@ -154,11 +313,16 @@ pub struct DataCollection {
     pub cpu_harvest: cpu::CpuHarvest,
     pub load_avg_harvest: cpu::LoadAvgHarvest,
     pub process_data: ProcessData,
+    /// The details of the process being looked at, as of the last time they were collected.
+    pub process_details: Option<ProcessDetails>,
     pub disk_harvest: Vec<disks::DiskHarvest>,
     pub io_harvest: disks::IoHarvest,
     pub io_labels_and_prev: Vec<((u64, u64), (u64, u64))>,
     pub io_labels: Vec<(String, String)>,
     pub temp_harvest: Vec<temperature::TempHarvest>,
+    pub cgroup_harvest: Vec<cgroups::CgroupHarvest>,
+    pub sensor_harvest: Vec<sensors::SensorHarvest>,
+    pub pressure_harvest: pressure::PressureHarvest,
     #[cfg(feature = "battery")]
     pub battery_harvest: Vec<batteries::BatteryHarvest>,
 }
@@ -175,11 +339,15 @@ impl Default for DataCollection {
             cpu_harvest: cpu::CpuHarvest::default(),
             load_avg_harvest: cpu::LoadAvgHarvest::default(),
             process_data: Default::default(),
+            process_details: None,
             disk_harvest: Vec::default(),
             io_harvest: disks::IoHarvest::default(),
             io_labels_and_prev: Vec::default(),
             io_labels: Vec::default(),
             temp_harvest: Vec::default(),
+            cgroup_harvest: Vec::default(),
+            sensor_harvest: Vec::default(),
+            pressure_harvest: pressure::PressureHarvest::default(),
             #[cfg(feature = "battery")]
             battery_harvest: Vec::default(),
         }
@@ -194,10 +362,14 @@ impl DataCollection {
         self.swap_harvest = memory::MemHarvest::default();
         self.cpu_harvest = cpu::CpuHarvest::default();
         self.process_data = Default::default();
+        self.process_details = None;
         self.disk_harvest = Vec::default();
         self.io_harvest = disks::IoHarvest::default();
         self.io_labels_and_prev = Vec::default();
         self.temp_harvest = Vec::default();
+        self.cgroup_harvest = Vec::default();
+        self.sensor_harvest = Vec::default();
+        self.pressure_harvest = pressure::PressureHarvest::default();
         #[cfg(feature = "battery")]
         {
             self.battery_harvest = Vec::default();
@@ -229,6 +401,12 @@ impl DataCollection {
         };
 
         self.timed_data_vec.drain(0..remove_index);
+
+        if let Some(oldest) =
+            current_time.checked_sub(std::time::Duration::from_millis(max_time_millis))
+        {
+            self.process_data.clean_history(oldest);
+        }
     }
 
     pub fn eat_data(&mut self, harvested_data: Box<Data>) {
@@ -259,19 +437,35 @@ impl DataCollection {
 
         // Temp
         if let Some(temperature_sensors) = harvested_data.temperature_sensors {
-            self.eat_temp(temperature_sensors);
+            self.eat_temp(temperature_sensors, &mut new_entry);
         }
 
         // Disks
         if let Some(disks) = harvested_data.disks {
             if let Some(io) = harvested_data.io {
-                self.eat_disks(disks, io, harvested_time);
+                self.eat_disks(disks, io, harvested_time, &mut new_entry);
             }
         }
 
         // Processes
         if let Some(list_of_processes) = harvested_data.list_of_processes {
-            self.eat_proc(list_of_processes);
+            self.eat_proc(list_of_processes, harvested_time);
+        }
+
+        // Cgroups
+        if let Some(cgroups) = harvested_data.cgroups {
+            self.cgroup_harvest = cgroups;
+        }
+
+        // Sensors
+        if let Some(sensors) = harvested_data.sensors {
+            self.sensor_harvest = sensors;
+        }
+
+        // Pressure
+        if let Some(pressure) = harvested_data.pressure {
+            new_entry.pressure_data = Some(pressure);
+            self.pressure_harvest = pressure;
         }
 
         #[cfg(feature = "battery")]
@@ -292,6 +486,15 @@ impl DataCollection {
     ) {
         // Memory
         new_entry.mem_data = memory.use_percent;
+        if let Some(breakdown) = &memory.breakdown {
+            if memory.mem_total_in_kib > 0 {
+                let total = memory.mem_total_in_kib as f64;
+                new_entry.mem_breakdown_data = Some((
+                    breakdown.buffers_in_kib as f64 / total * 100.0,
+                    breakdown.cache_in_kib() as f64 / total * 100.0,
+                ));
+            }
+        }
 
         // Swap
         new_entry.swap_data = swap.use_percent;
@@ -312,6 +515,18 @@ impl DataCollection {
             new_entry.tx_data = network.tx as f64;
         }
 
+        // Per-interface
+        new_entry.interface_data = network
+            .interfaces
+            .iter()
+            .map(|interface| {
+                (
+                    interface.name.clone(),
+                    (interface.rx as f64, interface.tx as f64),
+                )
+            })
+            .collect();
+
         // In addition copy over latest data for easy reference
         self.network_harvest = network;
     }
@@ -320,8 +535,14 @@ impl DataCollection {
         // Note this only pre-calculates the data points - the names will be
         // within the local copy of cpu_harvest.  Since it's all sequential
         // it probably doesn't matter anyways.
-        cpu.iter()
-            .for_each(|cpu| new_entry.cpu_data.push(cpu.cpu_usage));
+        cpu.iter().for_each(|cpu| {
+            new_entry.cpu_data.push(cpu.cpu_usage);
+            new_entry.cpu_breakdown_data.push(cpu.breakdown);
+            new_entry.cpu_frequency_data.push(
+                cpu.frequency
+                    .map(|frequency| frequency.current_khz as Value),
+            );
+        });
 
         self.cpu_harvest = cpu.to_vec();
     }
@@ -332,13 +553,20 @@ impl DataCollection {
         self.load_avg_harvest = load_avg;
     }
 
-    fn eat_temp(&mut self, temperature_sensors: Vec<temperature::TempHarvest>) {
-        // TODO: [PO] To implement
+    fn eat_temp(
+        &mut self, temperature_sensors: Vec<temperature::TempHarvest>, new_entry: &mut TimedData,
+    ) {
+        new_entry.temp_data = temperature_sensors
+            .iter()
+            .map(|sensor| (sensor.name.clone(), sensor.temperature as f64))
+            .collect();
+
         self.temp_harvest = temperature_sensors.to_vec();
     }
 
     fn eat_disks(
         &mut self, disks: Vec<disks::DiskHarvest>, io: disks::IoHarvest, harvested_time: Instant,
+        new_entry: &mut TimedData,
     ) {
         // TODO: [PO] To implement
 
@@ -347,71 +575,61 @@ impl DataCollection {
             .as_secs_f64();
 
         for (itx, device) in disks.iter().enumerate() {
-            if let Some(trim) = device.name.split('/').last() {
-                let io_device = if cfg!(target_os = "macos") {
-                    // Must trim one level further for macOS!
-                    static DISK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"disk\d+").unwrap());
-                    if let Some(disk_trim) = DISK_REGEX.find(trim) {
-                        io.get(disk_trim.as_str())
-                    } else {
-                        None
-                    }
+            if let Some(io_device) = disks::get_disk_io(&io, &device.name) {
+                let (io_r_pt, io_w_pt) = if let Some(io) = io_device {
+                    (io.read_bytes, io.write_bytes)
                 } else {
-                    io.get(trim)
+                    (0, 0)
                 };
 
-                if let Some(io_device) = io_device {
-                    let (io_r_pt, io_w_pt) = if let Some(io) = io_device {
-                        (io.read_bytes, io.write_bytes)
-                    } else {
-                        (0, 0)
-                    };
+                if self.io_labels.len() <= itx {
+                    self.io_labels.push((String::default(), String::default()));
+                }
 
-                    if self.io_labels.len() <= itx {
-                        self.io_labels.push((String::default(), String::default()));
-                    }
+                if self.io_labels_and_prev.len() <= itx {
+                    self.io_labels_and_prev.push(((0, 0), (io_r_pt, io_w_pt)));
+                }
 
-                    if self.io_labels_and_prev.len() <= itx {
-                        self.io_labels_and_prev.push(((0, 0), (io_r_pt, io_w_pt)));
-                    }
+                if let Some((io_curr, io_prev)) = self.io_labels_and_prev.get_mut(itx) {
+                    let r_rate = ((io_r_pt.saturating_sub(io_prev.0)) as f64
+                        / time_since_last_harvest)
+                        .round() as u64;
+                    let w_rate = ((io_w_pt.saturating_sub(io_prev.1)) as f64
+                        / time_since_last_harvest)
+                        .round() as u64;
 
-                    if let Some((io_curr, io_prev)) = self.io_labels_and_prev.get_mut(itx) {
-                        let r_rate = ((io_r_pt.saturating_sub(io_prev.0)) as f64
-                            / time_since_last_harvest)
-                            .round() as u64;
-                        let w_rate = ((io_w_pt.saturating_sub(io_prev.1)) as f64
-                            / time_since_last_harvest)
-                            .round() as u64;
-
-                        *io_curr = (r_rate, w_rate);
-                        *io_prev = (io_r_pt, io_w_pt);
-
-                        if let Some(io_labels) = self.io_labels.get_mut(itx) {
-                            let converted_read = get_decimal_bytes(r_rate);
-                            let converted_write = get_decimal_bytes(w_rate);
-                            *io_labels = (
-                                if r_rate >= GIGA_LIMIT {
-                                    format!("{:.*}{}/s", 1, converted_read.0, converted_read.1)
-                                } else {
-                                    format!("{:.*}{}/s", 0, converted_read.0, converted_read.1)
-                                },
-                                if w_rate >= GIGA_LIMIT {
-                                    format!("{:.*}{}/s", 1, converted_write.0, converted_write.1)
-                                } else {
-                                    format!("{:.*}{}/s", 0, converted_write.0, converted_write.1)
-                                },
-                            );
-                        }
-                    }
-                } else {
-                    if self.io_labels.len() <= itx {
-                        self.io_labels.push((String::default(), String::default()));
-                    }
+                    *io_curr = (r_rate, w_rate);
+                    *io_prev = (io_r_pt, io_w_pt);
+
+                    new_entry
+                        .io_data
+                        .insert(device.name.clone(), (r_rate as f64, w_rate as f64));
 
                     if let Some(io_labels) = self.io_labels.get_mut(itx) {
-                        *io_labels = ("N/A".to_string(), "N/A".to_string());
+                        let converted_read = get_decimal_bytes(r_rate);
+                        let converted_write = get_decimal_bytes(w_rate);
+                        *io_labels = (
+                            if r_rate >= GIGA_LIMIT {
+                                format!("{:.*}{}/s", 1, converted_read.0, converted_read.1)
+                            } else {
+                                format!("{:.*}{}/s", 0, converted_read.0, converted_read.1)
+                            },
+                            if w_rate >= GIGA_LIMIT {
+                                format!("{:.*}{}/s", 1, converted_write.0, converted_write.1)
+                            } else {
+                                format!("{:.*}{}/s", 0, converted_write.0, converted_write.1)
+                            },
+                        );
                     }
                 }
+            } else {
+                if self.io_labels.len() <= itx {
+                    self.io_labels.push((String::default(), String::default()));
+                }
+
+                if let Some(io_labels) = self.io_labels.get_mut(itx) {
+                    *io_labels = ("N/A".to_string(), "N/A".to_string());
+                }
             }
         }
 
@@ -419,8 +637,8 @@ impl DataCollection {
         self.io_harvest = io;
     }
 
-    fn eat_proc(&mut self, list_of_processes: Vec<ProcessHarvest>) {
-        self.process_data.ingest(list_of_processes);
+    fn eat_proc(&mut self, list_of_processes: Vec<ProcessHarvest>, harvested_time: Instant) {
+        self.process_data.ingest(list_of_processes, harvested_time);
     }
 
     #[cfg(feature = "battery")]
@@ -428,3 +646,92 @@ impl DataCollection {
         self.battery_harvest = list_of_batteries;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::time::Duration;
+
+    fn process(pid: Pid, cpu_usage_percent: f64) -> ProcessHarvest {
+        ProcessHarvest {
+            pid,
+            cpu_usage_percent,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn process_history() {
+        let start = Instant::now();
+        let mut process_data = ProcessData::default();
+        for tick in 0..ProcessData::MAX_HISTORY as u64 + 5 {
+            process_data.ingest(
+                vec![process(1, 1.0), process(2, tick as f64)],
+                start + Duration::from_secs(tick),
+            );
+        }
+        assert_eq!(process_data.history[&1].len(), ProcessData::MAX_HISTORY);
+        assert_eq!(
+            process_data.history[&2].front().unwrap().cpu_usage_percent,
+            5.0
+        );
+
+        let combined = process_data.combined_history(&[1, 2], usize::MAX);
+        assert_eq!(combined.len(), ProcessData::MAX_HISTORY);
+        assert_eq!(
+            combined.last().unwrap().cpu_usage_percent,
+            1.0 + ProcessData::MAX_HISTORY as f64 + 4.0
+        );
+
+        let recent = process_data.combined_history(&[1, 2], 3);
+        assert_eq!(recent.len(), 3);
+        assert_eq!(recent.last(), combined.last());
+        assert_eq!(
+            process_data.combined_history(&[2], 3)[0].cpu_usage_percent,
+            { ProcessData::MAX_HISTORY as f64 + 2.0 }
+        );
+
+        // Processes that are gone lose their history.
+        let now = start + Duration::from_secs(ProcessData::MAX_HISTORY as u64 + 5);
+        process_data.ingest(vec![process(2, 0.0)], now);
+        assert!(!process_data.history.contains_key(&1));
+
+        process_data.clean_history(now - Duration::from_secs(1));
+        assert_eq!(process_data.history[&2].len(), 2);
+        process_data.clean_history(now + Duration::from_secs(1));
+        assert!(process_data.history.is_empty());
+    }
+
+    #[test]
+    fn process_subtrees() {
+        let child = |pid: Pid, parent_pid: Pid| ProcessHarvest {
+            pid,
+            parent_pid: Some(parent_pid),
+            ..Default::default()
+        };
+
+        let mut process_data = ProcessData::default();
+        process_data.ingest(
+            vec![
+                process(1, 0.0),
+                child(2, 1),
+                child(3, 1),
+                child(4, 2),
+                // A cycle, which can happen if a PID was reused while collecting.
+                child(5, 6),
+                child(6, 5),
+            ],
+            Instant::now(),
+        );
+
+        assert_eq!(process_data.subtree_pids(1), vec![1, 3, 2, 4]);
+        assert_eq!(process_data.subtree_pids(4), vec![4]);
+        assert_eq!(process_data.subtree_pids(5), vec![5, 6]);
+        assert_eq!(process_data.subtree_pids(7), vec![7]);
+
+        // Every process comes before its parent, and overlapping subtrees are only listed once.
+        assert_eq!(process_data.subtrees_leaf_first(&[2, 1]), vec![4, 2, 3, 1]);
+        assert_eq!(process_data.subtrees_leaf_first(&[5]), vec![6, 5]);
+    }
+}
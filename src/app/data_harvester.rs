@@ -2,6 +2,8 @@
 
 use std::time::Instant;
 
+use serde::{Deserialize, Serialize};
+
 #[cfg(target_os = "linux")]
 use fxhash::FxHashMap;
 
@@ -19,15 +21,29 @@ use super::DataFilters;
 
 #[cfg(feature = "battery")]
 pub mod batteries;
+pub mod cgroups;
 pub mod cpu;
 pub mod disks;
 pub mod memory;
 pub mod network;
+pub mod pressure;
 pub mod processes;
+pub mod sensors;
 pub mod temperature;
 
-#[derive(Clone, Debug)]
+/// Reads a file holding a single value, like most of those under `/sys`. Returns `None` if it can't be read or
+/// parsed.
+pub(crate) fn read_value<T: std::str::FromStr>(dir: &std::path::Path, file: &str) -> Option<T> {
+    std::fs::read_to_string(dir.join(file))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Data {
+    #[serde(skip, default = "Instant::now")]
     pub last_collection_time: Instant,
     pub cpu: Option<cpu::CpuHarvest>,
     pub load_avg: Option<cpu::LoadAvgHarvest>,
@@ -38,6 +54,12 @@ pub struct Data {
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
     pub io: Option<disks::IoHarvest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroups: Option<Vec<cgroups::CgroupHarvest>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<pressure::PressureHarvest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensors: Option<Vec<sensors::SensorHarvest>>,
     #[cfg(feature = "battery")]
     pub list_of_batteries: Option<Vec<batteries::BatteryHarvest>>,
 }
@@ -54,6 +76,9 @@ impl Default for Data {
             list_of_processes: None,
             disks: None,
             io: None,
+            cgroups: None,
+            pressure: None,
+            sensors: None,
             network: None,
             #[cfg(feature = "battery")]
             list_of_batteries: None,
@@ -71,6 +96,9 @@ impl Data {
         self.swap = None;
         self.cpu = None;
         self.load_avg = None;
+        self.cgroups = None;
+        self.pressure = None;
+        self.sensors = None;
 
         if let Some(network) = &mut self.network {
             network.first_run_cleanup();
@@ -83,8 +111,14 @@ pub struct DataCollector {
     pub data: Data,
     #[cfg(not(target_os = "linux"))]
     sys: System,
-    previous_cpu_times: Vec<(cpu::PastCpuWork, cpu::PastCpuTotal)>,
-    previous_average_cpu_time: Option<(cpu::PastCpuWork, cpu::PastCpuTotal)>,
+    previous_cpu_times: Vec<cpu::CpuTimes>,
+    previous_average_cpu_time: Option<cpu::CpuTimes>,
+    /// The topology of each CPU by its ID, which is only read again if a CPU comes online.
+    #[cfg(target_os = "linux")]
+    cpu_topology: FxHashMap<usize, cpu::topology::CpuTopology>,
+    /// The frequency limits of each CPU by its ID, which are only read the first time it is seen.
+    #[cfg(target_os = "linux")]
+    cpu_frequency_limits: FxHashMap<usize, cpu::frequency::CpuFrequencyLimits>,
     #[cfg(target_os = "linux")]
     pid_mapping: FxHashMap<crate::Pid, processes::PrevProcDetails>,
     #[cfg(target_os = "linux")]
@@ -97,6 +131,9 @@ pub struct DataCollector {
     last_collection_time: Instant,
     total_rx: u64,
     total_tx: u64,
+    prev_interface_totals: network::PrevInterfaceTotals,
+    prev_cgroup_totals: cgroups::PrevCgroupTotals,
+    prev_rapl_energy: sensors::PrevRaplEnergy,
     show_average_cpu: bool,
     widgets_to_harvest: UsedWidgets,
     #[cfg(feature = "battery")]
@@ -104,6 +141,17 @@ pub struct DataCollector {
     #[cfg(feature = "battery")]
     battery_list: Option<Vec<Battery>>,
     filters: DataFilters,
+    /// The process to collect details for, if any.
+    details_pid: Option<crate::Pid>,
+    /// The processes to collect threads for. Only Linux collects threads.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    thread_pids: fxhash::FxHashSet<crate::Pid>,
+    /// Whether to collect the PSS of processes, which is slow. Only Linux collects the PSS.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    collect_pss: bool,
+    /// The details of the process with `details_pid`, as of the last harvest. These are kept out of [`Data`] so that
+    /// things like the environment of a process are never recorded or logged, and are sent on their own instead.
+    pub process_details: Option<processes::ProcessDetails>,
 
     #[cfg(target_family = "unix")]
     user_table: self::processes::UserTable,
@@ -118,6 +166,10 @@ impl DataCollector {
             previous_cpu_times: vec![],
             previous_average_cpu_time: None,
             #[cfg(target_os = "linux")]
+            cpu_topology: FxHashMap::default(),
+            #[cfg(target_os = "linux")]
+            cpu_frequency_limits: FxHashMap::default(),
+            #[cfg(target_os = "linux")]
             pid_mapping: FxHashMap::default(),
             #[cfg(target_os = "linux")]
             prev_idle: 0_f64,
@@ -129,6 +181,9 @@ impl DataCollector {
             last_collection_time: Instant::now(),
             total_rx: 0,
             total_tx: 0,
+            prev_interface_totals: Default::default(),
+            prev_cgroup_totals: Default::default(),
+            prev_rapl_energy: Default::default(),
             show_average_cpu: false,
             widgets_to_harvest: UsedWidgets::default(),
             #[cfg(feature = "battery")]
@@ -136,6 +191,10 @@ impl DataCollector {
             #[cfg(feature = "battery")]
             battery_list: None,
             filters,
+            details_pid: None,
+            thread_pids: Default::default(),
+            collect_pss: false,
+            process_details: None,
             #[cfg(target_family = "unix")]
             user_table: Default::default(),
         }
@@ -212,6 +271,69 @@ impl DataCollector {
         self.show_average_cpu = show_average_cpu;
     }
 
+    pub fn set_details_pid(&mut self, details_pid: Option<crate::Pid>) {
+        self.details_pid = details_pid;
+    }
+
+    pub fn set_collect_pss(&mut self, collect_pss: bool) {
+        self.collect_pss = collect_pss;
+    }
+
+    pub fn set_thread_pids(&mut self, thread_pids: fxhash::FxHashSet<crate::Pid>) {
+        self.thread_pids = thread_pids;
+    }
+
+    /// Fills in where each CPU is, reading the topology again if a CPU has come online since it was last read.
+    #[cfg(target_os = "linux")]
+    fn add_cpu_topology(&mut self, mut cpu_data: cpu::CpuHarvest) -> cpu::CpuHarvest {
+        let cpu_ids: Vec<usize> = cpu_data.iter().filter_map(|cpu| cpu.cpu_id).collect();
+        if cpu_ids
+            .iter()
+            .any(|cpu_id| !self.cpu_topology.contains_key(cpu_id))
+        {
+            self.cpu_topology = cpu::topology::get_cpu_topology(
+                std::path::Path::new(cpu::CPU_DIR),
+                std::path::Path::new(cpu::topology::NODE_DIR),
+                &cpu_ids,
+            )
+            .into_iter()
+            .collect();
+        }
+
+        for cpu in cpu_data.iter_mut() {
+            if let Some(cpu_id) = cpu.cpu_id {
+                cpu.topology = self.cpu_topology.get(&cpu_id).copied();
+            }
+        }
+
+        cpu_data
+    }
+
+    /// Fills in the frequency of each CPU. The throttle counts are only read if the CPU frequency widget, which
+    /// is the only thing that shows them, is used.
+    #[cfg(target_os = "linux")]
+    fn add_cpu_frequency(&mut self, mut cpu_data: cpu::CpuHarvest) -> cpu::CpuHarvest {
+        let cpu_dir = std::path::Path::new(cpu::CPU_DIR);
+        let read_throttle_counts = self.widgets_to_harvest.use_cpu_freq;
+
+        for cpu in cpu_data.iter_mut() {
+            if let Some(cpu_id) = cpu.cpu_id {
+                let limits = *self
+                    .cpu_frequency_limits
+                    .entry(cpu_id)
+                    .or_insert_with(|| cpu::frequency::get_cpu_frequency_limits(cpu_dir, cpu_id));
+                cpu.frequency = cpu::frequency::get_cpu_frequency(
+                    cpu_dir,
+                    cpu_id,
+                    limits,
+                    read_throttle_counts,
+                );
+            }
+        }
+
+        cpu_data
+    }
+
     pub async fn update_data(&mut self) {
         #[cfg(not(target_os = "linux"))]
         {
@@ -239,6 +361,11 @@ impl DataCollector {
             )
             .await
             {
+                #[cfg(target_os = "linux")]
+                let cpu_data = self.add_cpu_topology(cpu_data);
+                #[cfg(target_os = "linux")]
+                let cpu_data = self.add_cpu_frequency(cpu_data);
+
                 self.data.cpu = Some(cpu_data);
             }
 
@@ -270,6 +397,8 @@ impl DataCollector {
                         &mut self.prev_idle,
                         &mut self.prev_non_idle,
                         &mut self.pid_mapping,
+                        &self.thread_pids,
+                        self.collect_pss,
                         self.use_current_cpu_total,
                         current_instant
                             .duration_since(self.last_collection_time)
@@ -301,6 +430,41 @@ impl DataCollector {
             } {
                 self.data.list_of_processes = Some(process_list);
             }
+
+            #[cfg(target_os = "linux")]
+            {
+                self.process_details = self
+                    .details_pid
+                    .and_then(|pid| self.pid_mapping.get(&pid))
+                    .map(processes::get_process_details);
+            }
+        }
+
+        if self.widgets_to_harvest.use_cgroup {
+            self.data.cgroups = cgroups::get_cgroup_data(
+                std::path::Path::new(cgroups::CGROUP_ROOT),
+                &mut self.prev_cgroup_totals,
+                current_instant
+                    .duration_since(self.last_collection_time)
+                    .as_secs_f64(),
+            );
+        }
+
+        if self.widgets_to_harvest.use_pressure {
+            self.data.pressure =
+                pressure::get_pressure_data(std::path::Path::new(pressure::PRESSURE_DIR));
+        }
+
+        if self.widgets_to_harvest.use_sensors {
+            self.data.sensors = Some(sensors::get_sensor_data(
+                std::path::Path::new(sensors::HWMON_DIR),
+                std::path::Path::new(sensors::POWERCAP_DIR),
+                &self.filters.sensor_filter,
+                &mut self.prev_rapl_energy,
+                current_instant
+                    .duration_since(self.last_collection_time)
+                    .as_secs_f64(),
+            ));
         }
 
         let network_data_fut = {
@@ -311,6 +475,7 @@ impl DataCollector {
                     self.last_collection_time,
                     &mut self.total_rx,
                     &mut self.total_tx,
+                    &mut self.prev_interface_totals,
                     current_instant,
                     self.widgets_to_harvest.use_net,
                     &self.filters.net_filter,
@@ -322,6 +487,7 @@ impl DataCollector {
                     self.last_collection_time,
                     &mut self.total_rx,
                     &mut self.total_tx,
+                    &mut self.prev_interface_totals,
                     current_instant,
                     self.widgets_to_harvest.use_net,
                     &self.filters.net_filter,
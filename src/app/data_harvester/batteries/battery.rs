@@ -9,12 +9,13 @@
 //!
 //! For more information, refer to the [starship_battery](https://github.com/starship/rust-battery) repo/docs.
 
+use serde::{Deserialize, Serialize};
 use starship_battery::{
     units::{power::watt, ratio::percent, time::second},
     Battery, Manager,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatteryHarvest {
     pub charge_percent: f64,
     pub secs_until_full: Option<i64>,
@@ -0,0 +1,276 @@
+//! Data collection for cgroup v2 groups.
+//!
+//! Groups are read straight from the files of the unified hierarchy, which is usually mounted at
+//! [`CGROUP_ROOT`]. Nothing is collected on systems that only have cgroup v1.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::data_harvester::read_value;
+
+/// Where the unified cgroup hierarchy is usually mounted.
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+/// Usage of a single cgroup. Usage of a group includes the usage of all of its descendants.
+pub struct CgroupHarvest {
+    /// The path of the group from the root of the hierarchy, like `/system.slice/ssh.service`. The root
+    /// itself is `/`.
+    pub path: String,
+    /// The CPU usage, where 100% is one whole CPU.
+    pub cpu_usage_percent: f64,
+    pub mem_current: Option<u64>,
+    /// The memory limit of the group, or `None` if it has none.
+    pub mem_max: Option<u64>,
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+    pub pids_current: Option<u64>,
+}
+
+impl CgroupHarvest {
+    /// The last part of the path of the group, or `/` for the root.
+    pub fn name(&self) -> &str {
+        match self.path.rsplit('/').next() {
+            Some(name) if !name.is_empty() => name,
+            _ => "/",
+        }
+    }
+
+    /// The path of the group containing this one, or `None` for the root.
+    pub fn parent_path(&self) -> Option<&str> {
+        if self.path == "/" {
+            return None;
+        }
+
+        match self.path.rfind('/') {
+            Some(0) => Some("/"),
+            Some(index) => Some(&self.path[..index]),
+            None => None,
+        }
+    }
+}
+
+/// The counters of a group that are turned into rates.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CgroupTotals {
+    pub cpu_usage_usec: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+}
+
+/// The previous counters of each group by path, used to work out per-group rates.
+pub type PrevCgroupTotals = fxhash::FxHashMap<String, CgroupTotals>;
+
+/// Reads `usage_usec` from the contents of a `cpu.stat` file.
+fn parse_cpu_usage_usec(cpu_stat: &str) -> Option<u64> {
+    cpu_stat.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some("usage_usec"), Some(usage)) => usage.parse().ok(),
+            _ => None,
+        }
+    })
+}
+
+/// Sums the bytes read and written over every device in the contents of an `io.stat` file.
+fn parse_io_bytes(io_stat: &str) -> (u64, u64) {
+    io_stat
+        .split_whitespace()
+        .filter_map(|field| field.split_once('='))
+        .fold((0, 0), |(read, write), (key, value)| {
+            let value = value.parse::<u64>().unwrap_or(0);
+            match key {
+                "rbytes" => (read + value, write),
+                "wbytes" => (read, write + value),
+                _ => (read, write),
+            }
+        })
+}
+
+/// Parses a single value like that of `memory.max`, where `max` means there is no limit.
+fn parse_limit(value: &str) -> Option<u64> {
+    match value.trim() {
+        "max" => None,
+        value => value.parse().ok(),
+    }
+}
+
+/// Reads a single group, returning its usage without rates, and its counters.
+fn read_cgroup(dir: &Path, path: String) -> (CgroupHarvest, CgroupTotals) {
+    let (read_bytes, write_bytes) = fs::read_to_string(dir.join("io.stat"))
+        .map(|io_stat| parse_io_bytes(&io_stat))
+        .unwrap_or_default();
+    let totals = CgroupTotals {
+        cpu_usage_usec: fs::read_to_string(dir.join("cpu.stat"))
+            .ok()
+            .and_then(|cpu_stat| parse_cpu_usage_usec(&cpu_stat))
+            .unwrap_or(0),
+        read_bytes,
+        write_bytes,
+    };
+
+    (
+        CgroupHarvest {
+            path,
+            mem_current: read_value(dir, "memory.current"),
+            mem_max: fs::read_to_string(dir.join("memory.max"))
+                .ok()
+                .and_then(|max| parse_limit(&max)),
+            pids_current: read_value(dir, "pids.current"),
+            ..CgroupHarvest::default()
+        },
+        totals,
+    )
+}
+
+/// Collects every group under `root`, sorted by path. Rates are worked out from `prev_totals`, which is then
+/// replaced with the current counters; groups that weren't seen last time start at a rate of zero.
+///
+/// Returns `None` if `root` isn't the root of a cgroup v2 hierarchy.
+pub fn get_cgroup_data(
+    root: &Path, prev_totals: &mut PrevCgroupTotals, elapsed_time: f64,
+) -> Option<Vec<CgroupHarvest>> {
+    if !root.join("cgroup.controllers").is_file() {
+        return None;
+    }
+
+    let mut cgroups = vec![];
+    let mut new_totals = PrevCgroupTotals::default();
+    let mut stack: Vec<(PathBuf, String)> = vec![(root.to_path_buf(), "/".to_string())];
+
+    while let Some((dir, path)) = stack.pop() {
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    let child_path = if path == "/" {
+                        format!("/{}", name)
+                    } else {
+                        format!("{}/{}", path, name)
+                    };
+                    stack.push((entry.path(), child_path));
+                }
+            }
+        }
+
+        let (mut cgroup, totals) = read_cgroup(&dir, path);
+        if let Some(prev) = prev_totals.get(&cgroup.path) {
+            if elapsed_time > 0.0 {
+                let rate =
+                    |current: u64, prev: u64| current.saturating_sub(prev) as f64 / elapsed_time;
+                cgroup.cpu_usage_percent =
+                    rate(totals.cpu_usage_usec, prev.cpu_usage_usec) * 100.0 / 1_000_000.0;
+                cgroup.read_bytes_per_sec = rate(totals.read_bytes, prev.read_bytes) as u64;
+                cgroup.write_bytes_per_sec = rate(totals.write_bytes, prev.write_bytes) as u64;
+            }
+        }
+        new_totals.insert(cgroup.path.clone(), totals);
+        cgroups.push(cgroup);
+    }
+
+    *prev_totals = new_totals;
+    cgroups.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Some(cgroups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::FakeDir;
+
+    /// A fake cgroup v2 hierarchy.
+    fn fake_hierarchy() -> FakeDir {
+        let hierarchy = FakeDir::default();
+        hierarchy.write("cgroup.controllers", "cpu io memory pids\n");
+        hierarchy
+    }
+
+    #[test]
+    fn test_cgroup_parse() {
+        assert_eq!(
+            parse_cpu_usage_usec("usage_usec 1234\nuser_usec 1000\nsystem_usec 234\n"),
+            Some(1234)
+        );
+        assert_eq!(parse_cpu_usage_usec("user_usec 1000\n"), None);
+
+        assert_eq!(
+            parse_io_bytes(
+                "8:0 rbytes=100 wbytes=20 rios=1 wios=1 dbytes=0 dios=0\n\
+                 259:0 rbytes=50 wbytes=5 rios=1 wios=1 dbytes=0 dios=0\n"
+            ),
+            (150, 25)
+        );
+        assert_eq!(parse_io_bytes(""), (0, 0));
+
+        assert_eq!(parse_limit("max\n"), None);
+        assert_eq!(parse_limit("1073741824\n"), Some(1073741824));
+    }
+
+    #[test]
+    fn test_cgroup_paths() {
+        let cgroup = |path: &str| CgroupHarvest {
+            path: path.to_string(),
+            ..CgroupHarvest::default()
+        };
+
+        assert_eq!(cgroup("/").name(), "/");
+        assert_eq!(cgroup("/").parent_path(), None);
+        assert_eq!(cgroup("/system.slice").name(), "system.slice");
+        assert_eq!(cgroup("/system.slice").parent_path(), Some("/"));
+        assert_eq!(cgroup("/system.slice/ssh.service").name(), "ssh.service");
+        assert_eq!(
+            cgroup("/system.slice/ssh.service").parent_path(),
+            Some("/system.slice")
+        );
+    }
+
+    #[test]
+    fn test_get_cgroup_data() {
+        let hierarchy = fake_hierarchy();
+        hierarchy.write("cpu.stat", "usage_usec 1000000\n");
+        hierarchy.write("ci.slice/cpu.stat", "usage_usec 500000\n");
+        hierarchy.write("ci.slice/memory.current", "1048576\n");
+        hierarchy.write("ci.slice/memory.max", "4194304\n");
+        hierarchy.write("ci.slice/pids.current", "12\n");
+        hierarchy.write("ci.slice/io.stat", "8:0 rbytes=1000 wbytes=2000\n");
+        hierarchy.write("ci.slice/job-1.scope/memory.max", "max\n");
+
+        let mut prev_totals = PrevCgroupTotals::default();
+        let cgroups = get_cgroup_data(hierarchy.path(), &mut prev_totals, 1.0).unwrap();
+        assert_eq!(
+            cgroups.iter().map(|c| c.path.as_str()).collect::<Vec<_>>(),
+            vec!["/", "/ci.slice", "/ci.slice/job-1.scope"]
+        );
+
+        let ci = &cgroups[1];
+        assert_eq!(ci.mem_current, Some(1048576));
+        assert_eq!(ci.mem_max, Some(4194304));
+        assert_eq!(ci.pids_current, Some(12));
+        assert_eq!(ci.cpu_usage_percent, 0.0);
+        assert_eq!(ci.read_bytes_per_sec, 0);
+        assert_eq!(cgroups[2].mem_max, None);
+
+        hierarchy.write("ci.slice/cpu.stat", "usage_usec 1500000\n");
+        hierarchy.write("ci.slice/io.stat", "8:0 rbytes=5000 wbytes=2000\n");
+
+        let cgroups = get_cgroup_data(hierarchy.path(), &mut prev_totals, 2.0).unwrap();
+        let ci = &cgroups[1];
+        assert_eq!(ci.cpu_usage_percent, 50.0);
+        assert_eq!(ci.read_bytes_per_sec, 2000);
+        assert_eq!(ci.write_bytes_per_sec, 0);
+        assert_eq!(prev_totals.len(), 3);
+    }
+
+    #[test]
+    fn test_get_cgroup_data_without_v2() {
+        let hierarchy = FakeDir::default();
+        hierarchy.write("cpu.stat", "usage_usec 1000000\n");
+
+        assert!(get_cgroup_data(hierarchy.path(), &mut PrevCgroupTotals::default(), 1.0).is_none());
+    }
+}
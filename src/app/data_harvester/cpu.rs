@@ -3,6 +3,8 @@
 //! For CPU usage, Linux, macOS, and Windows are handled by Heim.
 //!
 //! For load average, macOS and Linux are supported through Heim.
+//!
+//! CPU frequencies, throttling, and topology are only collected on Linux.
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))] {
@@ -11,4 +13,10 @@ cfg_if::cfg_if! {
     }
 }
 
+pub mod frequency;
+pub mod topology;
+
+/// Where the kernel reports the state of each CPU.
+pub const CPU_DIR: &str = "/sys/devices/system/cpu";
+
 pub type LoadAvgHarvest = [f32; 3];
@@ -0,0 +1,122 @@
+//! Data collection for CPU frequencies and thermal throttling on Linux.
+//!
+//! This is read from the `cpufreq` and `thermal_throttle` directories of each CPU in [`CPU_DIR`]. Either may be
+//! missing, such as in virtual machines or on kernels without the matching drivers.
+//!
+//! [`CPU_DIR`]: super::CPU_DIR
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::data_harvester::read_value;
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// The frequency of a CPU in kHz, and how many times it has been throttled for running too hot since boot.
+pub struct CpuFrequency {
+    pub current_khz: u64,
+    pub min_khz: Option<u64>,
+    pub max_khz: Option<u64>,
+    /// How many times the core itself was throttled.
+    pub core_throttle_count: Option<u64>,
+    /// How many times the package the core is on was throttled.
+    pub package_throttle_count: Option<u64>,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+/// The lowest and highest frequency a CPU can run at in kHz, which don't change, so they are only read once.
+pub struct CpuFrequencyLimits {
+    pub min_khz: Option<u64>,
+    pub max_khz: Option<u64>,
+}
+
+/// Reads the frequency limits of the CPU with the ID `cpu_id` from `dir`.
+pub fn get_cpu_frequency_limits(dir: &Path, cpu_id: usize) -> CpuFrequencyLimits {
+    let cpufreq = dir.join(format!("cpu{}", cpu_id)).join("cpufreq");
+
+    CpuFrequencyLimits {
+        min_khz: read_value(&cpufreq, "cpuinfo_min_freq"),
+        max_khz: read_value(&cpufreq, "cpuinfo_max_freq"),
+    }
+}
+
+/// Reads the current frequency of the CPU with the ID `cpu_id` from `dir`, and its throttle counts if
+/// `read_throttle_counts` is set. Returns `None` if its current frequency isn't reported.
+pub fn get_cpu_frequency(
+    dir: &Path, cpu_id: usize, limits: CpuFrequencyLimits, read_throttle_counts: bool,
+) -> Option<CpuFrequency> {
+    let cpu_dir = dir.join(format!("cpu{}", cpu_id));
+    let cpufreq = cpu_dir.join("cpufreq");
+    let current_khz = read_value(&cpufreq, "scaling_cur_freq")
+        .or_else(|| read_value(&cpufreq, "cpuinfo_cur_freq"))?;
+
+    let (core_throttle_count, package_throttle_count) = if read_throttle_counts {
+        let thermal_throttle = cpu_dir.join("thermal_throttle");
+        (
+            read_value(&thermal_throttle, "core_throttle_count"),
+            read_value(&thermal_throttle, "package_throttle_count"),
+        )
+    } else {
+        (None, None)
+    };
+
+    Some(CpuFrequency {
+        current_khz,
+        min_khz: limits.min_khz,
+        max_khz: limits.max_khz,
+        core_throttle_count,
+        package_throttle_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::FakeDir;
+
+    #[test]
+    fn test_get_cpu_frequency() {
+        let dir = FakeDir::default();
+        dir.write("cpu0/cpufreq/scaling_cur_freq", "2400000\n");
+        dir.write("cpu0/cpufreq/cpuinfo_min_freq", "800000\n");
+        dir.write("cpu0/cpufreq/cpuinfo_max_freq", "4200000\n");
+        dir.write("cpu0/thermal_throttle/core_throttle_count", "3\n");
+        dir.write("cpu2/cpufreq/cpuinfo_cur_freq", "1200000\n");
+
+        let limits = get_cpu_frequency_limits(dir.path(), 0);
+        assert_eq!(
+            limits,
+            CpuFrequencyLimits {
+                min_khz: Some(800000),
+                max_khz: Some(4200000),
+            }
+        );
+        assert_eq!(
+            get_cpu_frequency(dir.path(), 0, limits, true),
+            Some(CpuFrequency {
+                current_khz: 2400000,
+                min_khz: Some(800000),
+                max_khz: Some(4200000),
+                core_throttle_count: Some(3),
+                package_throttle_count: None,
+            })
+        );
+        assert_eq!(
+            get_cpu_frequency(dir.path(), 0, limits, false)
+                .unwrap()
+                .core_throttle_count,
+            None
+        );
+
+        let limits = get_cpu_frequency_limits(dir.path(), 2);
+        assert_eq!(limits, CpuFrequencyLimits::default());
+        assert_eq!(
+            get_cpu_frequency(dir.path(), 2, limits, true),
+            Some(CpuFrequency {
+                current_khz: 1200000,
+                ..CpuFrequency::default()
+            })
+        );
+        assert_eq!(get_cpu_frequency(dir.path(), 1, limits, true), None);
+    }
+}
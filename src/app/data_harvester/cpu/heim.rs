@@ -18,41 +18,109 @@ cfg_if::cfg_if! {
     }
 }
 
-#[derive(Default, Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct CpuData {
     pub cpu_prefix: String,
     pub cpu_count: Option<usize>,
+    /// The kernel's ID of the CPU, which skips over offline CPUs unlike `cpu_count`. This is `None` for the
+    /// average.
+    #[serde(default)]
+    pub cpu_id: Option<usize>,
     pub cpu_usage: f64,
+    /// How the time since the last harvest was spent, where it is known. This is only collected on Linux.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<CpuTimeBreakdown>,
+    /// The frequency of the CPU, where it is known. This is only collected on Linux, and not for the average.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<super::frequency::CpuFrequency>,
+    /// Where the CPU is, if it is known. This is only collected on Linux, and not for the average.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topology: Option<super::topology::CpuTopology>,
 }
 
 pub type CpuHarvest = Vec<CpuData>;
 
-pub type PastCpuWork = f64;
-pub type PastCpuTotal = f64;
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// The time a CPU spent on each kind of work. This is in seconds in [`CpuTimes`], and as a percentage of the
+/// time since the last harvest in [`CpuData`].
+///
+/// `user` includes niced time and `irq` includes soft interrupts. Everything but `io_wait` counts towards the
+/// usage of the CPU, as waiting on I/O is idle time.
+pub struct CpuTimeBreakdown {
+    pub user: f64,
+    pub system: f64,
+    pub irq: f64,
+    pub steal: f64,
+    pub io_wait: f64,
+}
 
-use futures::StreamExt;
-use std::collections::VecDeque;
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+/// The times of a CPU since boot, in seconds.
+pub struct CpuTimes {
+    pub work: f64,
+    pub total: f64,
+    pub breakdown: Option<CpuTimeBreakdown>,
+}
 
-pub async fn get_cpu_data_list(
-    show_average_cpu: bool, previous_cpu_times: &mut Vec<(PastCpuWork, PastCpuTotal)>,
-    previous_average_cpu_time: &mut Option<(PastCpuWork, PastCpuTotal)>,
-) -> crate::error::Result<CpuHarvest> {
-    fn calculate_cpu_usage_percentage(
-        (previous_working_time, previous_total_time): (f64, f64),
-        (current_working_time, current_total_time): (f64, f64),
-    ) -> f64 {
-        ((if current_working_time > previous_working_time {
-            current_working_time - previous_working_time
+impl CpuTimes {
+    /// The usage of the CPU since `previous`, as a percentage.
+    fn usage_since(&self, previous: &CpuTimes) -> f64 {
+        ((if self.work > previous.work {
+            self.work - previous.work
         } else {
             0.0
         }) * 100.0)
-            / (if current_total_time > previous_total_time {
-                current_total_time - previous_total_time
-            } else {
-                1.0
-            })
+            / self.total_since(previous)
     }
 
+    /// How the time since `previous` was spent, as percentages, if it is known.
+    fn breakdown_since(&self, previous: &CpuTimes) -> Option<CpuTimeBreakdown> {
+        let (current, past) = (self.breakdown?, previous.breakdown?);
+        let total = self.total_since(previous);
+        let percent = |current: f64, past: f64| (current - past).max(0.0) * 100.0 / total;
+
+        Some(CpuTimeBreakdown {
+            user: percent(current.user, past.user),
+            system: percent(current.system, past.system),
+            irq: percent(current.irq, past.irq),
+            steal: percent(current.steal, past.steal),
+            io_wait: percent(current.io_wait, past.io_wait),
+        })
+    }
+
+    fn total_since(&self, previous: &CpuTimes) -> f64 {
+        if self.total > previous.total {
+            self.total - previous.total
+        } else {
+            1.0
+        }
+    }
+
+    /// A [`CpuData`] for the time since `previous`.
+    fn data_since(
+        &self, previous: &CpuTimes, cpu_prefix: &str, cpu_count: Option<usize>,
+    ) -> CpuData {
+        CpuData {
+            cpu_prefix: cpu_prefix.to_string(),
+            cpu_count,
+            cpu_id: cpu_count,
+            cpu_usage: self.usage_since(previous),
+            breakdown: self.breakdown_since(previous),
+            frequency: None,
+            topology: None,
+        }
+    }
+}
+
+use futures::StreamExt;
+use std::collections::VecDeque;
+
+pub async fn get_cpu_data_list(
+    show_average_cpu: bool, previous_cpu_times: &mut Vec<CpuTimes>,
+    previous_average_cpu_time: &mut Option<CpuTimes>,
+) -> crate::error::Result<CpuHarvest> {
     // Get all CPU times...
     let cpu_times = heim::cpu::times().await?;
     futures::pin_mut!(cpu_times);
@@ -64,7 +132,7 @@ pub async fn get_cpu_data_list(
         let second_cpu_times = heim::cpu::times().await?;
         futures::pin_mut!(second_cpu_times);
 
-        let mut new_cpu_times: Vec<(PastCpuWork, PastCpuTotal)> = Vec::new();
+        let mut new_cpu_times: Vec<CpuTimes> = Vec::new();
         let mut cpu_deque: VecDeque<CpuData> = VecDeque::new();
         let mut collected_zip = cpu_times.zip(second_cpu_times).enumerate(); // Gotta move it here, can't on while line.
 
@@ -72,20 +140,18 @@ pub async fn get_cpu_data_list(
             if let (Ok(past), Ok(present)) = (past, present) {
                 let present_times = convert_cpu_times(&present);
                 new_cpu_times.push(present_times);
-                cpu_deque.push_back(CpuData {
-                    cpu_prefix: "CPU".to_string(),
-                    cpu_count: Some(itx),
-                    cpu_usage: calculate_cpu_usage_percentage(
-                        convert_cpu_times(&past),
-                        present_times,
-                    ),
-                });
+                cpu_deque.push_back(present_times.data_since(
+                    &convert_cpu_times(&past),
+                    "CPU",
+                    Some(itx),
+                ));
             } else {
-                new_cpu_times.push((0.0, 0.0));
+                new_cpu_times.push(CpuTimes::default());
                 cpu_deque.push_back(CpuData {
                     cpu_prefix: "CPU".to_string(),
                     cpu_count: Some(itx),
-                    cpu_usage: 0.0,
+                    cpu_id: Some(itx),
+                    ..CpuData::default()
                 });
             }
         }
@@ -93,78 +159,120 @@ pub async fn get_cpu_data_list(
         *previous_cpu_times = new_cpu_times;
         cpu_deque
     } else {
-        let (new_cpu_times, cpu_deque): (Vec<(PastCpuWork, PastCpuTotal)>, VecDeque<CpuData>) =
-            cpu_times
-                .collect::<Vec<_>>()
-                .await
-                .iter()
-                .zip(&*previous_cpu_times)
-                .enumerate()
-                .map(|(itx, (current_cpu, (past_cpu_work, past_cpu_total)))| {
-                    if let Ok(cpu_time) = current_cpu {
-                        let present_times = convert_cpu_times(cpu_time);
-
-                        (
-                            present_times,
-                            CpuData {
-                                cpu_prefix: "CPU".to_string(),
-                                cpu_count: Some(itx),
-                                cpu_usage: calculate_cpu_usage_percentage(
-                                    (*past_cpu_work, *past_cpu_total),
-                                    present_times,
-                                ),
-                            },
-                        )
-                    } else {
-                        (
-                            (*past_cpu_work, *past_cpu_total),
-                            CpuData {
-                                cpu_prefix: "CPU".to_string(),
-                                cpu_count: Some(itx),
-                                cpu_usage: 0.0,
-                            },
-                        )
-                    }
-                })
-                .unzip();
+        let (new_cpu_times, cpu_deque): (Vec<CpuTimes>, VecDeque<CpuData>) = cpu_times
+            .collect::<Vec<_>>()
+            .await
+            .iter()
+            .zip(&*previous_cpu_times)
+            .enumerate()
+            .map(|(itx, (current_cpu, past_cpu_times))| {
+                if let Ok(cpu_time) = current_cpu {
+                    let present_times = convert_cpu_times(cpu_time);
+
+                    (
+                        present_times,
+                        present_times.data_since(past_cpu_times, "CPU", Some(itx)),
+                    )
+                } else {
+                    (
+                        *past_cpu_times,
+                        CpuData {
+                            cpu_prefix: "CPU".to_string(),
+                            cpu_count: Some(itx),
+                            cpu_id: Some(itx),
+                            ..CpuData::default()
+                        },
+                    )
+                }
+            })
+            .unzip();
 
         *previous_cpu_times = new_cpu_times;
         cpu_deque
     };
 
+    #[cfg(target_os = "linux")]
+    {
+        let cpu_dir = std::path::Path::new(super::CPU_DIR);
+
+        // Only online CPUs are listed, so their IDs have gaps wherever a CPU is offline.
+        if let Some(online_ids) = super::topology::get_online_cpu_ids(cpu_dir) {
+            for cpu in cpu_deque.iter_mut() {
+                if let Some(cpu_count) = cpu.cpu_count {
+                    cpu.cpu_id = online_ids.get(cpu_count).copied().or(cpu.cpu_id);
+                }
+            }
+        }
+    }
+
     // Get average CPU if needed... and slap it at the top
     if show_average_cpu {
         let cpu_time = heim::cpu::time().await?;
 
-        let (cpu_usage, new_average_cpu_time) = if let Some((past_cpu_work, past_cpu_total)) =
-            previous_average_cpu_time
-        {
-            let present_times = convert_cpu_times(&cpu_time);
-            (
-                calculate_cpu_usage_percentage((*past_cpu_work, *past_cpu_total), present_times),
-                present_times,
-            )
-        } else {
-            // Again, we need to do a quick timeout...
-            futures_timer::Delay::new(std::time::Duration::from_millis(100)).await;
-            let second_cpu_time = heim::cpu::time().await?;
-
-            let present_times = convert_cpu_times(&second_cpu_time);
-            (
-                calculate_cpu_usage_percentage(convert_cpu_times(&cpu_time), present_times),
-                present_times,
-            )
-        };
+        let (average_cpu_data, new_average_cpu_time) =
+            if let Some(past_cpu_times) = previous_average_cpu_time {
+                let present_times = convert_cpu_times(&cpu_time);
+                (
+                    present_times.data_since(past_cpu_times, "AVG", None),
+                    present_times,
+                )
+            } else {
+                // Again, we need to do a quick timeout...
+                futures_timer::Delay::new(std::time::Duration::from_millis(100)).await;
+                let second_cpu_time = heim::cpu::time().await?;
+
+                let present_times = convert_cpu_times(&second_cpu_time);
+                (
+                    present_times.data_since(&convert_cpu_times(&cpu_time), "AVG", None),
+                    present_times,
+                )
+            };
 
         *previous_average_cpu_time = Some(new_average_cpu_time);
-        cpu_deque.push_front(CpuData {
-            cpu_prefix: "AVG".to_string(),
-            cpu_count: None,
-            cpu_usage,
-        })
+        cpu_deque.push_front(average_cpu_data)
     }
 
     // Ok(Vec::from(cpu_deque.drain(0..3).collect::<Vec<_>>())) // For artificially limiting the CPU results
 
     Ok(Vec::from(cpu_deque))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_data_since() {
+        let breakdown = |user, system, irq, steal, io_wait| CpuTimeBreakdown {
+            user,
+            system,
+            irq,
+            steal,
+            io_wait,
+        };
+        let previous = CpuTimes {
+            work: 10.0,
+            total: 100.0,
+            breakdown: Some(breakdown(6.0, 3.0, 0.5, 0.5, 1.0)),
+        };
+        let current = CpuTimes {
+            work: 14.0,
+            total: 110.0,
+            breakdown: Some(breakdown(7.0, 4.0, 0.5, 2.5, 3.0)),
+        };
+
+        let data = current.data_since(&previous, "CPU", Some(0));
+        assert_eq!(data.cpu_usage, 40.0);
+        assert_eq!(data.breakdown, Some(breakdown(10.0, 10.0, 0.0, 20.0, 20.0)));
+
+        // Without a breakdown on either side, there is nothing to compare.
+        let current = CpuTimes {
+            breakdown: None,
+            ..current
+        };
+        assert_eq!(
+            current.data_since(&previous, "CPU", Some(0)).breakdown,
+            None
+        );
+    }
+}
@@ -1,16 +1,23 @@
 //! Linux-specific functions regarding CPU usage.
 
 use heim::cpu::os::linux::CpuTimeExt;
-pub fn convert_cpu_times(cpu_time: &heim::cpu::CpuTime) -> (f64, f64) {
-    let working_time: f64 = (cpu_time.user()
-        + cpu_time.nice()
-        + cpu_time.system()
-        + cpu_time.irq()
-        + cpu_time.soft_irq()
-        + cpu_time.steal())
-    .get::<heim::units::time::second>();
-    (
-        working_time,
-        working_time + (cpu_time.idle() + cpu_time.io_wait()).get::<heim::units::time::second>(),
-    )
+
+use super::{CpuTimeBreakdown, CpuTimes};
+
+pub fn convert_cpu_times(cpu_time: &heim::cpu::CpuTime) -> CpuTimes {
+    let seconds = |time: heim::units::Time| time.get::<heim::units::time::second>();
+    let breakdown = CpuTimeBreakdown {
+        user: seconds(cpu_time.user() + cpu_time.nice()),
+        system: seconds(cpu_time.system()),
+        irq: seconds(cpu_time.irq() + cpu_time.soft_irq()),
+        steal: seconds(cpu_time.steal()),
+        io_wait: seconds(cpu_time.io_wait()),
+    };
+    let working_time = breakdown.user + breakdown.system + breakdown.irq + breakdown.steal;
+
+    CpuTimes {
+        work: working_time,
+        total: working_time + seconds(cpu_time.idle()) + breakdown.io_wait,
+        breakdown: Some(breakdown),
+    }
 }
@@ -0,0 +1,159 @@
+//! Data collection for the topology of the CPUs on Linux, which is the physical core, package (socket), and NUMA
+//! node each CPU is on.
+//!
+//! The core and package are read from the `topology` directory of each CPU in [`CPU_DIR`], and the nodes from the
+//! CPU list of each node in [`NODE_DIR`]. Nodes are missing on kernels built without NUMA support.
+//!
+//! [`CPU_DIR`]: super::CPU_DIR
+
+use std::{collections::HashMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::data_harvester::read_value;
+
+/// Where the kernel reports the state of each NUMA node.
+pub const NODE_DIR: &str = "/sys/devices/system/node";
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Where a CPU is, where it is known. CPUs with the same core ID on the same package are SMT siblings.
+pub struct CpuTopology {
+    pub package_id: Option<u32>,
+    pub core_id: Option<u32>,
+    pub node_id: Option<u32>,
+}
+
+/// Parses a list of CPUs in the kernel's format, such as `0-3,8,10-11`. Anything that can't be parsed is skipped.
+pub fn parse_cpu_list(list: &str) -> Vec<usize> {
+    list.trim()
+        .split(',')
+        .filter_map(|range| {
+            let mut bounds = range.splitn(2, '-').map(|bound| bound.trim().parse().ok());
+            let start: usize = bounds.next()??;
+            let end = match bounds.next() {
+                Some(end) => end?,
+                None => start,
+            };
+            Some(start..=end)
+        })
+        .flatten()
+        .collect()
+}
+
+/// Returns the IDs of the CPUs that are online according to `cpu_dir`, in order. Returns `None` if they aren't
+/// listed.
+pub fn get_online_cpu_ids(cpu_dir: &Path) -> Option<Vec<usize>> {
+    fs::read_to_string(cpu_dir.join("online"))
+        .ok()
+        .map(|list| parse_cpu_list(&list))
+}
+
+/// Returns the node of each CPU listed by the nodes in `dir`.
+fn get_node_ids(dir: &Path) -> HashMap<usize, u32> {
+    let mut node_ids = HashMap::new();
+
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let node_id = match name
+                .to_str()
+                .and_then(|name| name.strip_prefix("node"))
+                .and_then(|id| id.parse().ok())
+            {
+                Some(node_id) => node_id,
+                None => continue,
+            };
+
+            if let Ok(cpu_list) = fs::read_to_string(entry.path().join("cpulist")) {
+                for cpu in parse_cpu_list(&cpu_list) {
+                    node_ids.insert(cpu, node_id);
+                }
+            }
+        }
+    }
+
+    node_ids
+}
+
+/// Reads the topology of each CPU in `cpu_ids` from `cpu_dir` and `node_dir`, by its ID.
+pub fn get_cpu_topology(
+    cpu_dir: &Path, node_dir: &Path, cpu_ids: &[usize],
+) -> HashMap<usize, CpuTopology> {
+    let node_ids = get_node_ids(node_dir);
+
+    cpu_ids
+        .iter()
+        .map(|&cpu_id| {
+            let topology_dir = cpu_dir.join(format!("cpu{}", cpu_id)).join("topology");
+
+            (
+                cpu_id,
+                CpuTopology {
+                    package_id: read_value(&topology_dir, "physical_package_id"),
+                    core_id: read_value(&topology_dir, "core_id"),
+                    node_id: node_ids.get(&cpu_id).copied(),
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::FakeDir;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,8,10-11\n"), vec![0, 1, 2, 3, 8, 10, 11]);
+        assert_eq!(parse_cpu_list("5"), vec![5]);
+        assert_eq!(parse_cpu_list("\n"), Vec::<usize>::new());
+        assert_eq!(parse_cpu_list("1,x,4-a,6"), vec![1, 6]);
+    }
+
+    #[test]
+    fn test_get_cpu_topology() {
+        let dir = FakeDir::default();
+        for (cpu, package, core) in [(0, 0, 0), (1, 0, 0), (2, 1, 0)] {
+            dir.write(
+                &format!("cpu/cpu{}/topology/physical_package_id", cpu),
+                &format!("{}\n", package),
+            );
+            dir.write(
+                &format!("cpu/cpu{}/topology/core_id", cpu),
+                &format!("{}\n", core),
+            );
+        }
+        dir.write("node/node0/cpulist", "0-1\n");
+        dir.write("node/node1/cpulist", "2\n");
+        dir.write("node/possible", "0-1\n");
+        dir.write("cpu/online", "0,2-3\n");
+
+        assert_eq!(get_online_cpu_ids(&dir.join("cpu")), Some(vec![0, 2, 3]));
+        assert_eq!(get_online_cpu_ids(&dir.join("node")), None);
+        assert_eq!(
+            get_cpu_topology(&dir.join("cpu"), &dir.join("node"), &[0, 2, 3]),
+            vec![
+                (
+                    0,
+                    CpuTopology {
+                        package_id: Some(0),
+                        core_id: Some(0),
+                        node_id: Some(0),
+                    }
+                ),
+                (
+                    2,
+                    CpuTopology {
+                        package_id: Some(1),
+                        core_id: Some(0),
+                        node_id: Some(1),
+                    }
+                ),
+                (3, CpuTopology::default()),
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+}
@@ -1,5 +1,9 @@
 use crate::app::Filter;
 
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
 cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
         pub mod linux;
@@ -10,7 +14,7 @@ cfg_if::cfg_if! {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DiskHarvest {
     pub name: String,
     pub mount_point: String,
@@ -19,7 +23,7 @@ pub struct DiskHarvest {
     pub total_space: Option<u64>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IoData {
     pub read_bytes: u64,
     pub write_bytes: u64,
@@ -27,6 +31,20 @@ pub struct IoData {
 
 pub type IoHarvest = std::collections::HashMap<String, Option<IoData>>;
 
+/// Returns the IO data of a disk, if any was harvested for it.
+pub fn get_disk_io<'a>(io: &'a IoHarvest, disk_name: &str) -> Option<&'a Option<IoData>> {
+    let trim = disk_name.rsplit('/').next()?;
+    if cfg!(target_os = "macos") {
+        // Must trim one level further for macOS!
+        static DISK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"disk\d+").unwrap());
+        DISK_REGEX
+            .find(trim)
+            .and_then(|disk_trim| io.get(disk_trim.as_str()))
+    } else {
+        io.get(trim)
+    }
+}
+
 pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Option<IoHarvest>> {
     if !actually_get {
         return Ok(None);
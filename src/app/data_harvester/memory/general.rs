@@ -1,10 +1,107 @@
 //! Data collection for memory via heim.
 
-#[derive(Debug, Clone, Default)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MemHarvest {
     pub mem_total_in_kib: u64,
     pub mem_used_in_kib: u64,
     pub use_percent: Option<f64>,
+    /// Where the memory is going, if known. This is only collected for RAM on Linux.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<MemBreakdown>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The breakdown of RAM usage from `/proc/meminfo`. Everything is in KiB except for the huge page counts.
+pub struct MemBreakdown {
+    pub free_in_kib: u64,
+    pub available_in_kib: u64,
+    pub buffers_in_kib: u64,
+    pub cached_in_kib: u64,
+    pub shmem_in_kib: u64,
+    pub slab_in_kib: u64,
+    pub s_reclaimable_in_kib: u64,
+    pub dirty_in_kib: u64,
+    pub writeback_in_kib: u64,
+    pub huge_pages_total: u64,
+    pub huge_pages_free: u64,
+    pub huge_pages_rsvd: u64,
+    pub huge_pages_surp: u64,
+    pub huge_page_size_in_kib: u64,
+}
+
+impl MemBreakdown {
+    /// The page cache that can be reclaimed, which is the cache and reclaimable slab minus shared memory.
+    pub fn cache_in_kib(&self) -> u64 {
+        (self.cached_in_kib + self.s_reclaimable_in_kib).saturating_sub(self.shmem_in_kib)
+    }
+
+    /// The memory in use out of `total`, in KiB.
+    pub fn used_in_kib(&self, total: u64) -> u64 {
+        // Let's preface this by saying that memory usage calculations are... not straightforward.
+        // There are conflicting implementations everywhere.
+        //
+        // Now that we've added this preface (mainly for future reference), the current implementation below for usage
+        // is based on htop's calculation formula. See
+        // https://github.com/htop-dev/htop/blob/976c6123f41492aaf613b9d172eef1842fb7b0a3/linux/LinuxProcessList.c#L1584
+        // for implementation details as of writing.
+        //
+        // Another implementation, commonly used in other things, is to skip the shmem part of the calculation,
+        // which matches gopsutil and stuff like free.
+        let used_diff = self.free_in_kib + self.cache_in_kib() + self.buffers_in_kib;
+        if total >= used_diff {
+            total - used_diff
+        } else {
+            total.saturating_sub(self.free_in_kib)
+        }
+    }
+
+    /// The memory taken by huge pages, in KiB.
+    pub fn huge_pages_in_kib(&self) -> u64 {
+        self.huge_pages_total * self.huge_page_size_in_kib
+    }
+}
+
+/// Parses the contents of `/proc/meminfo`, returning the total memory in KiB and its breakdown.
+#[cfg(any(target_os = "linux", test))]
+fn parse_meminfo(meminfo: &str) -> (u64, MemBreakdown) {
+    let mut mem_total = 0;
+    let mut breakdown = MemBreakdown::default();
+
+    for line in meminfo.lines() {
+        if let Some((label, value)) = line.split_once(':') {
+            let to_write = match label {
+                "MemTotal" => &mut mem_total,
+                "MemFree" => &mut breakdown.free_in_kib,
+                "MemAvailable" => &mut breakdown.available_in_kib,
+                "Buffers" => &mut breakdown.buffers_in_kib,
+                "Cached" => &mut breakdown.cached_in_kib,
+                "Shmem" => &mut breakdown.shmem_in_kib,
+                "Slab" => &mut breakdown.slab_in_kib,
+                "SReclaimable" => &mut breakdown.s_reclaimable_in_kib,
+                "Dirty" => &mut breakdown.dirty_in_kib,
+                "Writeback" => &mut breakdown.writeback_in_kib,
+                "HugePages_Total" => &mut breakdown.huge_pages_total,
+                "HugePages_Free" => &mut breakdown.huge_pages_free,
+                "HugePages_Rsvd" => &mut breakdown.huge_pages_rsvd,
+                "HugePages_Surp" => &mut breakdown.huge_pages_surp,
+                "Hugepagesize" => &mut breakdown.huge_page_size_in_kib,
+                _ => {
+                    continue;
+                }
+            };
+
+            // Sizes are in KiB, and the huge page counts have no unit at all.
+            if let Some(number) = value.split_whitespace().next() {
+                if let Ok(number) = number.parse::<u64>() {
+                    *to_write = number;
+                }
+            }
+        }
+    }
+
+    (mem_total, breakdown)
 }
 
 pub async fn get_mem_data(
@@ -23,73 +120,14 @@ pub async fn get_mem_data(
 }
 
 pub async fn get_ram_data() -> crate::utils::error::Result<Option<MemHarvest>> {
-    let (mem_total_in_kib, mem_used_in_kib) = {
+    let (mem_total_in_kib, mem_used_in_kib, breakdown) = {
         #[cfg(target_os = "linux")]
         {
             use smol::fs::read_to_string;
             let meminfo = read_to_string("/proc/meminfo").await?;
+            let (total, breakdown) = parse_meminfo(&meminfo);
 
-            // All values are in KiB by default.
-            let mut mem_total = 0;
-            let mut cached = 0;
-            let mut s_reclaimable = 0;
-            let mut shmem = 0;
-            let mut buffers = 0;
-            let mut mem_free = 0;
-
-            let mut keys_read: u8 = 0;
-            const TOTAL_KEYS_NEEDED: u8 = 6;
-
-            for line in meminfo.lines() {
-                if let Some((label, value)) = line.split_once(':') {
-                    let to_write = match label {
-                        "MemTotal" => &mut mem_total,
-                        "MemFree" => &mut mem_free,
-                        "Buffers" => &mut buffers,
-                        "Cached" => &mut cached,
-                        "Shmem" => &mut shmem,
-                        "SReclaimable" => &mut s_reclaimable,
-                        _ => {
-                            continue;
-                        }
-                    };
-
-                    if let Some((number, _unit)) = value.trim_start().split_once(' ') {
-                        // Parse the value, remember it's in KiB!
-                        if let Ok(number) = number.parse::<u64>() {
-                            *to_write = number;
-
-                            // We only need a few keys, so we can bail early.
-                            keys_read += 1;
-                            if keys_read == TOTAL_KEYS_NEEDED {
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Let's preface this by saying that memory usage calculations are... not straightforward.
-            // There are conflicting implementations everywhere.
-            //
-            // Now that we've added this preface (mainly for future reference), the current implementation below for usage
-            // is based on htop's calculation formula. See
-            // https://github.com/htop-dev/htop/blob/976c6123f41492aaf613b9d172eef1842fb7b0a3/linux/LinuxProcessList.c#L1584
-            // for implementation details as of writing.
-            //
-            // Another implementation, commonly used in other things, is to skip the shmem part of the calculation,
-            // which matches gopsutil and stuff like free.
-
-            let total = mem_total;
-            let cached_mem = cached + s_reclaimable - shmem;
-            let used_diff = mem_free + cached_mem + buffers;
-            let used = if total >= used_diff {
-                total - used_diff
-            } else {
-                total - mem_free
-            };
-
-            (total, used)
+            (total, breakdown.used_in_kib(total), Some(breakdown))
         }
         #[cfg(target_os = "macos")]
         {
@@ -100,6 +138,7 @@ pub async fn get_ram_data() -> crate::utils::error::Result<Option<MemHarvest>> {
             (
                 memory.total().get::<kibibyte>(),
                 memory.active().get::<kibibyte>() + memory.wire().get::<kibibyte>(),
+                None,
             )
         }
         #[cfg(target_os = "windows")]
@@ -111,6 +150,7 @@ pub async fn get_ram_data() -> crate::utils::error::Result<Option<MemHarvest>> {
             (
                 mem_total_in_kib,
                 mem_total_in_kib - memory.available().get::<kibibyte>(),
+                None,
             )
         }
     };
@@ -123,6 +163,7 @@ pub async fn get_ram_data() -> crate::utils::error::Result<Option<MemHarvest>> {
         } else {
             Some(mem_used_in_kib as f64 / mem_total_in_kib as f64 * 100.0)
         },
+        breakdown,
     }))
 }
 
@@ -157,5 +198,45 @@ pub async fn get_swap_data() -> crate::utils::error::Result<Option<MemHarvest>>
         } else {
             Some(mem_used_in_kib as f64 / mem_total_in_kib as f64 * 100.0)
         },
+        breakdown: None,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let (total, breakdown) = parse_meminfo(
+            "MemTotal:       16000000 kB\n\
+             MemFree:         2000000 kB\n\
+             MemAvailable:    9000000 kB\n\
+             Buffers:          500000 kB\n\
+             Cached:          6000000 kB\n\
+             SwapCached:        10000 kB\n\
+             Dirty:              1200 kB\n\
+             Writeback:            40 kB\n\
+             Shmem:           1000000 kB\n\
+             Slab:             800000 kB\n\
+             SReclaimable:     600000 kB\n\
+             HugePages_Total:       4\n\
+             HugePages_Free:        3\n\
+             HugePages_Rsvd:        1\n\
+             HugePages_Surp:        0\n\
+             Hugepagesize:       2048 kB\n",
+        );
+
+        assert_eq!(total, 16000000);
+        assert_eq!(breakdown.available_in_kib, 9000000);
+        assert_eq!(breakdown.dirty_in_kib, 1200);
+        assert_eq!(breakdown.writeback_in_kib, 40);
+        assert_eq!(breakdown.slab_in_kib, 800000);
+        assert_eq!(breakdown.huge_pages_total, 4);
+        assert_eq!(breakdown.huge_pages_free, 3);
+        assert_eq!(breakdown.huge_pages_rsvd, 1);
+        assert_eq!(breakdown.huge_pages_in_kib(), 8192);
+        assert_eq!(breakdown.cache_in_kib(), 5600000);
+        assert_eq!(breakdown.used_in_kib(total), 7900000);
+    }
+}
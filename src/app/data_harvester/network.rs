@@ -13,18 +13,102 @@ cfg_if::cfg_if! {
     }
 }
 
-#[derive(Default, Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 /// All units in bits.
 pub struct NetworkHarvest {
     pub rx: u64,
     pub tx: u64,
     pub total_rx: u64,
     pub total_tx: u64,
+    /// Each unfiltered interface on its own.
+    #[serde(default)]
+    pub interfaces: Vec<InterfaceHarvest>,
 }
 
 impl NetworkHarvest {
     pub fn first_run_cleanup(&mut self) {
         self.rx = 0;
         self.tx = 0;
+        for interface in &mut self.interfaces {
+            interface.rx = 0;
+            interface.tx = 0;
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+/// Usage of a single network interface. Rates and totals are in bits, and the packet, error and drop counts are
+/// totals since the interface came up. Drops are `None` where they aren't reported.
+pub struct InterfaceHarvest {
+    pub name: String,
+    pub rx: u64,
+    pub tx: u64,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub packets_rx: u64,
+    pub packets_tx: u64,
+    pub errors_rx: u64,
+    pub errors_tx: u64,
+    pub drops_rx: Option<u64>,
+    pub drops_tx: Option<u64>,
+}
+
+/// The previous received and transmitted totals of each interface, used to work out per-interface rates.
+pub type PrevInterfaceTotals = fxhash::FxHashMap<String, (u64, u64)>;
+
+/// Fills in the rates of each interface from its previous totals, and replaces the previous totals with the
+/// current ones. Interfaces that weren't seen last time start at a rate of zero.
+fn set_interface_rates(
+    interfaces: &mut [InterfaceHarvest], prev_totals: &mut PrevInterfaceTotals, elapsed_time: f64,
+) {
+    let mut new_totals = PrevInterfaceTotals::default();
+    for interface in interfaces.iter_mut() {
+        let (prev_rx, prev_tx) = prev_totals
+            .get(&interface.name)
+            .copied()
+            .unwrap_or((interface.total_rx, interface.total_tx));
+
+        if elapsed_time > 0.0 {
+            interface.rx =
+                (interface.total_rx.saturating_sub(prev_rx) as f64 / elapsed_time) as u64;
+            interface.tx =
+                (interface.total_tx.saturating_sub(prev_tx) as f64 / elapsed_time) as u64;
+        }
+
+        new_totals.insert(
+            interface.name.clone(),
+            (interface.total_rx, interface.total_tx),
+        );
+    }
+
+    *prev_totals = new_totals;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_interface_rates() {
+        let interface = |name: &str, total_rx, total_tx| InterfaceHarvest {
+            name: name.to_string(),
+            total_rx,
+            total_tx,
+            ..InterfaceHarvest::default()
+        };
+
+        let mut prev_totals = PrevInterfaceTotals::default();
+        prev_totals.insert("eth0".to_string(), (1000, 500));
+        prev_totals.insert("gone0".to_string(), (1, 1));
+
+        let mut interfaces = vec![interface("eth0", 3000, 1500), interface("wlan0", 800, 800)];
+        set_interface_rates(&mut interfaces, &mut prev_totals, 2.0);
+
+        assert_eq!((interfaces[0].rx, interfaces[0].tx), (1000, 500));
+        assert_eq!((interfaces[1].rx, interfaces[1].tx), (0, 0));
+        assert_eq!(prev_totals.len(), 2);
+        assert_eq!(prev_totals.get("wlan0"), Some(&(800, 800)));
     }
 }
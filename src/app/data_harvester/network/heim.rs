@@ -1,14 +1,15 @@
 //! Gets network data via heim.
 
-use super::NetworkHarvest;
+use super::{set_interface_rates, InterfaceHarvest, NetworkHarvest, PrevInterfaceTotals};
 use std::time::Instant;
 
-// TODO: Eventually make it so that this thing also takes individual usage into account, so we can show per-interface!
 pub async fn get_network_data(
     prev_net_access_time: Instant, prev_net_rx: &mut u64, prev_net_tx: &mut u64,
-    curr_time: Instant, actually_get: bool, filter: &Option<crate::app::Filter>,
+    prev_interface_totals: &mut PrevInterfaceTotals, curr_time: Instant, actually_get: bool,
+    filter: &Option<crate::app::Filter>,
 ) -> crate::utils::error::Result<Option<NetworkHarvest>> {
     use futures::StreamExt;
+    use heim::units::information::bit;
 
     if !actually_get {
         return Ok(None);
@@ -18,6 +19,7 @@ pub async fn get_network_data(
     futures::pin_mut!(io_data);
     let mut total_rx: u64 = 0;
     let mut total_tx: u64 = 0;
+    let mut interfaces = Vec::new();
 
     while let Some(io) = io_data.next().await {
         if let Ok(io) = io {
@@ -43,8 +45,32 @@ pub async fn get_network_data(
                 // Since you might have to do a double conversion (bytes -> bits -> bytes) in some cases;
                 // but if you stick to bytes, then in the bytes, case, you do no conversion, and in the bits case,
                 // you only do one conversion...
-                total_rx += io.bytes_recv().get::<heim::units::information::bit>();
-                total_tx += io.bytes_sent().get::<heim::units::information::bit>();
+                let interface_rx = io.bytes_recv().get::<bit>();
+                let interface_tx = io.bytes_sent().get::<bit>();
+                total_rx += interface_rx;
+                total_tx += interface_tx;
+
+                #[cfg(target_os = "linux")]
+                let drops_tx = {
+                    use heim::net::os::linux::IoCountersExt;
+                    Some(io.drop_sent())
+                };
+                #[cfg(not(target_os = "linux"))]
+                let drops_tx = None;
+
+                interfaces.push(InterfaceHarvest {
+                    name: io.interface().to_string(),
+                    rx: 0,
+                    tx: 0,
+                    total_rx: interface_rx,
+                    total_tx: interface_tx,
+                    packets_rx: io.packets_recv(),
+                    packets_tx: io.packets_sent(),
+                    errors_rx: io.errors_recv(),
+                    errors_tx: io.errors_sent(),
+                    drops_rx: Some(io.drop_recv()),
+                    drops_tx,
+                });
             }
         }
     }
@@ -59,6 +85,8 @@ pub async fn get_network_data(
             ((total_tx.saturating_sub(*prev_net_tx)) as f64 / elapsed_time) as u64,
         )
     };
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    set_interface_rates(&mut interfaces, prev_interface_totals, elapsed_time);
 
     *prev_net_rx = total_rx;
     *prev_net_tx = total_tx;
@@ -67,5 +95,6 @@ pub async fn get_network_data(
         tx,
         total_rx,
         total_tx,
+        interfaces,
     }))
 }
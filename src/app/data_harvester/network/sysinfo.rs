@@ -1,12 +1,12 @@
 //! Gets network data via sysinfo.
 
-use super::NetworkHarvest;
+use super::{set_interface_rates, InterfaceHarvest, NetworkHarvest, PrevInterfaceTotals};
 use std::time::Instant;
 
 pub async fn get_network_data(
     sys: &sysinfo::System, prev_net_access_time: Instant, prev_net_rx: &mut u64,
-    prev_net_tx: &mut u64, curr_time: Instant, actually_get: bool,
-    filter: &Option<crate::app::Filter>,
+    prev_net_tx: &mut u64, prev_interface_totals: &mut PrevInterfaceTotals, curr_time: Instant,
+    actually_get: bool, filter: &Option<crate::app::Filter>,
 ) -> crate::utils::error::Result<Option<NetworkHarvest>> {
     use sysinfo::{NetworkExt, SystemExt};
 
@@ -16,6 +16,7 @@ pub async fn get_network_data(
 
     let mut total_rx: u64 = 0;
     let mut total_tx: u64 = 0;
+    let mut interfaces = Vec::new();
 
     let networks = sys.networks();
     for (name, network) in networks {
@@ -33,8 +34,24 @@ pub async fn get_network_data(
         };
 
         if to_keep {
-            total_rx += network.total_received() * 8;
-            total_tx += network.total_transmitted() * 8;
+            let interface_rx = network.total_received() * 8;
+            let interface_tx = network.total_transmitted() * 8;
+            total_rx += interface_rx;
+            total_tx += interface_tx;
+
+            interfaces.push(InterfaceHarvest {
+                name: name.to_string(),
+                rx: 0,
+                tx: 0,
+                total_rx: interface_rx,
+                total_tx: interface_tx,
+                packets_rx: network.total_packets_received(),
+                packets_tx: network.total_packets_transmitted(),
+                errors_rx: network.total_errors_on_received(),
+                errors_tx: network.total_errors_on_transmitted(),
+                drops_rx: None,
+                drops_tx: None,
+            });
         }
     }
 
@@ -48,6 +65,8 @@ pub async fn get_network_data(
             ((total_tx.saturating_sub(*prev_net_tx)) as f64 / elapsed_time) as u64,
         )
     };
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    set_interface_rates(&mut interfaces, prev_interface_totals, elapsed_time);
 
     *prev_net_rx = total_rx;
     *prev_net_tx = total_tx;
@@ -56,5 +75,6 @@ pub async fn get_network_data(
         tx,
         total_rx,
         total_tx,
+        interfaces,
     }))
 }
@@ -0,0 +1,146 @@
+//! Data collection for pressure stall information (PSI).
+//!
+//! This is read from the files in [`PRESSURE_DIR`], which are only there on Linux 4.20 and later, and only when
+//! the kernel was built with PSI enabled.
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// Where the kernel reports pressure stall information.
+pub const PRESSURE_DIR: &str = "/proc/pressure";
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+/// The share of time that tasks were stalled, as percentages averaged over the last 10, 60 and 300 seconds, and
+/// the total time stalled in microseconds.
+pub struct PressureValues {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total: u64,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+/// The pressure on a single resource. `some` is the time that at least one task was stalled, and `full` the
+/// time that all non-idle tasks were stalled at once. `full` isn't reported for the CPU on older kernels.
+pub struct Pressure {
+    pub some: PressureValues,
+    pub full: Option<PressureValues>,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+/// The pressure on each resource, where reported.
+pub struct PressureHarvest {
+    pub cpu: Option<Pressure>,
+    pub memory: Option<Pressure>,
+    pub io: Option<Pressure>,
+}
+
+/// Parses a line like `some avg10=0.00 avg60=0.00 avg300=0.00 total=0`, returning its kind and values.
+fn parse_pressure_line(line: &str) -> Option<(&str, PressureValues)> {
+    let mut fields = line.split_whitespace();
+    let kind = fields.next()?;
+    let mut values = PressureValues::default();
+    for field in fields {
+        match field.split_once('=')? {
+            ("avg10", value) => values.avg10 = value.parse().ok()?,
+            ("avg60", value) => values.avg60 = value.parse().ok()?,
+            ("avg300", value) => values.avg300 = value.parse().ok()?,
+            ("total", value) => values.total = value.parse().ok()?,
+            _ => {}
+        }
+    }
+
+    Some((kind, values))
+}
+
+/// Parses the contents of a file in [`PRESSURE_DIR`]. Returns `None` if there is no `some` line.
+fn parse_pressure(contents: &str) -> Option<Pressure> {
+    let mut some = None;
+    let mut full = None;
+    for (kind, values) in contents.lines().filter_map(parse_pressure_line) {
+        match kind {
+            "some" => some = Some(values),
+            "full" => full = Some(values),
+            _ => {}
+        }
+    }
+
+    Some(Pressure { some: some?, full })
+}
+
+/// Reads the pressure on each resource from `dir`. Returns `None` if none of them are reported.
+pub fn get_pressure_data(dir: &Path) -> Option<PressureHarvest> {
+    let read = |resource: &str| {
+        fs::read_to_string(dir.join(resource))
+            .ok()
+            .and_then(|contents| parse_pressure(&contents))
+    };
+    let pressure = PressureHarvest {
+        cpu: read("cpu"),
+        memory: read("memory"),
+        io: read("io"),
+    };
+
+    if pressure == PressureHarvest::default() {
+        None
+    } else {
+        Some(pressure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::FakeDir;
+
+    #[test]
+    fn test_pressure_parse() {
+        let pressure = parse_pressure(
+            "some avg10=2.70 avg60=3.22 avg300=2.60 total=68287919\n\
+             full avg10=0.50 avg60=0.25 avg300=0.00 total=1234\n",
+        )
+        .unwrap();
+        assert_eq!(
+            pressure.some,
+            PressureValues {
+                avg10: 2.70,
+                avg60: 3.22,
+                avg300: 2.60,
+                total: 68287919,
+            }
+        );
+        assert_eq!(
+            pressure.full,
+            Some(PressureValues {
+                avg10: 0.50,
+                avg60: 0.25,
+                avg300: 0.00,
+                total: 1234,
+            })
+        );
+
+        // Older kernels only report `some` for the CPU.
+        let pressure = parse_pressure("some avg10=1.00 avg60=2.00 avg300=3.00 total=4\n").unwrap();
+        assert_eq!(pressure.full, None);
+
+        assert_eq!(parse_pressure(""), None);
+        assert_eq!(parse_pressure("some avg10=oops\n"), None);
+    }
+
+    #[test]
+    fn test_get_pressure_data() {
+        let dir = FakeDir::default();
+        dir.write(
+            "memory",
+            "some avg10=5.00 avg60=1.00 avg300=0.20 total=100\n\
+             full avg10=1.00 avg60=0.50 avg300=0.10 total=50\n",
+        );
+
+        let pressure = get_pressure_data(dir.path()).unwrap();
+        assert_eq!(pressure.cpu, None);
+        assert_eq!(pressure.io, None);
+        assert_eq!(pressure.memory.unwrap().some.avg10, 5.00);
+        assert_eq!(get_pressure_data(&dir.join("missing")), None);
+    }
+}
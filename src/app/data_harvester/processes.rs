@@ -23,9 +23,13 @@ cfg_if::cfg_if! {
     }
 }
 
+use std::sync::Arc;
+
 use crate::Pid;
 
-#[derive(Debug, Clone, Default)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProcessHarvest {
     /// The pid of the process.
     pub pid: Pid,
@@ -39,9 +43,23 @@ pub struct ProcessHarvest {
     /// Memory usage as a percentage.
     pub mem_usage_percent: f64,
 
-    /// Memory usage as bytes.
+    /// Memory usage as bytes. This is the resident set size (RSS) of the process.
     pub mem_usage_bytes: u64,
 
+    /// The virtual memory size of the process in bytes.
+    pub virt_bytes: u64,
+
+    /// Resident memory shared with other processes (file-backed and shared memory pages) in bytes.
+    /// This is only used on Linux.
+    pub shared_bytes: u64,
+
+    /// Memory that has been swapped out in bytes. This is only used on Linux.
+    pub swap_bytes: u64,
+
+    /// The proportional set size (PSS) of the process in bytes, or the resident memory where each shared page
+    /// is divided by the number of processes sharing it. This is only used on Linux.
+    pub pss_bytes: u64,
+
     /// The name of the process.
     pub name: String,
 
@@ -70,9 +88,25 @@ pub struct ProcessHarvest {
     /// This is the process' user. This is only used on Unix platforms.
     #[cfg(target_family = "unix")]
     pub user: std::borrow::Cow<'static, str>,
-    // TODO: Additional fields
-    // pub rss_kb: u64,
-    // pub virt_kb: u64,
+
+    /// The threads of the process, if any were harvested. Each thread's `parent_pid` is the owning process.
+    pub threads: Vec<ProcessHarvest>,
+
+    /// The cgroup of the process, which is shared with its threads. This is only used on Linux.
+    pub cgroup: Arc<ProcessCgroup>,
+}
+
+/// Where a process sits in the cgroup hierarchy.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProcessCgroup {
+    /// The path of the cgroup, e.g. `/system.slice/sshd.service`.
+    pub path: String,
+
+    /// The ID of the container the process runs in, as found in its cgroup.
+    pub container_id: Option<String>,
+
+    /// The innermost systemd unit (or if there is none, slice) of the cgroup.
+    pub unit: Option<String>,
 }
 
 impl ProcessHarvest {
@@ -80,9 +114,86 @@ impl ProcessHarvest {
         self.cpu_usage_percent += rhs.cpu_usage_percent;
         self.mem_usage_bytes += rhs.mem_usage_bytes;
         self.mem_usage_percent += rhs.mem_usage_percent;
+        self.virt_bytes += rhs.virt_bytes;
+        self.shared_bytes += rhs.shared_bytes;
+        self.swap_bytes += rhs.swap_bytes;
+        self.pss_bytes += rhs.pss_bytes;
         self.read_bytes_per_sec += rhs.read_bytes_per_sec;
         self.write_bytes_per_sec += rhs.write_bytes_per_sec;
         self.total_read_bytes += rhs.total_read_bytes;
         self.total_write_bytes += rhs.total_write_bytes;
     }
+
+    /// Whether the process is stopped, e.g. by `SIGSTOP`.
+    pub fn is_stopped(&self) -> bool {
+        self.process_state.1 == 'T'
+    }
+
+    /// Describes where the process runs: the start of its container ID if it runs in a container, and otherwise its
+    /// systemd unit, falling back to its cgroup.
+    pub fn cgroup_label(&self) -> &str {
+        /// How much of a container ID is shown, the same as `docker ps` does.
+        const SHORT_CONTAINER_ID_LEN: usize = 12;
+
+        if let Some(container_id) = &self.cgroup.container_id {
+            container_id
+                .get(..SHORT_CONTAINER_ID_LEN)
+                .unwrap_or(container_id)
+        } else if let Some(unit) = &self.cgroup.unit {
+            unit
+        } else {
+            &self.cgroup.path
+        }
+    }
+}
+
+/// A limit on the resources a process may use, as shown by `/proc/<PID>/limits`.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessLimit {
+    pub name: String,
+
+    /// The soft limit, which is what the process is actually held to. `None` means unlimited.
+    pub soft: Option<u64>,
+
+    /// The hard limit, or the ceiling the soft limit can be raised to. `None` means unlimited.
+    pub hard: Option<u64>,
+}
+
+/// Details about a single process that don't fit in the process table. These are only collected for the process
+/// being looked at, and only on Linux. They are never serialized, as they include things like the environment of the
+/// process.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessDetails {
+    /// The pid of the process.
+    pub pid: Pid,
+
+    /// The command line of the process, split into its arguments.
+    pub command_line: Vec<String>,
+
+    /// The environment variables of the process as `KEY=VALUE`, sorted by key.
+    pub environment: Vec<String>,
+
+    /// The current working directory of the process.
+    pub cwd: Option<String>,
+
+    /// The path of the executable of the process.
+    pub exe: Option<String>,
+
+    /// When the process started, in seconds since the Unix epoch.
+    pub start_time: Option<u64>,
+
+    /// The total CPU time the process has used, in both user and kernel mode, in seconds.
+    pub cpu_time_secs: f64,
+
+    /// The number of threads in the process.
+    pub num_threads: u64,
+
+    /// The number of file descriptors the process has open.
+    pub num_fds: Option<u64>,
+
+    /// The resource limits of the process.
+    pub limits: Vec<ProcessLimit>,
+
+    /// The cgroups the process belongs to, as `hierarchy:controllers:path`.
+    pub cgroups: Vec<String>,
 }
@@ -1,15 +1,15 @@
 //! Process data collection for Linux.
 
-use std::collections::hash_map::Entry;
+use std::{collections::hash_map::Entry, sync::Arc};
 
 use crate::utils::error::{self, BottomError};
 use crate::Pid;
 
-use super::{ProcessHarvest, UserTable};
+use super::{ProcessCgroup, ProcessDetails, ProcessHarvest, ProcessLimit, UserTable};
 
 use sysinfo::ProcessStatus;
 
-use procfs::process::{Process, Stat};
+use procfs::process::{Limit, LimitValue, Process, Stat, Task};
 
 use fxhash::{FxHashMap, FxHashSet};
 
@@ -23,6 +23,16 @@ pub struct PrevProcDetails {
     pub total_write_bytes: u64,
     pub cpu_time: u64,
     pub process: Process,
+    pub threads: FxHashMap<Pid, PrevThreadDetails>,
+    /// The cgroup of the process. Processes rarely move between cgroups, so this is only read once.
+    pub cgroup: Arc<ProcessCgroup>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrevThreadDetails {
+    pub total_read_bytes: u64,
+    pub total_write_bytes: u64,
+    pub cpu_time: u64,
 }
 
 impl PrevProcDetails {
@@ -32,6 +42,8 @@ impl PrevProcDetails {
             total_write_bytes: 0,
             cpu_time: 0,
             process: Process::new(pid)?,
+            threads: FxHashMap::default(),
+            cgroup: Arc::new(read_cgroup(pid)),
         })
     }
 }
@@ -116,11 +128,97 @@ fn get_linux_cpu_usage(
     }
 }
 
+/// Parses the `Pss` line of a `/proc/<PID>/smaps_rollup` file, returning the value in bytes.
+fn parse_pss_bytes(smaps_rollup: &str) -> Option<u64> {
+    smaps_rollup
+        .lines()
+        .find_map(|line| line.strip_prefix("Pss:"))
+        .and_then(|val| val.trim().trim_end_matches("kB").trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+/// Picks the cgroup path out of a `/proc/<PID>/cgroup` file. The unified (v2) hierarchy is preferred, unless it is
+/// just the root on a hybrid system, in which case the hierarchy systemd manages is used.
+fn parse_cgroup(contents: &str) -> Option<&str> {
+    let mut fallback = None;
+    for line in contents.lines() {
+        let mut fields = line.splitn(3, ':');
+        let (hierarchy, controllers, path) = (fields.next()?, fields.next()?, fields.next()?);
+        if hierarchy == "0" && controllers.is_empty() && path != "/" {
+            return Some(path);
+        } else if controllers == "name=systemd" || fallback.is_none() {
+            fallback = Some(path);
+        }
+    }
+
+    fallback
+}
+
+/// Finds the ID of the container a cgroup belongs to. Docker, podman, containerd and CRI-O (including under
+/// Kubernetes) all name a container's cgroup after its full ID, sometimes with a runtime prefix and a `.scope`
+/// suffix.
+fn parse_container_id(cgroup: &str) -> Option<String> {
+    const RUNTIME_PREFIXES: [&str; 5] = [
+        "docker-",
+        "libpod-",
+        "cri-containerd-",
+        "crio-",
+        "containerd-",
+    ];
+
+    cgroup.rsplit('/').find_map(|component| {
+        let id = component.strip_suffix(".scope").unwrap_or(component);
+        let id = RUNTIME_PREFIXES
+            .iter()
+            .find_map(|prefix| id.strip_prefix(prefix))
+            .unwrap_or(id);
+        if id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()) {
+            Some(id.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Finds the innermost systemd service or scope a cgroup belongs to, or the innermost slice if there is none.
+fn parse_systemd_unit(cgroup: &str) -> Option<String> {
+    cgroup
+        .rsplit('/')
+        .find(|component| component.ends_with(".service") || component.ends_with(".scope"))
+        .or_else(|| {
+            cgroup
+                .rsplit('/')
+                .find(|component| component.ends_with(".slice"))
+        })
+        .map(str::to_string)
+}
+
+/// Reads the cgroup of a process, which is left empty if it can't be read.
+fn read_cgroup(pid: Pid) -> ProcessCgroup {
+    let path = std::fs::read_to_string(format!("/proc/{}/cgroup", pid))
+        .ok()
+        .and_then(|contents| parse_cgroup(&contents).map(str::to_string))
+        .unwrap_or_default();
+
+    ProcessCgroup {
+        container_id: parse_container_id(&path),
+        unit: parse_systemd_unit(&path),
+        path,
+    }
+}
+
+/// Reads the proportional set size of a process. This file only exists since Linux 4.14, and requires
+/// the same permissions as reading a process' memory maps.
+fn read_pss_bytes(pid: Pid) -> Option<u64> {
+    let smaps_rollup = std::fs::read_to_string(format!("/proc/{}/smaps_rollup", pid)).ok()?;
+    parse_pss_bytes(&smaps_rollup)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn read_proc(
     prev_proc: &PrevProcDetails, stat: &Stat, cpu_usage: f64, cpu_fraction: f64,
     use_current_cpu_total: bool, time_difference_in_secs: u64, mem_total_kb: u64,
-    user_table: &mut UserTable,
+    collect_pss: bool, user_table: &mut UserTable,
 ) -> error::Result<(ProcessHarvest, u64)> {
     use std::convert::TryFrom;
 
@@ -172,6 +270,20 @@ fn read_proc(
     let mem_usage_bytes = u64::try_from(stat.rss_bytes()?).unwrap_or(0);
     let mem_usage_kb = mem_usage_bytes / 1024;
     let mem_usage_percent = mem_usage_kb as f64 / mem_total_kb as f64 * 100.0;
+    let virt_bytes = stat.vsize;
+
+    // Both of these can fail if permission is denied, in which case we just leave them as 0.
+    let (shared_bytes, swap_bytes) = if let Ok(status) = process.status() {
+        let shared_kb = status.rssfile.unwrap_or(0) + status.rssshmem.unwrap_or(0);
+        (shared_kb * 1024, status.vmswap.unwrap_or(0) * 1024)
+    } else {
+        (0, 0)
+    };
+    let pss_bytes = if collect_pss {
+        read_pss_bytes(process.pid).unwrap_or(0)
+    } else {
+        0
+    };
 
     // This can fail if permission is denied!
 
@@ -212,6 +324,10 @@ fn read_proc(
             cpu_usage_percent,
             mem_usage_percent,
             mem_usage_bytes,
+            virt_bytes,
+            shared_bytes,
+            swap_bytes,
+            pss_bytes,
             name,
             command,
             read_bytes_per_sec,
@@ -224,18 +340,237 @@ fn read_proc(
                 .get_uid_to_username_mapping(uid)
                 .map(Into::into)
                 .unwrap_or_else(|_| "N/A".into()),
+            threads: Vec::new(),
+            cgroup: prev_proc.cgroup.clone(),
         },
         new_process_times,
     ))
 }
 
+/// Reads a single thread of a process from `/proc/<PID>/task/<TID>`. Memory, user and cgroup values are shared
+/// between all threads of a process, so they are just copied from the owning process.
+///
+/// Threads are only read while they are shown, so one without a `prev_thread` is measured from now on instead
+/// of over its whole lifetime.
+fn read_thread(
+    task: &Task, owner: &ProcessHarvest, prev_thread: Option<&PrevThreadDetails>, cpu_usage: f64,
+    cpu_fraction: f64, use_current_cpu_total: bool, time_difference_in_secs: u64,
+) -> error::Result<(ProcessHarvest, u64)> {
+    let stat = task.stat()?;
+    let process_state = (ProcessStatus::from(stat.state).to_string(), stat.state);
+    let (cpu_usage_percent, new_thread_times) = get_linux_cpu_usage(
+        &stat,
+        cpu_usage,
+        cpu_fraction,
+        prev_thread
+            .map(|prev_thread| prev_thread.cpu_time)
+            .unwrap_or(stat.utime + stat.stime),
+        use_current_cpu_total,
+    );
+
+    let (total_read_bytes, total_write_bytes, read_bytes_per_sec, write_bytes_per_sec) =
+        if let Ok(io) = task.io() {
+            let total_read_bytes = io.read_bytes;
+            let total_write_bytes = io.write_bytes;
+            let (prev_read_bytes, prev_write_bytes) = prev_thread
+                .map(|prev_thread| (prev_thread.total_read_bytes, prev_thread.total_write_bytes))
+                .unwrap_or((total_read_bytes, total_write_bytes));
+
+            (
+                total_read_bytes,
+                total_write_bytes,
+                total_read_bytes
+                    .saturating_sub(prev_read_bytes)
+                    .checked_div(time_difference_in_secs)
+                    .unwrap_or(0),
+                total_write_bytes
+                    .saturating_sub(prev_write_bytes)
+                    .checked_div(time_difference_in_secs)
+                    .unwrap_or(0),
+            )
+        } else {
+            (0, 0, 0, 0)
+        };
+
+    Ok((
+        ProcessHarvest {
+            pid: task.tid,
+            parent_pid: Some(owner.pid),
+            cpu_usage_percent,
+            mem_usage_percent: owner.mem_usage_percent,
+            mem_usage_bytes: owner.mem_usage_bytes,
+            virt_bytes: owner.virt_bytes,
+            shared_bytes: owner.shared_bytes,
+            swap_bytes: owner.swap_bytes,
+            pss_bytes: owner.pss_bytes,
+            name: stat.comm.clone(),
+            command: stat.comm,
+            read_bytes_per_sec,
+            write_bytes_per_sec,
+            total_read_bytes,
+            total_write_bytes,
+            process_state,
+            uid: owner.uid,
+            user: owner.user.clone(),
+            threads: Vec::new(),
+            cgroup: owner.cgroup.clone(),
+        },
+        new_thread_times,
+    ))
+}
+
+/// Reads all threads of a process, updating the previous thread details stored in `prev_proc`.
+fn read_threads(
+    prev_proc: &mut PrevProcDetails, owner: &ProcessHarvest, cpu_usage: f64, cpu_fraction: f64,
+    use_current_cpu_total: bool, time_difference_in_secs: u64,
+) -> Vec<ProcessHarvest> {
+    let mut new_threads = FxHashMap::default();
+
+    let threads = if let Ok(tasks) = prev_proc.process.tasks() {
+        tasks
+            .filter_map(|task| {
+                let task = task.ok()?;
+                let (thread_harvest, new_thread_times) = read_thread(
+                    &task,
+                    owner,
+                    prev_proc.threads.get(&task.tid),
+                    cpu_usage,
+                    cpu_fraction,
+                    use_current_cpu_total,
+                    time_difference_in_secs,
+                )
+                .ok()?;
+
+                new_threads.insert(
+                    task.tid,
+                    PrevThreadDetails {
+                        total_read_bytes: thread_harvest.total_read_bytes,
+                        total_write_bytes: thread_harvest.total_write_bytes,
+                        cpu_time: new_thread_times,
+                    },
+                );
+
+                Some(thread_harvest)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Replacing the old mapping also drops any threads that have since exited.
+    prev_proc.threads = new_threads;
+
+    threads
+}
+
+/// Reads the details of a process that don't fit in the process table. Anything that can't be read, usually
+/// because permission is denied, is left empty.
+pub fn get_process_details(prev_proc: &PrevProcDetails) -> ProcessDetails {
+    use std::convert::TryFrom;
+
+    let process = &prev_proc.process;
+    let stat = process.stat().unwrap_or_else(|_| process.stat.clone());
+    let ticks_per_second = procfs::ticks_per_second().unwrap_or(100).max(1) as u64;
+
+    let mut environment = process
+        .environ()
+        .map(|environ| {
+            environ
+                .into_iter()
+                .map(|(key, value)| {
+                    format!("{}={}", key.to_string_lossy(), value.to_string_lossy())
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    environment.sort();
+
+    let limits = process
+        .limits()
+        .map(|limits| {
+            fn to_process_limit(name: &str, limit: &Limit) -> ProcessLimit {
+                fn value(value: &LimitValue) -> Option<u64> {
+                    match value {
+                        LimitValue::Unlimited => None,
+                        LimitValue::Value(value) => Some(*value),
+                    }
+                }
+
+                ProcessLimit {
+                    name: name.to_string(),
+                    soft: value(&limit.soft_limit),
+                    hard: value(&limit.hard_limit),
+                }
+            }
+
+            vec![
+                to_process_limit("CPU time (s)", &limits.max_cpu_time),
+                to_process_limit("File size (B)", &limits.max_file_size),
+                to_process_limit("Data size (B)", &limits.max_data_size),
+                to_process_limit("Stack size (B)", &limits.max_stack_size),
+                to_process_limit("Core file size (B)", &limits.max_core_file_size),
+                to_process_limit("Resident set (B)", &limits.max_resident_set),
+                to_process_limit("Processes", &limits.max_processes),
+                to_process_limit("Open files", &limits.max_open_files),
+                to_process_limit("Locked memory (B)", &limits.max_locked_memory),
+                to_process_limit("Address space (B)", &limits.max_address_space),
+                to_process_limit("File locks", &limits.max_file_locks),
+                to_process_limit("Pending signals", &limits.max_pending_signals),
+                to_process_limit("Message queue size (B)", &limits.max_msgqueue_size),
+                to_process_limit("Nice priority", &limits.max_nice_priority),
+                to_process_limit("Realtime priority", &limits.max_realtime_priority),
+                to_process_limit("Realtime timeout (us)", &limits.max_realtime_timeout),
+            ]
+        })
+        .unwrap_or_default();
+
+    let cgroups = process
+        .cgroups()
+        .map(|cgroups| {
+            cgroups
+                .into_iter()
+                .map(|cgroup| {
+                    format!(
+                        "{}:{}:{}",
+                        cgroup.hierarchy,
+                        cgroup.controllers.join(","),
+                        cgroup.pathname
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    ProcessDetails {
+        pid: process.pid,
+        command_line: process.cmdline().unwrap_or_default(),
+        environment,
+        cwd: process
+            .cwd()
+            .ok()
+            .map(|cwd| cwd.to_string_lossy().into_owned()),
+        exe: process
+            .exe()
+            .ok()
+            .map(|exe| exe.to_string_lossy().into_owned()),
+        start_time: procfs::boot_time_secs()
+            .ok()
+            .map(|boot_time| boot_time + stat.starttime / ticks_per_second),
+        cpu_time_secs: (stat.utime + stat.stime) as f64 / ticks_per_second as f64,
+        num_threads: u64::try_from(stat.num_threads).unwrap_or(0),
+        num_fds: process.fd_count().ok().map(|count| count as u64),
+        limits,
+        cgroups,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn get_process_data(
     prev_idle: &mut f64, prev_non_idle: &mut f64,
-    pid_mapping: &mut FxHashMap<Pid, PrevProcDetails>, use_current_cpu_total: bool,
-    time_difference_in_secs: u64, mem_total_kb: u64, user_table: &mut UserTable,
+    pid_mapping: &mut FxHashMap<Pid, PrevProcDetails>, thread_pids: &FxHashSet<Pid>,
+    collect_pss: bool, use_current_cpu_total: bool, time_difference_in_secs: u64,
+    mem_total_kb: u64, user_table: &mut UserTable,
 ) -> crate::utils::error::Result<Vec<ProcessHarvest>> {
-    // TODO: [PROC THREADS] Add threads
-
     if let Ok((cpu_usage, cpu_fraction)) = cpu_usage_calculation(prev_idle, prev_non_idle) {
         let mut pids_to_clear: FxHashSet<Pid> = pid_mapping.keys().cloned().collect();
 
@@ -267,7 +602,9 @@ pub fn get_process_data(
                                 return None;
                             }
 
-                            if let Ok((process_harvest, new_process_times)) = read_proc(
+                            let num_threads = stat.num_threads;
+
+                            if let Ok((mut process_harvest, new_process_times)) = read_proc(
                                 prev_proc_details,
                                 stat,
                                 cpu_usage,
@@ -275,6 +612,7 @@ pub fn get_process_data(
                                 use_current_cpu_total,
                                 time_difference_in_secs,
                                 mem_total_kb,
+                                collect_pss,
                                 user_table,
                             ) {
                                 prev_proc_details.cpu_time = new_process_times;
@@ -283,6 +621,21 @@ pub fn get_process_data(
                                 prev_proc_details.total_write_bytes =
                                     process_harvest.total_write_bytes;
 
+                                // Threads are only read for processes that are showing them, and single-threaded
+                                // processes have nothing extra to show.
+                                if num_threads > 1 && thread_pids.contains(&pid) {
+                                    process_harvest.threads = read_threads(
+                                        prev_proc_details,
+                                        &process_harvest,
+                                        cpu_usage,
+                                        cpu_fraction,
+                                        use_current_cpu_total,
+                                        time_difference_in_secs,
+                                    );
+                                } else {
+                                    prev_proc_details.threads.clear();
+                                }
+
                                 pids_to_clear.remove(&pid);
                                 return Some(process_harvest);
                             }
@@ -348,4 +701,109 @@ mod tests {
             "Failed to properly calculate idle/non-idle for /proc/stat CPU with 10 values"
         );
     }
+
+    #[test]
+    fn test_pss_parse() {
+        let smaps_rollup =
+            "55d7c0a4b000-7ffd3b5fd000 ---p 00000000 00:00 0                  [rollup]
+Rss:                4480 kB
+Pss:                1234 kB
+Pss_Anon:            512 kB
+Pss_File:            722 kB
+Shared_Clean:       3328 kB
+Swap:                  0 kB
+SwapPss:               0 kB";
+
+        assert_eq!(parse_pss_bytes(smaps_rollup), Some(1234 * 1024));
+        assert_eq!(parse_pss_bytes("Rss:                4480 kB"), None);
+    }
+
+    #[test]
+    fn test_cgroup_parse() {
+        assert_eq!(
+            parse_cgroup("0::/system.slice/sshd.service\n"),
+            Some("/system.slice/sshd.service")
+        );
+
+        // On a hybrid system, the unified hierarchy may just be the root.
+        let hybrid = "12:cpuset:/
+11:memory:/user.slice/user-1000.slice
+1:name=systemd:/user.slice/user-1000.slice/session-2.scope
+0::/";
+        assert_eq!(
+            parse_cgroup(hybrid),
+            Some("/user.slice/user-1000.slice/session-2.scope")
+        );
+        assert_eq!(parse_cgroup(""), None);
+    }
+
+    #[test]
+    fn test_container_id_parse() {
+        let id = "3f4e1d2c5b6a79880123456789abcdef0123456789abcdef0123456789abcdef";
+
+        for cgroup in [
+            format!("/docker/{}", id),
+            format!("/system.slice/docker-{}.scope", id),
+            format!("/machine.slice/libpod-{}.scope/container", id),
+            format!(
+                "/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1234.slice/cri-containerd-{}.scope",
+                id
+            ),
+            format!("/kubepods/besteffort/pod5678/{}", id),
+            format!("/kubepods.slice/kubepods-pod9.slice/crio-{}.scope", id),
+        ] {
+            assert_eq!(parse_container_id(&cgroup).as_deref(), Some(id), "{}", cgroup);
+        }
+
+        assert_eq!(parse_container_id("/system.slice/sshd.service"), None);
+        assert_eq!(
+            parse_container_id(&format!("/machine.slice/libpod-conmon-{}.scope", id)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_systemd_unit_parse() {
+        assert_eq!(
+            parse_systemd_unit("/system.slice/sshd.service").as_deref(),
+            Some("sshd.service")
+        );
+        assert_eq!(
+            parse_systemd_unit(
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/app-firefox-1234.scope"
+            )
+            .as_deref(),
+            Some("app-firefox-1234.scope")
+        );
+        assert_eq!(
+            parse_systemd_unit("/user.slice/user-1000.slice").as_deref(),
+            Some("user-1000.slice")
+        );
+        assert_eq!(parse_systemd_unit("/"), None);
+    }
+
+    #[test]
+    fn test_get_process_details() {
+        let pid = std::process::id() as Pid;
+        let details = get_process_details(&PrevProcDetails::new(pid).unwrap());
+
+        assert_eq!(details.pid, pid);
+        assert!(!details.command_line.is_empty());
+        assert!(details.exe.is_some());
+        assert!(details.cwd.is_some());
+        assert!(details.start_time.is_some());
+        assert!(details.num_threads >= 1);
+        assert!(matches!(details.num_fds, Some(fds) if fds >= 1));
+
+        // Cargo runs tests with its own variables set, and the environment is sorted.
+        assert!(details
+            .environment
+            .iter()
+            .any(|var| var.starts_with("CARGO_PKG_NAME=")));
+        assert!(details.environment.windows(2).all(|w| w[0] <= w[1]));
+
+        assert_eq!(details.limits.len(), 16);
+        assert_eq!(details.limits[7].name, "Open files");
+        assert!(details.limits[7].soft.is_some());
+    }
 }
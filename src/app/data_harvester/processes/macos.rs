@@ -104,6 +104,10 @@ pub fn get_process_data(
                 0.0
             },
             mem_usage_bytes: process_val.memory() * 1024,
+            virt_bytes: process_val.virtual_memory() * 1024,
+            shared_bytes: 0,
+            swap_bytes: 0,
+            pss_bytes: 0,
             cpu_usage_percent: process_cpu_usage,
             read_bytes_per_sec: disk_usage.read_bytes,
             write_bytes_per_sec: disk_usage.written_bytes,
@@ -115,6 +119,8 @@ pub fn get_process_data(
                 .get_uid_to_username_mapping(uid)
                 .map(Into::into)
                 .unwrap_or_else(|_| "N/A".into()),
+            threads: Vec::new(),
+            cgroup: Default::default(),
         });
     }
 
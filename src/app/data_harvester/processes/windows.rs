@@ -67,12 +67,18 @@ pub fn get_process_data(
                 0.0
             },
             mem_usage_bytes: process_val.memory() * 1024,
+            virt_bytes: process_val.virtual_memory() * 1024,
+            shared_bytes: 0,
+            swap_bytes: 0,
+            pss_bytes: 0,
             cpu_usage_percent: process_cpu_usage,
             read_bytes_per_sec: disk_usage.read_bytes,
             write_bytes_per_sec: disk_usage.written_bytes,
             total_read_bytes: disk_usage.total_read_bytes,
             total_write_bytes: disk_usage.total_written_bytes,
             process_state,
+            threads: Vec::new(),
+            cgroup: Default::default(),
         });
     }
 
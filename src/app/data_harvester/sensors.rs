@@ -0,0 +1,353 @@
+//! Data collection for hardware sensors other than temperatures, which are fans, voltages, currents, and power.
+//!
+//! These are read from each hwmon device in [`HWMON_DIR`]. The power of each RAPL domain in [`POWERCAP_DIR`] is
+//! also worked out from how much its energy counter went up since the last harvest, though reading those
+//! counters usually needs root. Nothing is collected on systems without these directories.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::{data_harvester::read_value, Filter};
+
+/// Where the kernel lists hwmon devices.
+pub const HWMON_DIR: &str = "/sys/class/hwmon";
+
+/// Where the kernel lists power capping zones, which includes RAPL domains.
+pub const POWERCAP_DIR: &str = "/sys/class/powercap";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SensorType {
+    Fan,
+    Voltage,
+    Current,
+    Power,
+}
+
+impl SensorType {
+    const ALL: [SensorType; 4] = [
+        SensorType::Fan,
+        SensorType::Voltage,
+        SensorType::Current,
+        SensorType::Power,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SensorType::Fan => "Fan",
+            SensorType::Voltage => "Voltage",
+            SensorType::Current => "Current",
+            SensorType::Power => "Power",
+        }
+    }
+
+    /// The unit values of this type are in.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            SensorType::Fan => "RPM",
+            SensorType::Voltage => "V",
+            SensorType::Current => "A",
+            SensorType::Power => "W",
+        }
+    }
+
+    /// The prefix of the hwmon files for this type of sensor, and what their values are divided by to be in
+    /// [`SensorType::unit`].
+    fn hwmon_prefix(&self) -> (&'static str, f64) {
+        match self {
+            SensorType::Fan => ("fan", 1.0),
+            SensorType::Voltage => ("in", 1_000.0),
+            SensorType::Current => ("curr", 1_000.0),
+            SensorType::Power => ("power", 1_000_000.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SensorHarvest {
+    pub name: String,
+    /// The device the sensor was read from, like `hwmon3` or `intel-rapl:0`, which tells apart sensors that
+    /// share a name.
+    #[serde(default)]
+    pub device: String,
+    pub sensor_type: SensorType,
+    /// The reading in the unit of the type of sensor.
+    pub value: f64,
+}
+
+/// The energy counter of each RAPL domain in microjoules, as of the last harvest.
+pub type PrevRaplEnergy = fxhash::FxHashMap<PathBuf, u64>;
+
+/// Whether a sensor is kept by a filter, which either lists the sensors to drop or the only ones to keep.
+fn is_sensor_kept(filter: &Option<Filter>, name: &str) -> bool {
+    match filter {
+        Some(filter) => filter.list.iter().any(|r| r.is_match(name)) != filter.is_list_ignored,
+        None => true,
+    }
+}
+
+/// Returns the channel of a hwmon file named like `{prefix}{channel}_{suffix}`, such as `fan2_input`.
+fn hwmon_channel<'a>(file_name: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    let channel = file_name
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .strip_suffix('_')?;
+
+    if !channel.is_empty() && channel.chars().all(|c| c.is_ascii_digit()) {
+        Some(channel)
+    } else {
+        None
+    }
+}
+
+/// Reads the fans, voltages, currents, and power inputs of the hwmon device in `dir`.
+fn read_hwmon_sensors(dir: &Path, sensors: &mut Vec<SensorHarvest>) {
+    let device = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    // Some older drivers put their files in the device directory instead.
+    let dir = if dir.join("name").is_file() {
+        dir.to_path_buf()
+    } else {
+        dir.join("device")
+    };
+    let chip = read_value::<String>(&dir, "name").unwrap_or_default();
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_name = match file_name.to_str() {
+            Some(file_name) => file_name,
+            None => continue,
+        };
+
+        for sensor_type in SensorType::ALL {
+            let (prefix, divisor) = sensor_type.hwmon_prefix();
+            let channel = match hwmon_channel(file_name, prefix, "input") {
+                Some(channel) => channel,
+                // Some power sensors only report an average.
+                None if sensor_type == SensorType::Power => {
+                    match hwmon_channel(file_name, prefix, "average") {
+                        Some(channel)
+                            if !dir.join(format!("{}{}_input", prefix, channel)).exists() =>
+                        {
+                            channel
+                        }
+                        _ => continue,
+                    }
+                }
+                None => continue,
+            };
+
+            if let Some(value) = read_value::<f64>(&dir, file_name) {
+                let label = read_value(&dir, &format!("{}{}_label", prefix, channel))
+                    .unwrap_or_else(|| format!("{}{}", prefix, channel));
+
+                sensors.push(SensorHarvest {
+                    name: if chip.is_empty() {
+                        label
+                    } else {
+                        format!("{}: {}", chip, label)
+                    },
+                    device: device.clone(),
+                    sensor_type,
+                    value: value / divisor,
+                });
+            }
+        }
+    }
+}
+
+/// Works out the power of each RAPL domain in `dir` from how much its energy counter went up since
+/// `prev_energy`, which is then replaced with the current counters. Domains that weren't read last time are
+/// skipped until the next harvest.
+fn read_rapl_sensors(
+    dir: &Path, prev_energy: &mut PrevRaplEnergy, elapsed_time: f64,
+    sensors: &mut Vec<SensorHarvest>,
+) {
+    let mut new_energy = PrevRaplEnergy::default();
+
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let energy = match read_value(&path, "energy_uj") {
+                Some(energy) => energy,
+                None => continue,
+            };
+
+            if let Some(prev) = prev_energy.get(&path) {
+                // The counter wraps around once it reaches its maximum. If that isn't known, there is no telling
+                // how much energy was used, so the domain is skipped until the next harvest.
+                let used_energy = if energy >= *prev {
+                    Some(energy - prev)
+                } else {
+                    read_value::<u64>(&path, "max_energy_range_uj")
+                        .map(|max| max.saturating_sub(*prev) + energy)
+                };
+
+                if let Some(used_energy) = used_energy.filter(|_| elapsed_time > 0.0) {
+                    // Subdomains, like `intel-rapl:0:0`, are named after the domain they are part of.
+                    let zone = entry.file_name().to_string_lossy().to_string();
+                    let domain_name = read_value(&path, "name").unwrap_or_else(|| zone.clone());
+                    let name = match zone.rsplit_once(':').map(|(parent, _)| parent) {
+                        Some(parent) if parent.contains(':') => {
+                            match read_value::<String>(&dir.join(parent), "name") {
+                                Some(parent_name) => {
+                                    format!("RAPL: {} {}", parent_name, domain_name)
+                                }
+                                None => format!("RAPL: {}", domain_name),
+                            }
+                        }
+                        _ => format!("RAPL: {}", domain_name),
+                    };
+
+                    sensors.push(SensorHarvest {
+                        name,
+                        device: zone,
+                        sensor_type: SensorType::Power,
+                        value: used_energy as f64 / 1_000_000.0 / elapsed_time,
+                    });
+                }
+            }
+
+            new_energy.insert(path, energy);
+        }
+    }
+
+    *prev_energy = new_energy;
+}
+
+/// Collects the sensors of every hwmon device in `hwmon_dir` and RAPL domain in `powercap_dir` that `filter`
+/// keeps, sorted by type and then by name.
+pub fn get_sensor_data(
+    hwmon_dir: &Path, powercap_dir: &Path, filter: &Option<Filter>,
+    prev_rapl_energy: &mut PrevRaplEnergy, elapsed_time: f64,
+) -> Vec<SensorHarvest> {
+    let mut sensors = vec![];
+
+    if let Ok(entries) = fs::read_dir(hwmon_dir) {
+        for entry in entries.flatten() {
+            read_hwmon_sensors(&entry.path(), &mut sensors);
+        }
+    }
+    read_rapl_sensors(powercap_dir, prev_rapl_energy, elapsed_time, &mut sensors);
+
+    sensors.retain(|sensor| is_sensor_kept(filter, &sensor.name));
+    sensors.sort_by(|a, b| {
+        a.sensor_type
+            .cmp(&b.sensor_type)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    sensors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::FakeDir;
+
+    #[test]
+    fn test_hwmon_channel() {
+        assert_eq!(hwmon_channel("fan2_input", "fan", "input"), Some("2"));
+        assert_eq!(hwmon_channel("in10_input", "in", "input"), Some("10"));
+        assert_eq!(hwmon_channel("in0_label", "in", "input"), None);
+        assert_eq!(hwmon_channel("intrusion0_alarm", "in", "alarm"), None);
+        assert_eq!(
+            hwmon_channel("power1_average_interval", "power", "average"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_sensor_data() {
+        let dir = FakeDir::default();
+        dir.write("hwmon/hwmon0/name", "nct6775\n");
+        dir.write("hwmon/hwmon0/fan1_input", "1200\n");
+        dir.write("hwmon/hwmon0/fan2_input", "800\n");
+        dir.write("hwmon/hwmon0/fan2_label", "CPU fan\n");
+        dir.write("hwmon/hwmon0/in0_input", "1056\n");
+        dir.write("hwmon/hwmon0/temp1_input", "40000\n");
+        dir.write("hwmon/hwmon1/device/name", "amdgpu\n");
+        dir.write("hwmon/hwmon1/device/power1_average", "35000000\n");
+        dir.write("hwmon/hwmon1/device/curr1_input", "1500\n");
+        dir.write("powercap/intel-rapl:0/name", "package-0\n");
+        dir.write("powercap/intel-rapl:0/energy_uj", "1000000\n");
+        dir.write("powercap/intel-rapl:0:0/name", "core\n");
+        dir.write("powercap/intel-rapl:0:0/energy_uj", "900000\n");
+        dir.write("powercap/intel-rapl:0:0/max_energy_range_uj", "1000000\n");
+        dir.write("powercap/intel-rapl:1/name", "package-1\n");
+        dir.write("powercap/intel-rapl:1/energy_uj", "900000\n");
+
+        let hwmon_dir = dir.join("hwmon");
+        let powercap_dir = dir.join("powercap");
+        let mut prev_rapl_energy = PrevRaplEnergy::default();
+        let first = get_sensor_data(&hwmon_dir, &powercap_dir, &None, &mut prev_rapl_energy, 0.0);
+
+        dir.write("powercap/intel-rapl:0/energy_uj", "6000000\n");
+        dir.write("powercap/intel-rapl:0:0/energy_uj", "400000\n");
+        dir.write("powercap/intel-rapl:1/energy_uj", "400000\n");
+        let filter = Some(Filter {
+            is_list_ignored: true,
+            list: vec![regex::Regex::new("fan1").unwrap()],
+        });
+        let second = get_sensor_data(
+            &hwmon_dir,
+            &powercap_dir,
+            &filter,
+            &mut prev_rapl_energy,
+            2.0,
+        );
+
+        let readings = |sensors: &[SensorHarvest]| {
+            sensors
+                .iter()
+                .map(|sensor| (sensor.name.clone(), sensor.sensor_type, sensor.value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            readings(&first),
+            vec![
+                ("nct6775: CPU fan".to_string(), SensorType::Fan, 800.0),
+                ("nct6775: fan1".to_string(), SensorType::Fan, 1200.0),
+                ("nct6775: in0".to_string(), SensorType::Voltage, 1.056),
+                ("amdgpu: curr1".to_string(), SensorType::Current, 1.5),
+                ("amdgpu: power1".to_string(), SensorType::Power, 35.0),
+            ]
+        );
+        assert_eq!(
+            readings(&second),
+            vec![
+                ("nct6775: CPU fan".to_string(), SensorType::Fan, 800.0),
+                ("nct6775: in0".to_string(), SensorType::Voltage, 1.056),
+                ("amdgpu: curr1".to_string(), SensorType::Current, 1.5),
+                ("RAPL: package-0".to_string(), SensorType::Power, 2.5),
+                ("RAPL: package-0 core".to_string(), SensorType::Power, 0.25),
+                ("amdgpu: power1".to_string(), SensorType::Power, 35.0),
+            ]
+        );
+        assert_eq!(
+            second
+                .iter()
+                .map(|sensor| sensor.device.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "hwmon0",
+                "hwmon0",
+                "hwmon1",
+                "intel-rapl:0",
+                "intel-rapl:0:0",
+                "hwmon1"
+            ]
+        );
+    }
+}
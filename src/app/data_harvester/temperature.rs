@@ -20,10 +20,66 @@ use std::cmp::Ordering;
 
 use crate::app::Filter;
 
-#[derive(Default, Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct TempHarvest {
     pub name: String,
     pub temperature: f32,
+    /// The temperature the sensor reports as high, such as `temp1_max` in hwmon, if it reports one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub high: Option<f32>,
+    /// The temperature the sensor reports as critical, such as `temp1_crit` in hwmon, if it reports one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical: Option<f32>,
+}
+
+/// A user's own warning and critical temperatures for the sensors whose names match `sensor`, which replace the
+/// limits the sensors report. These are in the same unit as the temperatures.
+#[derive(Debug, Clone)]
+pub struct TempThreshold {
+    pub sensor: regex::Regex,
+    pub warning: Option<f32>,
+    pub critical: Option<f32>,
+}
+
+/// How close a temperature is to its limits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TempLevel {
+    Normal,
+    /// At or above the warning temperature.
+    Warning,
+    /// At or above the critical temperature.
+    Critical,
+}
+
+impl TempHarvest {
+    /// Returns the warning and critical temperatures of this sensor. The first threshold matching its name takes
+    /// precedence, and any limit it leaves out falls back to the one the sensor reports.
+    pub fn limits(&self, thresholds: &[TempThreshold]) -> (Option<f32>, Option<f32>) {
+        match thresholds
+            .iter()
+            .find(|threshold| threshold.sensor.is_match(&self.name))
+        {
+            Some(threshold) => (
+                threshold.warning.or(self.high),
+                threshold.critical.or(self.critical),
+            ),
+            None => (self.high, self.critical),
+        }
+    }
+
+    pub fn level(&self, thresholds: &[TempThreshold]) -> TempLevel {
+        let (warning, critical) = self.limits(thresholds);
+
+        if matches!(critical, Some(critical) if self.temperature >= critical) {
+            TempLevel::Critical
+        } else if matches!(warning, Some(warning) if self.temperature >= warning) {
+            TempLevel::Warning
+        } else {
+            TempLevel::Normal
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -86,3 +142,57 @@ fn temp_vec_sort(temperature_vec: &mut [TempHarvest]) {
 
     temperature_vec.sort_by(|a, b| a.name.partial_cmp(&b.name).unwrap_or(Ordering::Equal));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_level() {
+        let sensor = |name: &str, temperature: f32| TempHarvest {
+            name: name.to_string(),
+            temperature,
+            high: Some(80.0),
+            critical: Some(100.0),
+        };
+        let thresholds = vec![TempThreshold {
+            sensor: regex::Regex::new("(?i)k10temp").unwrap(),
+            warning: Some(60.0),
+            critical: None,
+        }];
+
+        assert_eq!(
+            sensor("k10temp: Tctl", 50.0).limits(&thresholds),
+            (Some(60.0), Some(100.0))
+        );
+        assert_eq!(
+            sensor("nvme: Composite", 50.0).limits(&thresholds),
+            (Some(80.0), Some(100.0))
+        );
+        assert_eq!(
+            sensor("k10temp: Tctl", 50.0).level(&thresholds),
+            TempLevel::Normal
+        );
+        assert_eq!(
+            sensor("k10temp: Tctl", 70.0).level(&thresholds),
+            TempLevel::Warning
+        );
+        assert_eq!(
+            sensor("nvme: Composite", 70.0).level(&thresholds),
+            TempLevel::Normal
+        );
+        assert_eq!(
+            sensor("nvme: Composite", 100.0).level(&thresholds),
+            TempLevel::Critical
+        );
+        assert_eq!(
+            TempHarvest {
+                name: "acpitz".to_string(),
+                temperature: 120.0,
+                ..Default::default()
+            }
+            .level(&[]),
+            TempLevel::Normal
+        );
+    }
+}
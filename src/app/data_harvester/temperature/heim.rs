@@ -7,12 +7,27 @@ pub async fn get_temperature_data(
     temp_type: &TemperatureType, actually_get: bool, filter: &Option<Filter>,
 ) -> crate::utils::error::Result<Option<Vec<TempHarvest>>> {
     use futures::StreamExt;
-    use heim::units::thermodynamic_temperature;
+    use heim::units::{thermodynamic_temperature, ThermodynamicTemperature};
 
     if !actually_get {
         return Ok(None);
     }
 
+    let convert = |temperature: ThermodynamicTemperature| match temp_type {
+        TemperatureType::Celsius => temperature.get::<thermodynamic_temperature::degree_celsius>(),
+        TemperatureType::Kelvin => temperature.get::<thermodynamic_temperature::kelvin>(),
+        TemperatureType::Fahrenheit => {
+            temperature.get::<thermodynamic_temperature::degree_fahrenheit>()
+        }
+    };
+
+    // Some drivers report a limit of zero when they don't have one.
+    let convert_limit = |limit: Option<ThermodynamicTemperature>| {
+        limit
+            .filter(|limit| limit.get::<thermodynamic_temperature::degree_celsius>() > 0.0)
+            .map(convert)
+    };
+
     let mut temperature_vec: Vec<TempHarvest> = Vec::new();
 
     let mut sensor_data = heim::sensors::temperatures().boxed_local();
@@ -31,19 +46,9 @@ pub async fn get_temperature_data(
             if is_temp_filtered(filter, &name) {
                 temperature_vec.push(TempHarvest {
                     name,
-                    temperature: match temp_type {
-                        TemperatureType::Celsius => sensor
-                            .current()
-                            .get::<thermodynamic_temperature::degree_celsius>(
-                        ),
-                        TemperatureType::Kelvin => {
-                            sensor.current().get::<thermodynamic_temperature::kelvin>()
-                        }
-                        TemperatureType::Fahrenheit => sensor
-                            .current()
-                            .get::<thermodynamic_temperature::degree_fahrenheit>(
-                        ),
-                    },
+                    temperature: convert(sensor.current()),
+                    high: convert_limit(sensor.high()),
+                    critical: convert_limit(sensor.critical()),
                 });
             }
         }
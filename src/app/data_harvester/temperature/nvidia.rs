@@ -5,7 +5,10 @@ use super::{
     TemperatureType,
 };
 
-use nvml_wrapper::{enum_wrappers::device::TemperatureSensor, NVML};
+use nvml_wrapper::{
+    enum_wrappers::device::{TemperatureSensor, TemperatureThreshold},
+    NVML,
+};
 
 pub fn add_nvidia_data(
     temperature_vec: &mut Vec<TempHarvest>, temp_type: &TemperatureType, filter: &Option<Filter>,
@@ -18,16 +21,33 @@ pub fn add_nvidia_data(
                         (device.name(), device.temperature(TemperatureSensor::Gpu))
                     {
                         if is_temp_filtered(filter, &name) {
-                            let temperature = temperature as f32;
-                            let temperature = match temp_type {
-                                TemperatureType::Celsius => temperature,
-                                TemperatureType::Kelvin => convert_celsius_to_kelvin(temperature),
-                                TemperatureType::Fahrenheit => {
-                                    convert_celsius_to_fahrenheit(temperature)
+                            let convert = |temperature: u32| {
+                                let temperature = temperature as f32;
+                                match temp_type {
+                                    TemperatureType::Celsius => temperature,
+                                    TemperatureType::Kelvin => {
+                                        convert_celsius_to_kelvin(temperature)
+                                    }
+                                    TemperatureType::Fahrenheit => {
+                                        convert_celsius_to_fahrenheit(temperature)
+                                    }
                                 }
                             };
 
-                            temperature_vec.push(TempHarvest { name, temperature });
+                            // The GPU starts to throttle at the slowdown temperature, and shuts down at the
+                            // shutdown one.
+                            temperature_vec.push(TempHarvest {
+                                name,
+                                temperature: convert(temperature),
+                                high: device
+                                    .temperature_threshold(TemperatureThreshold::Slowdown)
+                                    .ok()
+                                    .map(convert),
+                                critical: device
+                                    .temperature_threshold(TemperatureThreshold::Shutdown)
+                                    .ok()
+                                    .map(convert),
+                            });
                         }
                     }
                 }
@@ -22,15 +22,21 @@ pub async fn get_temperature_data(
         let name = component.label().to_string();
 
         if is_temp_filtered(filter, &name) {
+            let convert = |temperature: f32| match temp_type {
+                TemperatureType::Celsius => temperature,
+                TemperatureType::Kelvin => convert_celsius_to_kelvin(temperature),
+                TemperatureType::Fahrenheit => convert_celsius_to_fahrenheit(temperature),
+            };
+
             temperature_vec.push(TempHarvest {
                 name,
-                temperature: match temp_type {
-                    TemperatureType::Celsius => component.temperature(),
-                    TemperatureType::Kelvin => convert_celsius_to_kelvin(component.temperature()),
-                    TemperatureType::Fahrenheit => {
-                        convert_celsius_to_fahrenheit(component.temperature())
-                    }
-                },
+                temperature: convert(component.temperature()),
+                // sysinfo only reports the highest temperature seen so far, not a limit.
+                high: None,
+                critical: component
+                    .critical()
+                    .filter(|critical| *critical > 0.0)
+                    .map(convert),
             });
         }
     }
@@ -0,0 +1,767 @@
+//! Key bindings, which map key presses to the actions they perform. Every action has a default set of keys,
+//! which can be replaced through the `[keybindings]` table of the config file.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::error::{self, BottomError};
+
+/// The width of the key column of the help menu, including the gap before the description.
+const HELP_KEY_WIDTH: usize = 17;
+
+/// Something that can be bound to a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Reset,
+    Freeze,
+    Help,
+    MoveWidgetLeft,
+    MoveWidgetRight,
+    MoveWidgetUp,
+    MoveWidgetDown,
+    Left,
+    Right,
+    Up,
+    Down,
+    SkipToFirst,
+    SkipToLast,
+    PageUp,
+    PageDown,
+    Expand,
+    ZoomIn,
+    ZoomOut,
+    ResetZoom,
+    KillProcess,
+    KillProcessTree,
+    StopProcess,
+    ContinueProcess,
+    ChangePriority,
+    ChangeAffinity,
+    ShowProcessDetails,
+    ToggleMark,
+    ClearMarks,
+    SortByCpu,
+    SortByMem,
+    SortByPid,
+    SortByName,
+    ToggleCommand,
+    Search,
+    Sort,
+    InvertSort,
+    TogglePercentages,
+    ToggleTree,
+    ToggleThreads,
+    ToggleCgroupGrouping,
+    CycleCpuGrouping,
+    ReplayPause,
+    ReplaySlowDown,
+    ReplaySpeedUp,
+    ReplaySeekBackward,
+    ReplaySeekForward,
+}
+
+impl Action {
+    pub const ALL: [Action; 47] = [
+        Action::Quit,
+        Action::Reset,
+        Action::Freeze,
+        Action::Help,
+        Action::MoveWidgetLeft,
+        Action::MoveWidgetRight,
+        Action::MoveWidgetUp,
+        Action::MoveWidgetDown,
+        Action::Left,
+        Action::Right,
+        Action::Up,
+        Action::Down,
+        Action::SkipToFirst,
+        Action::SkipToLast,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Expand,
+        Action::ZoomIn,
+        Action::ZoomOut,
+        Action::ResetZoom,
+        Action::KillProcess,
+        Action::KillProcessTree,
+        Action::StopProcess,
+        Action::ContinueProcess,
+        Action::ChangePriority,
+        Action::ChangeAffinity,
+        Action::ShowProcessDetails,
+        Action::ToggleMark,
+        Action::ClearMarks,
+        Action::SortByCpu,
+        Action::SortByMem,
+        Action::SortByPid,
+        Action::SortByName,
+        Action::ToggleCommand,
+        Action::Search,
+        Action::Sort,
+        Action::InvertSort,
+        Action::TogglePercentages,
+        Action::ToggleTree,
+        Action::ToggleThreads,
+        Action::ToggleCgroupGrouping,
+        Action::CycleCpuGrouping,
+        Action::ReplayPause,
+        Action::ReplaySlowDown,
+        Action::ReplaySpeedUp,
+        Action::ReplaySeekBackward,
+        Action::ReplaySeekForward,
+    ];
+
+    /// The name of the action in the config file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Reset => "reset",
+            Action::Freeze => "freeze",
+            Action::Help => "help",
+            Action::MoveWidgetLeft => "move_widget_left",
+            Action::MoveWidgetRight => "move_widget_right",
+            Action::MoveWidgetUp => "move_widget_up",
+            Action::MoveWidgetDown => "move_widget_down",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::SkipToFirst => "skip_to_first",
+            Action::SkipToLast => "skip_to_last",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Expand => "expand",
+            Action::ZoomIn => "zoom_in",
+            Action::ZoomOut => "zoom_out",
+            Action::ResetZoom => "reset_zoom",
+            Action::KillProcess => "kill_process",
+            Action::KillProcessTree => "kill_process_tree",
+            Action::StopProcess => "stop_process",
+            Action::ContinueProcess => "continue_process",
+            Action::ChangePriority => "change_priority",
+            Action::ChangeAffinity => "change_affinity",
+            Action::ShowProcessDetails => "show_process_details",
+            Action::ToggleMark => "toggle_mark",
+            Action::ClearMarks => "clear_marks",
+            Action::SortByCpu => "sort_by_cpu",
+            Action::SortByMem => "sort_by_mem",
+            Action::SortByPid => "sort_by_pid",
+            Action::SortByName => "sort_by_name",
+            Action::ToggleCommand => "toggle_command",
+            Action::Search => "search",
+            Action::Sort => "sort",
+            Action::InvertSort => "invert_sort",
+            Action::TogglePercentages => "toggle_percentages",
+            Action::ToggleTree => "toggle_tree",
+            Action::ToggleThreads => "toggle_threads",
+            Action::ToggleCgroupGrouping => "toggle_cgroup_grouping",
+            Action::CycleCpuGrouping => "cycle_cpu_grouping",
+            Action::ReplayPause => "replay_pause",
+            Action::ReplaySlowDown => "replay_slow_down",
+            Action::ReplaySpeedUp => "replay_speed_up",
+            Action::ReplaySeekBackward => "replay_seek_backward",
+            Action::ReplaySeekForward => "replay_seek_forward",
+        }
+    }
+
+    /// The keys the action is bound to if the config file doesn't say otherwise, in the same format as the
+    /// config file.
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::Reset => &["Ctrl-r"],
+            Action::Freeze => &["f"],
+            Action::Help => &["?"],
+            Action::MoveWidgetLeft => &["Ctrl-Left", "Shift-Left", "H", "A"],
+            Action::MoveWidgetRight => &["Ctrl-Right", "Shift-Right", "L", "D"],
+            Action::MoveWidgetUp => &["Ctrl-Up", "Shift-Up", "K", "W"],
+            Action::MoveWidgetDown => &["Ctrl-Down", "Shift-Down", "J", "S"],
+            Action::Left => &["Left", "h", "Alt-h"],
+            Action::Right => &["Right", "l", "Alt-l"],
+            Action::Up => &["Up", "k"],
+            Action::Down => &["Down", "j"],
+            Action::SkipToFirst => &["g g", "Home"],
+            Action::SkipToLast => &["G", "End"],
+            Action::PageUp => &["PgUp"],
+            Action::PageDown => &["PgDown"],
+            Action::Expand => &["e"],
+            Action::ZoomIn => &["+"],
+            Action::ZoomOut => &["-"],
+            Action::ResetZoom => &["="],
+            Action::KillProcess => &["d d", "F9"],
+            Action::KillProcessTree => &["d t"],
+            Action::StopProcess => &["z"],
+            Action::ContinueProcess => &["Z"],
+            Action::ChangePriority => &["r"],
+            Action::ChangeAffinity => &["a"],
+            Action::ShowProcessDetails => &["i"],
+            Action::ToggleMark => &["v"],
+            Action::ClearMarks => &["V"],
+            Action::SortByCpu => &["c"],
+            Action::SortByMem => &["m"],
+            Action::SortByPid => &["p"],
+            Action::SortByName => &["n"],
+            Action::ToggleCommand => &["P"],
+            Action::Search => &["Ctrl-f", "/"],
+            Action::Sort => &["s", "F6"],
+            Action::InvertSort => &["I"],
+            Action::TogglePercentages => &["%"],
+            Action::ToggleTree => &["t", "F5"],
+            Action::ToggleThreads => &["T"],
+            Action::ToggleCgroupGrouping => &["C"],
+            Action::CycleCpuGrouping => &["o"],
+            Action::ReplayPause => &["Space"],
+            Action::ReplaySlowDown => &["<"],
+            Action::ReplaySpeedUp => &[">"],
+            Action::ReplaySeekBackward => &["["],
+            Action::ReplaySeekForward => &["]"],
+        }
+    }
+
+    /// Whether the action still works while a dialog is open, which is limited to navigating the dialog.
+    pub fn is_allowed_in_dialog(&self) -> bool {
+        matches!(
+            self,
+            Action::Quit
+                | Action::Reset
+                | Action::Left
+                | Action::Right
+                | Action::Up
+                | Action::Down
+                | Action::SkipToFirst
+                | Action::SkipToLast
+                | Action::PageUp
+                | Action::PageDown
+        )
+    }
+}
+
+/// A single key press, along with its modifiers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // The case of a character already says whether shift was held, and terminals don't agree on whether to
+        // report it as a modifier too, so it's dropped.
+        match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => KeyChord {
+                code: KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => KeyChord { code, modifiers },
+        }
+    }
+
+    /// Parses a key like `q`, `G`, `F5`, `PgUp`, `Ctrl-r` or `Shift-Left`. Modifiers and key names are case
+    /// insensitive, but single characters are not.
+    fn parse(key: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = key;
+        loop {
+            let lower = rest.to_lowercase();
+            let (modifier, len) = if lower.starts_with("ctrl-") {
+                (KeyModifiers::CONTROL, "ctrl-".len())
+            } else if lower.starts_with("alt-") {
+                (KeyModifiers::ALT, "alt-".len())
+            } else if lower.starts_with("shift-") {
+                (KeyModifiers::SHIFT, "shift-".len())
+            } else {
+                break;
+            };
+            modifiers |= modifier;
+            rest = &rest[len..];
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match rest.to_lowercase().as_str() {
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pgup" | "pageup" => KeyCode::PageUp,
+                "pgdown" | "pagedown" => KeyCode::PageDown,
+                "enter" => KeyCode::Enter,
+                "esc" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "space" => KeyCode::Char(' '),
+                name => match name.strip_prefix('f').map(str::parse::<u8>) {
+                    Some(Ok(num)) if (1..=12).contains(&num) => KeyCode::F(num),
+                    _ => return None,
+                },
+            },
+        };
+
+        Some(KeyChord::new(code, modifiers))
+    }
+
+    /// Whether the chord is a plain character, which is shown as-is when part of a sequence like `gg`.
+    fn is_plain_char(&self) -> bool {
+        self.modifiers.is_empty() && matches!(self.code, KeyCode::Char(c) if c != ' ')
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(event: KeyEvent) -> Self {
+        KeyChord::new(event.code, event.modifiers)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift-")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(num) => write!(f, "F{}", num),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDown"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Keys that are always handled the same way, and so can't be bound to anything.
+const RESERVED_KEYS: [(KeyCode, KeyModifiers); 6] = [
+    (KeyCode::Char('c'), KeyModifiers::CONTROL),
+    (KeyCode::Esc, KeyModifiers::NONE),
+    (KeyCode::Enter, KeyModifiers::NONE),
+    (KeyCode::Tab, KeyModifiers::NONE),
+    (KeyCode::Backspace, KeyModifiers::NONE),
+    (KeyCode::Delete, KeyModifiers::NONE),
+];
+
+/// A key, or a sequence of two keys like `gg`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct KeySequence(Vec<KeyChord>);
+
+impl KeySequence {
+    /// Parses a single key, or two keys separated by a space.
+    fn parse(keys: &str) -> Option<Self> {
+        let chords = keys
+            .split(' ')
+            .filter(|key| !key.is_empty())
+            .map(KeyChord::parse)
+            .collect::<Option<Vec<_>>>()?;
+
+        if (1..=2).contains(&chords.len()) {
+            Some(KeySequence(chords))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.0.iter().all(KeyChord::is_plain_char) {
+            ""
+        } else {
+            " "
+        };
+        for (index, chord) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, "{}", separator)?;
+            }
+            write!(f, "{}", chord)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The keys given to an action in the config file, either as a single key or a list of them.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ConfigKeys {
+    Single(String),
+    List(Vec<String>),
+}
+
+impl ConfigKeys {
+    fn keys(&self) -> Vec<&str> {
+        match self {
+            ConfigKeys::Single(key) => vec![key.as_str()],
+            ConfigKeys::List(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// What a key press resolved to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyLookup {
+    /// The key completed a binding.
+    Action(Action),
+    /// The key starts a sequence, so the next key is needed to know what to do.
+    Pending,
+    /// The key isn't bound to anything.
+    Unbound,
+}
+
+/// A line of the help menu.
+pub enum HelpEntry {
+    /// A line that doesn't depend on the key bindings.
+    Text(&'static str),
+    /// The keys bound to the given actions followed by some keys that can't be rebound, then a description.
+    Keys(&'static [Action], &'static [&'static str], &'static str),
+}
+
+/// The keys bound to every action.
+#[derive(Clone, Debug)]
+pub struct KeyBindings {
+    bindings: Vec<(Action, Vec<KeySequence>)>,
+    lookup: HashMap<KeySequence, Action>,
+    prefixes: HashSet<KeyChord>,
+}
+
+impl KeyBindings {
+    /// Creates the key bindings, where the keys given to an action in `config` replace its default ones.
+    pub fn new(config: &Option<HashMap<String, ConfigKeys>>) -> error::Result<Self> {
+        if let Some(config) = config {
+            let mut names = config.keys().collect::<Vec<_>>();
+            names.sort();
+            if let Some(name) = names.into_iter().find(|name| {
+                !Action::ALL
+                    .iter()
+                    .any(|action| action.name() == name.as_str())
+            }) {
+                return Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid key binding action.",
+                    name
+                )));
+            }
+        }
+
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| {
+                let keys = match config.as_ref().and_then(|config| config.get(action.name())) {
+                    Some(keys) => keys.keys(),
+                    None => action.default_keys().to_vec(),
+                };
+                let sequences = keys
+                    .into_iter()
+                    .map(|key| {
+                        KeySequence::parse(key).ok_or_else(|| {
+                            BottomError::ConfigError(format!(
+                                "\"{}\" is an invalid key for \"{}\".",
+                                key,
+                                action.name()
+                            ))
+                        })
+                    })
+                    .collect::<error::Result<Vec<_>>>()?;
+
+                Ok((*action, sequences))
+            })
+            .collect::<error::Result<Vec<_>>>()?;
+
+        let mut lookup: HashMap<KeySequence, Action> = HashMap::new();
+        let mut prefixes: HashMap<KeyChord, (KeySequence, Action)> = HashMap::new();
+        for (action, sequences) in &bindings {
+            for sequence in sequences {
+                if let Some(chord) = sequence
+                    .0
+                    .iter()
+                    .find(|chord| RESERVED_KEYS.contains(&(chord.code, chord.modifiers)))
+                {
+                    return Err(BottomError::ConfigError(format!(
+                        "\"{}\" is reserved and can't be bound to \"{}\".",
+                        chord,
+                        action.name()
+                    )));
+                }
+
+                if let Some(other) = lookup.insert(sequence.clone(), *action) {
+                    return Err(BottomError::ConfigError(format!(
+                        "\"{}\" is bound to both \"{}\" and \"{}\".",
+                        sequence,
+                        other.name(),
+                        action.name()
+                    )));
+                }
+
+                if let [first, _] = sequence.0[..] {
+                    prefixes.insert(first, (sequence.clone(), *action));
+                }
+            }
+        }
+
+        // A key can't be bound on its own if it also starts a sequence, since it would never get the chance
+        // to finish it.
+        for (action, sequences) in &bindings {
+            for sequence in sequences {
+                if let [chord] = sequence.0[..] {
+                    if let Some((other_sequence, other)) = prefixes.get(&chord) {
+                        return Err(BottomError::ConfigError(format!(
+                            "\"{}\" for \"{}\" conflicts with \"{}\" for \"{}\".",
+                            sequence,
+                            action.name(),
+                            other_sequence,
+                            other.name()
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(KeyBindings {
+            bindings,
+            lookup,
+            prefixes: prefixes.into_keys().collect(),
+        })
+    }
+
+    /// Resolves a key press, given the key pressed before it if that one started a sequence.
+    pub fn get(&self, pending: Option<KeyChord>, chord: KeyChord) -> KeyLookup {
+        if let Some(pending) = pending {
+            if let Some(action) = self.lookup.get(&KeySequence(vec![pending, chord])) {
+                return KeyLookup::Action(*action);
+            }
+        }
+
+        if self.prefixes.contains(&chord) {
+            KeyLookup::Pending
+        } else if let Some(action) = self.lookup.get(&KeySequence(vec![chord])) {
+            KeyLookup::Action(*action)
+        } else {
+            KeyLookup::Unbound
+        }
+    }
+
+    /// The keys bound to an action, as shown to the user.
+    fn keys(&self, action: Action) -> impl Iterator<Item = String> + '_ {
+        self.bindings
+            .iter()
+            .filter(move |(bound_action, _)| *bound_action == action)
+            .flat_map(|(_, sequences)| sequences.iter().map(|sequence| sequence.to_string()))
+    }
+
+    /// Generates the lines of each section of the help menu from `sections`, listing the keys that are
+    /// currently bound.
+    pub fn help_text(&self, sections: &[&[HelpEntry]]) -> Vec<Vec<String>> {
+        sections
+            .iter()
+            .map(|section| {
+                section
+                    .iter()
+                    .flat_map(|entry| match entry {
+                        HelpEntry::Text(text) => vec![text.to_string()],
+                        HelpEntry::Keys(actions, extra_keys, description) => {
+                            let keys = actions
+                                .iter()
+                                .flat_map(|action| self.keys(*action))
+                                .chain(extra_keys.iter().map(|key| key.to_string()))
+                                .collect::<Vec<_>>();
+                            help_lines(&keys, description)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Lays out a help menu entry. If the keys don't fit in the key column, they're spread over several lines,
+/// with the description on the middle one. A key that takes up more than half the column ends its line. An
+/// entry with no keys is left out.
+fn help_lines(keys: &[String], description: &str) -> Vec<String> {
+    let max_width = HELP_KEY_WIDTH - 1;
+    let mut key_lines: Vec<String> = Vec::new();
+    let mut is_line_full = true;
+    for (index, key) in keys.iter().enumerate() {
+        let key = if index + 1 < keys.len() {
+            format!("{},", key)
+        } else {
+            key.clone()
+        };
+
+        match key_lines.last_mut() {
+            Some(line) if !is_line_full && line.len() + 1 + key.len() <= max_width => {
+                line.push(' ');
+                line.push_str(&key);
+            }
+            _ => key_lines.push(key.clone()),
+        }
+        is_line_full = key.len() > max_width / 2;
+    }
+
+    let description_index = key_lines.len().saturating_sub(1) / 2;
+    key_lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == description_index {
+                format!("{:<width$}{}", line, description, width = HELP_KEY_WIDTH)
+            } else {
+                format!("{:<width$}", line, width = HELP_KEY_WIDTH)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(entries: &[(&str, &[&str])]) -> Option<HashMap<String, ConfigKeys>> {
+        Some(
+            entries
+                .iter()
+                .map(|(action, keys)| {
+                    (
+                        action.to_string(),
+                        ConfigKeys::List(keys.iter().map(|key| key.to_string()).collect()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn chord(key: &str) -> KeyChord {
+        KeyChord::parse(key).unwrap()
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            chord("q"),
+            KeyChord::new(KeyCode::Char('q'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            chord("ctrl-R"),
+            KeyChord::new(KeyCode::Char('R'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("Shift-Left"),
+            KeyChord::new(KeyCode::Left, KeyModifiers::SHIFT)
+        );
+        assert_eq!(chord("shift-g"), chord("G"));
+        assert_eq!(
+            chord("Ctrl--"),
+            KeyChord::new(KeyCode::Char('-'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("f12"),
+            KeyChord::new(KeyCode::F(12), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            chord("space"),
+            KeyChord::new(KeyCode::Char(' '), KeyModifiers::NONE)
+        );
+
+        assert!(KeyChord::parse("F13").is_none());
+        assert!(KeyChord::parse("hyper-x").is_none());
+        assert!(KeySequence::parse("g g g").is_none());
+        assert_eq!(KeySequence::parse("g g").unwrap().to_string(), "gg");
+        assert_eq!(
+            KeySequence::parse("ctrl-x k").unwrap().to_string(),
+            "Ctrl-x k"
+        );
+    }
+
+    #[test]
+    fn test_default_bindings() {
+        let key_bindings = KeyBindings::new(&None).unwrap();
+        let unbound = KeyChord::new(KeyCode::Char('x'), KeyModifiers::NONE);
+
+        assert_eq!(
+            key_bindings.get(None, chord("Ctrl-r")),
+            KeyLookup::Action(Action::Reset)
+        );
+        assert_eq!(
+            key_bindings.get(None, KeyChord::new(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            KeyLookup::Action(Action::SkipToLast)
+        );
+        assert_eq!(key_bindings.get(None, chord("d")), KeyLookup::Pending);
+        assert_eq!(
+            key_bindings.get(Some(chord("d")), chord("d")),
+            KeyLookup::Action(Action::KillProcess)
+        );
+        assert_eq!(
+            key_bindings.get(Some(chord("d")), chord("j")),
+            KeyLookup::Action(Action::Down)
+        );
+        assert_eq!(key_bindings.get(None, unbound), KeyLookup::Unbound);
+    }
+
+    #[test]
+    fn test_config_bindings() {
+        let key_bindings =
+            KeyBindings::new(&config(&[("freeze", &["F2", "x"]), ("quit", &[])])).unwrap();
+
+        assert_eq!(
+            key_bindings.get(None, chord("x")),
+            KeyLookup::Action(Action::Freeze)
+        );
+        assert_eq!(key_bindings.get(None, chord("f")), KeyLookup::Unbound);
+        assert_eq!(key_bindings.get(None, chord("q")), KeyLookup::Unbound);
+    }
+
+    #[test]
+    fn test_invalid_bindings() {
+        let error = |config| KeyBindings::new(&config).unwrap_err().to_string();
+
+        assert!(error(config(&[("fly", &["x"])])).contains("invalid key binding action"));
+        assert!(error(config(&[("freeze", &["Ctrl-Hyper"])])).contains("invalid key"));
+        assert!(error(config(&[("freeze", &["q"])])).contains("bound to both"));
+        assert!(error(config(&[("freeze", &["Ctrl-c"])])).contains("reserved"));
+        assert!(error(config(&[("freeze", &["Esc"])])).contains("reserved"));
+        assert!(error(config(&[("freeze", &["g"])])).contains("conflicts with"));
+    }
+
+    #[test]
+    fn test_help_text() {
+        let key_bindings = KeyBindings::new(&None).unwrap();
+        let section: &[HelpEntry] = &[
+            HelpEntry::Text("1 - General"),
+            HelpEntry::Keys(&[Action::Quit], &["Ctrl-c"], "Quit"),
+            HelpEntry::Keys(&[Action::MoveWidgetLeft], &[], "Move widget selection left"),
+            HelpEntry::Keys(&[Action::PageUp, Action::PageDown], &[], "Scroll by a page"),
+        ];
+
+        assert_eq!(
+            key_bindings.help_text(&[section]),
+            vec![vec![
+                "1 - General",
+                "q, Ctrl-c        Quit",
+                "Ctrl-Left,       ",
+                "Shift-Left,      Move widget selection left",
+                "H, A             ",
+                "PgUp, PgDown     Scroll by a page",
+            ]]
+        );
+
+        let key_bindings =
+            KeyBindings::new(&config(&[("page_up", &[]), ("page_down", &[])])).unwrap();
+        assert_eq!(
+            key_bindings.help_text(&[&section[3..]]),
+            vec![Vec::<String>::new()]
+        );
+    }
+}
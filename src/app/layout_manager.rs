@@ -892,7 +892,16 @@ pub enum BottomWidgetType {
     ProcSearch,
     ProcSort,
     Temp,
+    TempGraph,
+    TempGraphLegend,
     Disk,
+    DiskIo,
+    DiskIoLegend,
+    NetInterfaces,
+    Cgroups,
+    Sensors,
+    Pressure,
+    CpuFreq,
     BasicCpu,
     BasicMem,
     BasicNet,
@@ -903,12 +912,26 @@ pub enum BottomWidgetType {
 impl BottomWidgetType {
     pub fn is_widget_table(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Disk | Proc | ProcSort | Temp | CpuLegend)
+        matches!(
+            self,
+            Disk | DiskIoLegend
+                | NetInterfaces
+                | Cgroups
+                | Sensors
+                | Proc
+                | ProcSort
+                | Temp
+                | TempGraphLegend
+                | CpuLegend
+        )
     }
 
     pub fn is_widget_graph(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Cpu | Net | Mem)
+        matches!(
+            self,
+            Cpu | DiskIo | Net | Mem | TempGraph | Pressure | CpuFreq
+        )
     }
 
     pub fn get_pretty_name(&self) -> &str {
@@ -919,7 +942,14 @@ impl BottomWidgetType {
             Net => "Network",
             Proc => "Processes",
             Temp => "Temperature",
+            TempGraph => "Temperature Graph",
             Disk => "Disks",
+            DiskIo => "Disk I/O",
+            NetInterfaces => "Interfaces",
+            Cgroups => "Cgroups",
+            Sensors => "Sensors",
+            Pressure => "Pressure",
+            CpuFreq => "CPU Frequency",
             Battery => "Battery",
             _ => "",
         }
@@ -943,7 +973,14 @@ impl std::str::FromStr for BottomWidgetType {
             "net" | "network" => Ok(BottomWidgetType::Net),
             "proc" | "process" | "processes" => Ok(BottomWidgetType::Proc),
             "temp" | "temperature" => Ok(BottomWidgetType::Temp),
+            "temp_graph" => Ok(BottomWidgetType::TempGraph),
             "disk" => Ok(BottomWidgetType::Disk),
+            "disk_io" | "io" => Ok(BottomWidgetType::DiskIo),
+            "interface" | "interfaces" => Ok(BottomWidgetType::NetInterfaces),
+            "cgroup" | "cgroups" => Ok(BottomWidgetType::Cgroups),
+            "sensor" | "sensors" => Ok(BottomWidgetType::Sensors),
+            "pressure" | "psi" => Ok(BottomWidgetType::Pressure),
+            "cpu_freq" | "freq" => Ok(BottomWidgetType::CpuFreq),
             "empty" => Ok(BottomWidgetType::Empty),
             "battery" | "batt" if cfg!(feature = "battery") => Ok(BottomWidgetType::Battery),
             _ => {
@@ -963,8 +1000,22 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|        temp_graph        |
++--------------------------+
 |           disk           |
 +--------------------------+
+|        disk_io, io       |
++--------------------------+
+|  interface, interfaces   |
++--------------------------+
+|      cgroup, cgroups     |
++--------------------------+
+|      sensor, sensors     |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|      cpu_freq, freq      |
++--------------------------+
 |       batt, battery      |
 +--------------------------+
                 ",
@@ -986,7 +1037,21 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|        temp_graph        |
++--------------------------+
 |           disk           |
++--------------------------+
+|        disk_io, io       |
++--------------------------+
+|  interface, interfaces   |
++--------------------------+
+|      cgroup, cgroups     |
++--------------------------+
+|      sensor, sensors     |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|      cpu_freq, freq      |
 +--------------------------+
                 ",
                         s
@@ -1006,4 +1071,27 @@ pub struct UsedWidgets {
     pub use_disk: bool,
     pub use_temp: bool,
     pub use_battery: bool,
+    pub use_cgroup: bool,
+    pub use_sensors: bool,
+    pub use_pressure: bool,
+    pub use_cpu_freq: bool,
+}
+
+impl UsedWidgets {
+    /// Marks every widget as used, for when data is harvested without drawing anything.
+    pub fn all() -> Self {
+        UsedWidgets {
+            use_cpu: true,
+            use_mem: true,
+            use_net: true,
+            use_proc: true,
+            use_disk: true,
+            use_temp: true,
+            use_battery: cfg!(feature = "battery"),
+            use_cgroup: true,
+            use_sensors: true,
+            use_pressure: true,
+            use_cpu_freq: true,
+        }
+    }
 }
@@ -0,0 +1,113 @@
+//! This file is meant to house (OS specific) implementations on how to change which cores processes may run on.
+
+use crate::{
+    utils::error::{BottomError, Result},
+    Pid,
+};
+
+/// Turns the error left by a failed call into a message for the user.
+#[cfg(target_os = "linux")]
+fn last_affinity_error() -> BottomError {
+    let err_code = std::io::Error::last_os_error().raw_os_error();
+    let err = match err_code {
+        Some(libc::ESRCH) => "the target process did not exist.",
+        Some(libc::EPERM) => "the calling process does not have the permissions to change the affinity of the target process(es).",
+        Some(libc::EINVAL) => "none of the selected cores can be used by the target process(es).",
+        _ => "Unknown error occurred."
+    };
+
+    if let Some(err_code) = err_code {
+        BottomError::GenericError(format!("Error code {} - {}", err_code, err))
+    } else {
+        BottomError::GenericError(format!("Error code ??? - {}", err))
+    }
+}
+
+/// Gets the cores a process is allowed to run on, given a PID, for Linux.
+#[cfg(target_os = "linux")]
+pub fn get_affinity(pid: Pid) -> Result<Vec<usize>> {
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    let output =
+        unsafe { libc::sched_getaffinity(pid, std::mem::size_of::<libc::cpu_set_t>(), &mut set) };
+    if output != 0 {
+        return Err(last_affinity_error());
+    }
+
+    Ok((0..libc::CPU_SETSIZE as usize)
+        .filter(|core| unsafe { libc::CPU_ISSET(*core, &set) })
+        .collect())
+}
+
+/// Gets the cores a process is allowed to run on, given a PID. Only Linux is supported.
+#[cfg(not(target_os = "linux"))]
+pub fn get_affinity(_pid: Pid) -> Result<Vec<usize>> {
+    Err(BottomError::GenericError(
+        "CPU affinities can only be changed on Linux.".to_string(),
+    ))
+}
+
+/// Restricts a process to the given cores, given a PID, for Linux. If `include_threads` is set, every thread
+/// of the process is changed too, rather than just the main one.
+#[cfg(target_os = "linux")]
+pub fn set_affinity(pid: Pid, cores: &[usize], include_threads: bool) -> Result<()> {
+    if cores.is_empty() {
+        return Err(BottomError::GenericError(
+            "At least one core must be selected.".to_string(),
+        ));
+    }
+
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for core in cores {
+        unsafe { libc::CPU_SET(*core, &mut set) };
+    }
+
+    let tids = if include_threads {
+        procfs::process::Process::new(pid)?
+            .tasks()?
+            .filter_map(|task| task.ok().map(|task| task.tid))
+            .collect()
+    } else {
+        vec![pid]
+    };
+
+    for tid in tids {
+        let output =
+            unsafe { libc::sched_setaffinity(tid, std::mem::size_of::<libc::cpu_set_t>(), &set) };
+        if output != 0 {
+            // Threads may exit after being listed, which is fine.
+            let is_gone = std::io::Error::last_os_error().raw_os_error() == Some(libc::ESRCH);
+            if tid == pid || !is_gone {
+                return Err(last_affinity_error());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Restricts a process to the given cores, given a PID. Only Linux is supported.
+#[cfg(not(target_os = "linux"))]
+pub fn set_affinity(_pid: Pid, _cores: &[usize], _include_threads: bool) -> Result<()> {
+    Err(BottomError::GenericError(
+        "CPU affinities can only be changed on Linux.".to_string(),
+    ))
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_affinity() {
+        let pid = std::process::id() as Pid;
+
+        // Keeping the same affinity is always allowed.
+        let cores = get_affinity(pid).unwrap();
+        assert!(!cores.is_empty());
+        assert!(set_affinity(pid, &cores, false).is_ok());
+
+        assert!(set_affinity(pid, &[], false).is_err());
+        let err = get_affinity(Pid::MAX).unwrap_err().to_string();
+        assert!(err.contains("did not exist"));
+    }
+}
@@ -66,6 +66,35 @@ pub fn kill_process_given_pid(pid: Pid, signal: usize) -> crate::utils::error::R
     Ok(())
 }
 
+/// Pauses (`SIGSTOP`) or resumes (`SIGCONT`) a process, given a PID, for unix.
+#[cfg(target_family = "unix")]
+pub fn set_process_stopped(pid: Pid, stop: bool) -> crate::utils::error::Result<()> {
+    let signal = if stop { libc::SIGSTOP } else { libc::SIGCONT };
+    kill_process_given_pid(pid, signal as usize)
+}
+
+/// Whether a process has exited, given a PID, for unix.
+#[cfg(target_family = "unix")]
+pub fn has_exited(pid: Pid) -> bool {
+    let output = unsafe { libc::kill(pid, 0) };
+    output != 0 && std::io::Error::last_os_error().raw_os_error() == Some(libc::ESRCH)
+}
+
+/// Whether a process has exited, given a PID, for windows. This can't be told apart from not being allowed to
+/// open the process, so it is never assumed.
+#[cfg(target_os = "windows")]
+pub fn has_exited(_pid: Pid) -> bool {
+    false
+}
+
+/// Pauses or resumes a process, given a PID, for windows, which is not supported.
+#[cfg(target_os = "windows")]
+pub fn set_process_stopped(_pid: Pid, _stop: bool) -> crate::utils::error::Result<()> {
+    Err(crate::utils::error::BottomError::GenericError(
+        "Stopping and continuing processes is not supported on Windows.".to_string(),
+    ))
+}
+
 /// Kills a process, given a PID, for windows.
 #[cfg(target_os = "windows")]
 pub fn kill_process_given_pid(pid: Pid) -> crate::utils::error::Result<()> {
@@ -76,3 +105,59 @@ pub fn kill_process_given_pid(pid: Pid) -> crate::utils::error::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+#[cfg(target_os = "linux")]
+mod test {
+    use super::*;
+
+    use std::{
+        process::Command,
+        thread::sleep,
+        time::{Duration, Instant},
+    };
+
+    /// Waits for the state of a process to become `state`, returning whether it did.
+    fn wait_for_state(pid: Pid, state: char) -> bool {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(5) {
+            if matches!(procfs::process::Process::new(pid).and_then(|p| p.stat()), Ok(stat) if stat.state == state)
+            {
+                return true;
+            }
+            sleep(Duration::from_millis(10));
+        }
+
+        false
+    }
+
+    #[test]
+    fn stop_and_continue() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id() as Pid;
+
+        set_process_stopped(pid, true).unwrap();
+        assert!(wait_for_state(pid, 'T'));
+
+        set_process_stopped(pid, false).unwrap();
+        assert!(wait_for_state(pid, 'S'));
+
+        kill_process_given_pid(pid, libc::SIGKILL as usize).unwrap();
+        child.wait().unwrap();
+    }
+
+    #[test]
+    fn exited_process() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id() as Pid;
+        assert!(!has_exited(pid));
+
+        kill_process_given_pid(pid, libc::SIGKILL as usize).unwrap();
+        child.wait().unwrap();
+
+        // Once gone, signalling it fails, but only because it exited.
+        assert!(kill_process_given_pid(pid, libc::SIGTERM as usize).is_err());
+        assert!(has_exited(pid));
+        assert!(set_process_stopped(pid, false).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! This file is meant to house (OS specific) implementations on how to change the priority of processes.
+
+use crate::{
+    app::IoPriorityClass,
+    utils::error::{BottomError, Result},
+    Pid,
+};
+
+/// Turns the error left by a failed call into a message for the user.
+#[cfg(target_family = "unix")]
+fn last_priority_error() -> BottomError {
+    let err_code = std::io::Error::last_os_error().raw_os_error();
+    let err = match err_code {
+        Some(libc::ESRCH) => "the target process did not exist.",
+        Some(libc::EPERM) => "the calling process does not have the permissions to change the priority of the target process(es).",
+        Some(libc::EACCES) => "the calling process does not have the permissions to raise the priority of the target process(es).",
+        Some(libc::EINVAL) => "an invalid priority was specified.",
+        _ => "Unknown error occurred."
+    };
+
+    if let Some(err_code) = err_code {
+        BottomError::GenericError(format!("Error code {} - {}", err_code, err))
+    } else {
+        BottomError::GenericError(format!("Error code ??? - {}", err))
+    }
+}
+
+/// Gets the nice value of a process, for unix.
+#[cfg(target_family = "unix")]
+pub fn get_nice(pid: Pid) -> i32 {
+    // -1 is both a valid nice value and the error value; if the process is gone, setting it will fail anyways.
+    unsafe { libc::getpriority(libc::PRIO_PROCESS, pid as libc::id_t) }
+}
+
+/// Gets the nice value of a process, for windows, which has no such thing.
+#[cfg(target_os = "windows")]
+pub fn get_nice(_pid: Pid) -> i32 {
+    0
+}
+
+/// Sets the nice value of a process, given a PID, for unix.
+#[cfg(target_family = "unix")]
+pub fn set_nice(pid: Pid, nice: i32) -> Result<()> {
+    let output = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, nice) };
+    if output != 0 {
+        return Err(last_priority_error());
+    }
+
+    Ok(())
+}
+
+/// Sets the nice value of a process, given a PID, for windows.
+#[cfg(target_os = "windows")]
+pub fn set_nice(_pid: Pid, _nice: i32) -> Result<()> {
+    Err(BottomError::GenericError(
+        "Changing the priority of processes is not supported on Windows.".to_string(),
+    ))
+}
+
+/// Sets the I/O scheduling class and level of a process, given a PID, for Linux.
+#[cfg(target_os = "linux")]
+pub fn set_io_priority(pid: Pid, class: IoPriorityClass, level: u8) -> Result<()> {
+    const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+    const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+
+    let (class, level) = match class {
+        IoPriorityClass::Unchanged => return Ok(()),
+        IoPriorityClass::RealTime => (1, level),
+        IoPriorityClass::BestEffort => (2, level),
+        IoPriorityClass::Idle => (3, 0),
+    };
+    let ioprio = (class << IOPRIO_CLASS_SHIFT) | libc::c_int::from(level);
+
+    let output = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, pid, ioprio) };
+    if output != 0 {
+        return Err(last_priority_error());
+    }
+
+    Ok(())
+}
+
+/// Sets the I/O scheduling class and level of a process, given a PID. I/O priorities only exist on Linux, so
+/// anything other than leaving them unchanged fails.
+#[cfg(not(target_os = "linux"))]
+pub fn set_io_priority(_pid: Pid, class: IoPriorityClass, _level: u8) -> Result<()> {
+    match class {
+        IoPriorityClass::Unchanged => Ok(()),
+        _ => Err(BottomError::GenericError(
+            "I/O priorities are only supported on Linux.".to_string(),
+        )),
+    }
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_nice() {
+        let pid = std::process::id() as Pid;
+
+        // Keeping the same nice value is always allowed.
+        let nice = get_nice(pid);
+        assert!(set_nice(pid, nice).is_ok());
+        assert!(set_io_priority(pid, IoPriorityClass::Unchanged, 0).is_ok());
+
+        let err = set_nice(Pid::MAX, nice).unwrap_err().to_string();
+        assert!(err.contains("did not exist"));
+    }
+}
@@ -27,6 +27,14 @@ const AND_LIST: [&str; 2] = ["and", "&&"];
 /// - Write/s: Use prefix `w`.  Can compare.
 /// - Total read: Use prefix `read`.  Can compare.
 /// - Total write: Use prefix `write`.  Can compare.
+/// - Virtual memory: Use prefix `virt`.  Can compare.
+/// - Resident memory: Use prefix `rss`.  Can compare.
+/// - Shared memory: Use prefix `shared`.  Can compare.
+/// - Swapped memory: Use prefix `swap`.  Can compare.
+/// - Proportional memory: Use prefix `pss`.  Can compare.
+/// - Cgroup path: Use prefix `cgroup`, can use regex, match word, or case.
+/// - Container ID: Use prefix `container`, can use regex, match word, or case.
+/// - Systemd unit: Use prefix `unit`, can use regex, match word, or case.
 ///
 /// For queries, whitespaces are our delimiters.  We will merge together any adjacent non-prefixed
 /// or quoted elements after splitting to treat as process names.
@@ -248,7 +256,12 @@ pub fn parse_query(
                                 compare_prefix: None,
                             })
                         }
-                        PrefixType::Pid | PrefixType::State | PrefixType::User => {
+                        PrefixType::Pid
+                        | PrefixType::State
+                        | PrefixType::User
+                        | PrefixType::Cgroup
+                        | PrefixType::Container
+                        | PrefixType::Unit => {
                             // We have to check if someone put an "="...
                             if content == "=" {
                                 // Check next string if possible
@@ -334,7 +347,12 @@ pub fn parse_query(
                                         | PrefixType::Rps
                                         | PrefixType::Wps
                                         | PrefixType::TRead
-                                        | PrefixType::TWrite => {
+                                        | PrefixType::TWrite
+                                        | PrefixType::Virt
+                                        | PrefixType::Rss
+                                        | PrefixType::Shared
+                                        | PrefixType::Swap
+                                        | PrefixType::Pss => {
                                             if let Some(potential_unit) = query.front() {
                                                 match potential_unit.to_lowercase().as_str() {
                                                     "tb" => {
@@ -555,7 +573,7 @@ impl Debug for And {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum PrefixType {
     Pid,
     PCpu,
@@ -565,9 +583,17 @@ pub enum PrefixType {
     Wps,
     TRead,
     TWrite,
+    Virt,
+    Rss,
+    Shared,
+    Swap,
+    Pss,
     Name,
     State,
     User,
+    Cgroup,
+    Container,
+    Unit,
     __Nonexhaustive,
 }
 
@@ -588,9 +614,17 @@ impl std::str::FromStr for PrefixType {
             "write" | "w/s" => Ok(Wps),
             "tread" | "t.read" => Ok(TRead),
             "twrite" | "t.write" => Ok(TWrite),
+            "virt" => Ok(Virt),
+            "rss" => Ok(Rss),
+            "shared" => Ok(Shared),
+            "swap" => Ok(Swap),
+            "pss" => Ok(Pss),
             "pid" => Ok(Pid),
             "state" => Ok(State),
             "user" => Ok(User),
+            "cgroup" => Ok(Cgroup),
+            "container" => Ok(Container),
+            "unit" => Ok(Unit),
             _ => Ok(Name),
         }
     }
@@ -617,7 +651,13 @@ impl Prefix {
         } else if let Some((prefix_type, StringQuery::Value(regex_string))) = &mut self.regex_prefix
         {
             match prefix_type {
-                PrefixType::Pid | PrefixType::Name | PrefixType::State | PrefixType::User => {
+                PrefixType::Pid
+                | PrefixType::Name
+                | PrefixType::State
+                | PrefixType::User
+                | PrefixType::Cgroup
+                | PrefixType::Container
+                | PrefixType::Unit => {
                     let escaped_regex: String;
                     let final_regex_string = &format!(
                         "{}{}{}{}",
@@ -680,6 +720,19 @@ impl Prefix {
                             false
                         }
                     }
+                    PrefixType::Cgroup => r.is_match(&process.cgroup.path),
+                    PrefixType::Container => process
+                        .cgroup
+                        .container_id
+                        .as_ref()
+                        .map(|container_id| r.is_match(container_id))
+                        .unwrap_or(false),
+                    PrefixType::Unit => process
+                        .cgroup
+                        .unit
+                        .as_ref()
+                        .map(|unit| r.is_match(unit))
+                        .unwrap_or(false),
                     _ => true,
                 }
             } else {
@@ -722,6 +775,31 @@ impl Prefix {
                     process.total_write_bytes as f64,
                     numerical_query.value,
                 ),
+                PrefixType::Virt => matches_condition(
+                    &numerical_query.condition,
+                    process.virt_bytes as f64,
+                    numerical_query.value,
+                ),
+                PrefixType::Rss => matches_condition(
+                    &numerical_query.condition,
+                    process.mem_usage_bytes as f64,
+                    numerical_query.value,
+                ),
+                PrefixType::Shared => matches_condition(
+                    &numerical_query.condition,
+                    process.shared_bytes as f64,
+                    numerical_query.value,
+                ),
+                PrefixType::Swap => matches_condition(
+                    &numerical_query.condition,
+                    process.swap_bytes as f64,
+                    numerical_query.value,
+                ),
+                PrefixType::Pss => matches_condition(
+                    &numerical_query.condition,
+                    process.pss_bytes as f64,
+                    numerical_query.value,
+                ),
                 _ => true,
             }
         } else {
@@ -765,3 +843,158 @@ pub struct NumericalQuery {
     pub condition: QueryComparison,
     pub value: f64,
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::app::data_harvester::processes::ProcessCgroup;
+
+    /// Parses the query with the default search settings.
+    fn query(search_query: &str) -> Query {
+        parse_query(search_query, false, true, false).unwrap()
+    }
+
+    /// The single prefix the query was parsed into.
+    fn only_prefix(query: &Query) -> &Prefix {
+        assert_eq!(query.query.len(), 1);
+        let or = &query.query[0];
+        assert!(or.rhs.is_none());
+        assert!(or.lhs.rhs.is_none());
+        &or.lhs.lhs
+    }
+
+    #[test]
+    fn test_parse_memory_prefixes() {
+        let prefixes = [
+            ("virt", PrefixType::Virt),
+            ("rss", PrefixType::Rss),
+            ("shared", PrefixType::Shared),
+            ("swap", PrefixType::Swap),
+            ("pss", PrefixType::Pss),
+        ];
+        let units = [
+            ("", 1.0),
+            ("B", 1.0),
+            ("KB", 1000.0),
+            ("KiB", 1024.0),
+            ("MB", 1_000_000.0),
+            ("MiB", 1_048_576.0),
+            ("GB", 1_000_000_000.0),
+            ("GiB", 1_073_741_824.0),
+            ("TB", 1_000_000_000_000.0),
+            ("TiB", 1_099_511_627_776.0),
+        ];
+
+        for (prefix, expected_type) in &prefixes {
+            for (unit, multiplier) in &units {
+                let query = query(&format!("{} > 2 {}", prefix, unit));
+                let (prefix_type, numerical_query) =
+                    only_prefix(&query).compare_prefix.as_ref().unwrap();
+
+                assert_eq!(prefix_type, expected_type);
+                assert!(matches!(
+                    numerical_query.condition,
+                    QueryComparison::Greater
+                ));
+                assert!((numerical_query.value - 2.0 * multiplier).abs() < f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_memory_prefixes() {
+        let process = ProcessHarvest {
+            virt_bytes: 4 * 1024 * 1024,
+            mem_usage_bytes: 2 * 1024 * 1024,
+            shared_bytes: 512 * 1024,
+            swap_bytes: 0,
+            pss_bytes: 1024 * 1024,
+            ..Default::default()
+        };
+
+        for (search_query, expected) in &[
+            ("virt > 3 MiB", true),
+            ("virt > 5 MiB", false),
+            ("virt = 4194304", true),
+            ("rss >= 2 MiB", true),
+            ("rss > 2 MiB", false),
+            ("shared < 1 MB", true),
+            ("shared < 500 KB", false),
+            ("swap = 0", true),
+            ("swap > 0 B", false),
+            ("pss <= 1 MiB", true),
+            ("pss < 1 MiB", false),
+            ("rss > 1 MiB and swap = 0", true),
+            ("rss > 1 GiB or pss > 1 KiB", true),
+        ] {
+            assert_eq!(
+                query(search_query).check(&process, false),
+                *expected,
+                "{}",
+                search_query
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_cgroup_prefixes() {
+        for (prefix, expected_type) in &[
+            ("cgroup", PrefixType::Cgroup),
+            ("container", PrefixType::Container),
+            ("unit", PrefixType::Unit),
+        ] {
+            let query = query(&format!("{} sshd.service", prefix));
+            let (prefix_type, string_query) = only_prefix(&query).regex_prefix.as_ref().unwrap();
+
+            assert_eq!(prefix_type, expected_type);
+            match string_query {
+                StringQuery::Regex(r) => assert_eq!(r.as_str(), r"(?i)sshd\.service"),
+                StringQuery::Value(_) => panic!("the {} query was not turned into a regex", prefix),
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_cgroup_prefixes() {
+        let service = ProcessHarvest {
+            cgroup: Arc::new(ProcessCgroup {
+                path: "/system.slice/sshd.service".to_string(),
+                container_id: None,
+                unit: Some("sshd.service".to_string()),
+            }),
+            ..Default::default()
+        };
+        let container = ProcessHarvest {
+            cgroup: Arc::new(ProcessCgroup {
+                path: "/system.slice/docker-4f2a9c.scope".to_string(),
+                container_id: Some("4f2a9c".to_string()),
+                unit: Some("docker-4f2a9c.scope".to_string()),
+            }),
+            ..Default::default()
+        };
+        let no_cgroup = ProcessHarvest::default();
+
+        for (search_query, expected) in &[
+            ("cgroup system.slice", [true, true, false]),
+            ("cgroup sshd", [true, false, false]),
+            ("container 4f2a9c", [false, true, false]),
+            ("container sshd", [false, false, false]),
+            ("unit sshd.service", [true, false, false]),
+            ("unit scope", [false, true, false]),
+            ("unit slice", [false, false, false]),
+        ] {
+            let query = query(search_query);
+            for (process, expected) in [&service, &container, &no_cgroup].iter().zip(expected) {
+                assert_eq!(query.check(process, false), *expected, "{}", search_query);
+            }
+        }
+
+        // A process with no cgroup has an empty path, but no container or unit to match at all.
+        let query = parse_query("cgroup .*", false, true, true).unwrap();
+        assert!(query.check(&no_cgroup, false));
+        let query = parse_query("container .*", false, true, true).unwrap();
+        assert!(!query.check(&no_cgroup, false));
+    }
+}
@@ -1,14 +1,20 @@
-use std::{collections::HashMap, time::Instant};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
 
 use unicode_segmentation::GraphemeCursor;
 
 use crate::{
     app::{layout_manager::BottomWidgetType, query::*},
     components::text_table::{CellContent, TableComponentColumn, TableComponentState, WidthBounds},
-    constants,
+    constants, Pid,
 };
 
-use super::widgets::{DiskWidgetState, ProcWidget, TempWidgetState};
+use super::widgets::{
+    CgroupWidgetState, DiskWidgetState, NetInterfaceWidgetState, ProcWidget, SensorWidgetState,
+    TempWidgetState,
+};
 
 #[derive(Debug)]
 pub enum ScrollDirection {
@@ -54,9 +60,48 @@ impl Default for KillSignal {
     }
 }
 
+/// What the kill dialog does to the selected processes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProcessAction {
+    /// Sends a signal to the selected process(es).
+    #[default]
+    Kill,
+    /// Sends a signal to the selected process(es) and all of their descendants, children first.
+    KillTree,
+    /// Pauses the selected process(es).
+    Stop,
+    /// Resumes the selected process(es) after being paused.
+    Continue,
+}
+
+impl ProcessAction {
+    pub fn verb(&self) -> &'static str {
+        match self {
+            ProcessAction::Kill | ProcessAction::KillTree => "kill",
+            ProcessAction::Stop => "stop",
+            ProcessAction::Continue => "continue",
+        }
+    }
+}
+
+/// How the processes a dialog acts on were picked from the process table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProcessSelection {
+    /// The process in the selected row.
+    #[default]
+    Single,
+    /// Every process with the name in the selected row, while grouping.
+    Grouped,
+    /// Every process marked in the table, regardless of the selected row.
+    Marked,
+}
+
 #[derive(Default)]
 pub struct AppDeleteDialogState {
     pub is_showing_dd: bool,
+    /// What confirming the dialog does, or what failed if it is showing an error.
+    pub action: ProcessAction,
+    pub selection: ProcessSelection,
     pub selected_signal: KillSignal,
     /// tl x, tl y, br x, br y, index/signal
     pub button_positions: Vec<(u16, u16, u16, u16, usize)>,
@@ -65,6 +110,259 @@ pub struct AppDeleteDialogState {
     pub scroll_pos: usize,
 }
 
+/// The I/O scheduling class to give a process. Classes other than idle also take a level, where lower levels
+/// are served first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IoPriorityClass {
+    /// Leaves the I/O priority as it is.
+    #[default]
+    Unchanged,
+    RealTime,
+    BestEffort,
+    Idle,
+}
+
+impl IoPriorityClass {
+    const ALL: [IoPriorityClass; 4] = [
+        IoPriorityClass::Unchanged,
+        IoPriorityClass::RealTime,
+        IoPriorityClass::BestEffort,
+        IoPriorityClass::Idle,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IoPriorityClass::Unchanged => "Unchanged",
+            IoPriorityClass::RealTime => "Realtime",
+            IoPriorityClass::BestEffort => "Best effort",
+            IoPriorityClass::Idle => "Idle",
+        }
+    }
+
+    pub fn has_level(&self) -> bool {
+        matches!(
+            self,
+            IoPriorityClass::RealTime | IoPriorityClass::BestEffort
+        )
+    }
+
+    fn offset(&self, amount: isize) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|class| class == self)
+            .unwrap_or(0) as isize;
+        Self::ALL[(index + amount).clamp(0, Self::ALL.len() as isize - 1) as usize]
+    }
+}
+
+/// A setting in the priority dialog.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PriorityField {
+    #[default]
+    Nice,
+    IoClass,
+    IoLevel,
+    IncludeChildren,
+}
+
+#[derive(Default)]
+pub struct AppPriorityDialogState {
+    pub is_showing_priority: bool,
+    /// The name of the process or group to change, along with its PIDs.
+    pub process: Option<(String, Vec<Pid>)>,
+    pub selection: ProcessSelection,
+    pub selected_field: PriorityField,
+    pub nice: i32,
+    /// Whether the nice value was changed, as it starts as that of the first process and shouldn't be forced onto
+    /// the rest otherwise.
+    pub is_nice_changed: bool,
+    pub io_class: IoPriorityClass,
+    pub io_level: u8,
+    /// Whether to also change the priority of the process' descendants.
+    pub include_children: bool,
+}
+
+impl AppPriorityDialogState {
+    pub const MIN_NICE: i32 = -20;
+    pub const MAX_NICE: i32 = 19;
+    pub const MAX_IO_LEVEL: u8 = 7;
+
+    /// The settings shown in the dialog. I/O priorities are only supported on Linux, and a group has no single
+    /// process to take the descendants of.
+    pub fn fields(&self) -> Vec<PriorityField> {
+        let mut fields = vec![PriorityField::Nice];
+        if cfg!(target_os = "linux") {
+            fields.push(PriorityField::IoClass);
+            if self.io_class.has_level() {
+                fields.push(PriorityField::IoLevel);
+            }
+        }
+        if self.selection != ProcessSelection::Grouped {
+            fields.push(PriorityField::IncludeChildren);
+        }
+
+        fields
+    }
+
+    /// Selects the setting `amount` places away from the current one.
+    pub fn move_selection(&mut self, amount: isize) {
+        let fields = self.fields();
+        let index = fields
+            .iter()
+            .position(|field| *field == self.selected_field)
+            .unwrap_or(0) as isize;
+        self.selected_field = fields[index
+            .saturating_add(amount)
+            .clamp(0, fields.len() as isize - 1) as usize];
+    }
+
+    /// Changes the selected setting by `amount` steps.
+    pub fn change_selected(&mut self, amount: isize) {
+        match self.selected_field {
+            PriorityField::Nice => {
+                self.nice = (self.nice + amount as i32).clamp(Self::MIN_NICE, Self::MAX_NICE);
+                self.is_nice_changed = true;
+            }
+            PriorityField::IoClass => self.io_class = self.io_class.offset(amount),
+            PriorityField::IoLevel => {
+                self.io_level =
+                    (self.io_level as isize + amount).clamp(0, Self::MAX_IO_LEVEL as isize) as u8;
+            }
+            PriorityField::IncludeChildren => self.include_children = amount > 0,
+        }
+    }
+
+    /// The nice value to set, if it was changed.
+    pub fn changed_nice(&self) -> Option<i32> {
+        if self.is_nice_changed {
+            Some(self.nice)
+        } else {
+            None
+        }
+    }
+}
+
+/// A core in the CPU affinity dialog.
+#[derive(Clone, Debug)]
+pub struct AffinityCore {
+    /// The name of the core, as shown in the CPU legend.
+    pub name: String,
+    /// The kernel's ID for the core, which affinities are set with.
+    pub id: usize,
+    /// Whether the process is allowed to run on this core.
+    pub is_allowed: bool,
+}
+
+#[derive(Default)]
+pub struct AppAffinityDialogState {
+    pub is_showing_affinity: bool,
+    /// The name of the process or group to change, along with its PIDs.
+    pub process: Option<(String, Vec<Pid>)>,
+    pub selection: ProcessSelection,
+    pub cores: Vec<AffinityCore>,
+    pub selected_core: usize,
+    /// How many cores fit in a row of the grid, as of the last draw.
+    pub columns: usize,
+    /// How many rows of the grid fit in the dialog, as of the last draw.
+    pub visible_rows: usize,
+    /// The first row of the grid that is shown, as it is scrolled to keep the selected core in view.
+    pub scroll_row: usize,
+    /// Whether to also change the affinity of every thread of the process(es).
+    pub include_threads: bool,
+}
+
+impl AppAffinityDialogState {
+    /// Selects the core `amount` places away from the current one.
+    pub fn move_selection(&mut self, amount: isize) {
+        self.selected_core = (self.selected_core as isize)
+            .saturating_add(amount)
+            .clamp(0, self.cores.len().saturating_sub(1) as isize)
+            as usize;
+    }
+
+    /// Selects the core `amount` rows away from the current one in the grid.
+    pub fn move_row(&mut self, amount: isize) {
+        let target = self.selected_core as isize + amount * self.columns.max(1) as isize;
+        if target >= 0 && (target as usize) < self.cores.len() {
+            self.selected_core = target as usize;
+        }
+    }
+
+    /// How many rows the grid has.
+    pub fn rows(&self) -> usize {
+        self.cores.chunks(self.columns.max(1)).count()
+    }
+
+    /// Scrolls the grid as little as possible so the row of the selected core is among the `visible_rows` shown.
+    pub fn scroll_to_selected(&mut self, visible_rows: usize) {
+        let visible_rows = visible_rows.max(1);
+        let selected_row = self.selected_core / self.columns.max(1);
+        if selected_row < self.scroll_row {
+            self.scroll_row = selected_row;
+        } else if selected_row >= self.scroll_row + visible_rows {
+            self.scroll_row = selected_row + 1 - visible_rows;
+        }
+        self.scroll_row = self
+            .scroll_row
+            .min(self.rows().saturating_sub(visible_rows));
+    }
+
+    pub fn toggle_selected(&mut self) {
+        if let Some(core) = self.cores.get_mut(self.selected_core) {
+            core.is_allowed = !core.is_allowed;
+        }
+    }
+
+    /// Allows every core, or none if they are all already allowed.
+    pub fn toggle_all(&mut self) {
+        let is_allowed = !self.cores.iter().all(|core| core.is_allowed);
+        self.cores
+            .iter_mut()
+            .for_each(|core| core.is_allowed = is_allowed);
+    }
+
+    pub fn allowed_cores(&self) -> Vec<usize> {
+        self.cores
+            .iter()
+            .filter(|core| core.is_allowed)
+            .map(|core| core.id)
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct AppProcessDetailsState {
+    pub is_showing_details: bool,
+    /// The name and PID of the process being shown.
+    pub process: Option<(String, Pid)>,
+    pub scroll_state: ParagraphScrollState,
+}
+
+impl AppProcessDetailsState {
+    /// How many lines page up and page down scroll by.
+    pub const PAGE_LINES: i64 = 10;
+
+    /// The PID of the process being shown, if the dialog is open.
+    pub fn pid(&self) -> Option<Pid> {
+        if self.is_showing_details {
+            self.process.as_ref().map(|(_, pid)| *pid)
+        } else {
+            None
+        }
+    }
+
+    /// Scrolls the details by `amount` lines, staying within what can be scrolled to.
+    pub fn scroll(&mut self, amount: i64) {
+        let scroll_state = &mut self.scroll_state;
+        scroll_state.current_scroll_index = i64::from(scroll_state.current_scroll_index)
+            .saturating_add(amount)
+            .clamp(
+                0,
+                i64::from(scroll_state.max_scroll_index.saturating_sub(1)),
+            ) as u16;
+    }
+}
+
 pub struct AppHelpDialogState {
     pub is_showing_help: bool,
     pub scroll_state: ParagraphScrollState,
@@ -163,6 +461,8 @@ impl NetWidgetState {
 pub struct NetState {
     pub force_update: Option<u64>,
     pub widget_states: HashMap<u64, NetWidgetState>,
+    /// Interfaces graphed as their own lines in the network graph, in the order they were picked.
+    pub graphed_interfaces: Vec<String>,
 }
 
 impl NetState {
@@ -170,6 +470,20 @@ impl NetState {
         NetState {
             force_update: None,
             widget_states,
+            graphed_interfaces: Vec::new(),
+        }
+    }
+
+    /// Graphs the given interface if it isn't already, and stops graphing it otherwise.
+    pub fn toggle_graphed_interface(&mut self, interface: &str) {
+        if let Some(index) = self
+            .graphed_interfaces
+            .iter()
+            .position(|graphed| graphed == interface)
+        {
+            self.graphed_interfaces.remove(index);
+        } else {
+            self.graphed_interfaces.push(interface.to_string());
         }
     }
 
@@ -192,10 +506,11 @@ pub struct CpuWidgetState {
 
 impl CpuWidgetState {
     pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
-        const CPU_LEGEND_HEADER: [&str; 2] = ["CPU", "Use%"];
+        const CPU_LEGEND_HEADER: [&str; 3] = ["CPU", "Use%", "Freq"];
         const WIDTHS: [WidthBounds; CPU_LEGEND_HEADER.len()] = [
             WidthBounds::soft_from_str("CPU", Some(0.5)),
             WidthBounds::soft_from_str("Use%", Some(0.5)),
+            WidthBounds::soft_from_str("Freq", Some(0.5)),
         ];
 
         let table_state = TableComponentState::new(
@@ -218,9 +533,34 @@ impl CpuWidgetState {
     }
 }
 
+/// How the CPUs are grouped in the CPU widgets, by where they are in the topology of the machine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CpuGrouping {
+    #[default]
+    None,
+    Node,
+    Socket,
+    Core,
+}
+
+impl CpuGrouping {
+    /// The grouping to switch to after this one.
+    pub fn next(self) -> Self {
+        match self {
+            CpuGrouping::None => CpuGrouping::Node,
+            CpuGrouping::Node => CpuGrouping::Socket,
+            CpuGrouping::Socket => CpuGrouping::Core,
+            CpuGrouping::Core => CpuGrouping::None,
+        }
+    }
+}
+
 pub struct CpuState {
     pub force_update: Option<u64>,
     pub widget_states: HashMap<u64, CpuWidgetState>,
+    pub grouping: CpuGrouping,
+    /// The names of the groups whose CPUs are shown. Groups start out collapsed.
+    pub expanded_groups: HashSet<String>,
 }
 
 impl CpuState {
@@ -228,6 +568,15 @@ impl CpuState {
         CpuState {
             force_update: None,
             widget_states,
+            grouping: CpuGrouping::default(),
+            expanded_groups: HashSet::new(),
+        }
+    }
+
+    /// Shows the CPUs of the given group if they are hidden, and hides them otherwise.
+    pub fn toggle_expanded_group(&mut self, group_name: &str) {
+        if !self.expanded_groups.remove(group_name) {
+            self.expanded_groups.insert(group_name.to_string());
         }
     }
 
@@ -240,6 +589,63 @@ impl CpuState {
     }
 }
 
+pub struct DiskIoWidgetState {
+    pub current_display_time: u64,
+    pub is_legend_hidden: bool,
+    pub autohide_timer: Option<Instant>,
+    pub table_state: TableComponentState,
+}
+
+impl DiskIoWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        const DISK_IO_LEGEND_HEADER: [&str; 3] = ["Device", "R/s", "W/s"];
+        const WIDTHS: [WidthBounds; DISK_IO_LEGEND_HEADER.len()] = [
+            WidthBounds::soft_from_str("Device", Some(0.5)),
+            WidthBounds::soft_from_str("R/s", Some(0.25)),
+            WidthBounds::soft_from_str("W/s", Some(0.25)),
+        ];
+
+        let table_state = TableComponentState::new(
+            DISK_IO_LEGEND_HEADER
+                .iter()
+                .zip(WIDTHS)
+                .map(|(c, width)| {
+                    TableComponentColumn::new_custom(CellContent::new(*c, None), width)
+                })
+                .collect(),
+        );
+
+        DiskIoWidgetState {
+            current_display_time,
+            is_legend_hidden: false,
+            autohide_timer,
+            table_state,
+        }
+    }
+}
+
+pub struct DiskIoState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, DiskIoWidgetState>,
+}
+
+impl DiskIoState {
+    pub fn init(widget_states: HashMap<u64, DiskIoWidgetState>) -> Self {
+        DiskIoState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut DiskIoWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&DiskIoWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct MemWidgetState {
     pub current_display_time: u64,
     pub autohide_timer: Option<Instant>,
@@ -275,6 +681,78 @@ impl MemState {
     }
 }
 
+pub struct PressureWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl PressureWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        PressureWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
+
+pub struct PressureState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, PressureWidgetState>,
+}
+
+impl PressureState {
+    pub fn init(widget_states: HashMap<u64, PressureWidgetState>) -> Self {
+        PressureState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut PressureWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&PressureWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct CpuFreqWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl CpuFreqWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        CpuFreqWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
+
+pub struct CpuFreqState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, CpuFreqWidgetState>,
+}
+
+impl CpuFreqState {
+    pub fn init(widget_states: HashMap<u64, CpuFreqWidgetState>) -> Self {
+        CpuFreqState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut CpuFreqWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&CpuFreqWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct TempState {
     pub widget_states: HashMap<u64, TempWidgetState>,
 }
@@ -293,6 +771,72 @@ impl TempState {
     }
 }
 
+pub struct TempGraphWidgetState {
+    pub current_display_time: u64,
+    pub is_legend_hidden: bool,
+    pub autohide_timer: Option<Instant>,
+    pub table_state: TableComponentState,
+    /// The sensors that have been toggled off in the legend.
+    pub hidden_sensors: HashSet<String>,
+}
+
+impl TempGraphWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        const TEMP_GRAPH_LEGEND_HEADER: [&str; 2] = ["Sensor", "Temp"];
+        const WIDTHS: [WidthBounds; TEMP_GRAPH_LEGEND_HEADER.len()] = [
+            WidthBounds::soft_from_str("Sensor", Some(0.8)),
+            WidthBounds::soft_from_str("Temp", None),
+        ];
+
+        let table_state = TableComponentState::new(
+            TEMP_GRAPH_LEGEND_HEADER
+                .iter()
+                .zip(WIDTHS)
+                .map(|(c, width)| {
+                    TableComponentColumn::new_custom(CellContent::new(*c, None), width)
+                })
+                .collect(),
+        );
+
+        TempGraphWidgetState {
+            current_display_time,
+            is_legend_hidden: false,
+            autohide_timer,
+            table_state,
+            hidden_sensors: HashSet::new(),
+        }
+    }
+
+    /// Hides the given sensor's line in the graph, or shows it again if it is already hidden.
+    pub fn toggle_sensor(&mut self, sensor: &str) {
+        if !self.hidden_sensors.remove(sensor) {
+            self.hidden_sensors.insert(sensor.to_string());
+        }
+    }
+}
+
+pub struct TempGraphState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, TempGraphWidgetState>,
+}
+
+impl TempGraphState {
+    pub fn init(widget_states: HashMap<u64, TempGraphWidgetState>) -> Self {
+        TempGraphState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut TempGraphWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&TempGraphWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct DiskState {
     pub widget_states: HashMap<u64, DiskWidgetState>,
 }
@@ -310,6 +854,75 @@ impl DiskState {
         self.widget_states.get(&widget_id)
     }
 }
+pub struct NetInterfaceState {
+    pub widget_states: HashMap<u64, NetInterfaceWidgetState>,
+}
+
+impl NetInterfaceState {
+    pub fn init(widget_states: HashMap<u64, NetInterfaceWidgetState>) -> Self {
+        NetInterfaceState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut NetInterfaceWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&NetInterfaceWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct CgroupState {
+    /// Whether the rows should be rebuilt, as the data or the collapsed groups have changed.
+    pub force_update: bool,
+    pub widget_states: HashMap<u64, CgroupWidgetState>,
+    /// The paths of the groups whose children are hidden.
+    pub collapsed_paths: HashSet<String>,
+}
+
+impl CgroupState {
+    pub fn init(widget_states: HashMap<u64, CgroupWidgetState>) -> Self {
+        CgroupState {
+            force_update: false,
+            widget_states,
+            collapsed_paths: HashSet::new(),
+        }
+    }
+
+    /// Hides the children of the given group if they are shown, and shows them otherwise.
+    pub fn toggle_collapsed_path(&mut self, path: &str) {
+        if !self.collapsed_paths.remove(path) {
+            self.collapsed_paths.insert(path.to_string());
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut CgroupWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&CgroupWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct SensorState {
+    pub widget_states: HashMap<u64, SensorWidgetState>,
+}
+
+impl SensorState {
+    pub fn init(widget_states: HashMap<u64, SensorWidgetState>) -> Self {
+        SensorState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut SensorWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&SensorWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct BasicTableWidgetState {
     // Since this is intended (currently) to only be used for ONE widget, that's
     // how it's going to be written.  If we want to allow for multiple of these,
@@ -352,3 +965,196 @@ pub struct ParagraphScrollState {
     pub current_scroll_index: u16,
     pub max_scroll_index: u16,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_process_details_scroll() {
+        let mut state = AppProcessDetailsState::default();
+        state.scroll(1);
+        assert_eq!(state.scroll_state.current_scroll_index, 0);
+
+        state.scroll_state.max_scroll_index = 20;
+        state.scroll(5);
+        assert_eq!(state.scroll_state.current_scroll_index, 5);
+        state.scroll(-AppProcessDetailsState::PAGE_LINES);
+        assert_eq!(state.scroll_state.current_scroll_index, 0);
+        state.scroll(AppProcessDetailsState::PAGE_LINES);
+        assert_eq!(state.scroll_state.current_scroll_index, 10);
+
+        // Jumping to either end mustn't overflow.
+        state.scroll(i64::MAX);
+        assert_eq!(state.scroll_state.current_scroll_index, 19);
+        state.scroll(i64::MIN);
+        assert_eq!(state.scroll_state.current_scroll_index, 0);
+    }
+
+    #[test]
+    fn test_priority_dialog_fields() {
+        let mut state = AppPriorityDialogState::default();
+        if cfg!(target_os = "linux") {
+            assert_eq!(
+                state.fields(),
+                vec![
+                    PriorityField::Nice,
+                    PriorityField::IoClass,
+                    PriorityField::IncludeChildren
+                ]
+            );
+
+            // Only classes with levels show the level.
+            state.io_class = IoPriorityClass::BestEffort;
+            assert_eq!(state.fields().len(), 4);
+            state.io_class = IoPriorityClass::Idle;
+            assert_eq!(state.fields().len(), 3);
+        }
+
+        // A group has no single process to take the children of.
+        state.selection = ProcessSelection::Grouped;
+        assert!(!state.fields().contains(&PriorityField::IncludeChildren));
+
+        state.move_selection(-1);
+        assert_eq!(state.selected_field, PriorityField::Nice);
+        state.move_selection(isize::MAX);
+        assert_eq!(state.selected_field, *state.fields().last().unwrap());
+    }
+
+    #[test]
+    fn test_priority_dialog_change() {
+        let mut state = AppPriorityDialogState {
+            nice: 5,
+            ..Default::default()
+        };
+        assert_eq!(state.changed_nice(), None);
+
+        state.change_selected(-30);
+        assert_eq!(state.nice, AppPriorityDialogState::MIN_NICE);
+        state.change_selected(100);
+        assert_eq!(state.nice, AppPriorityDialogState::MAX_NICE);
+        assert_eq!(state.changed_nice(), Some(AppPriorityDialogState::MAX_NICE));
+
+        // Changing the I/O priority leaves the nice value alone.
+        let mut state = AppPriorityDialogState {
+            selected_field: PriorityField::IoClass,
+            ..Default::default()
+        };
+        state.change_selected(2);
+        assert_eq!(state.io_class, IoPriorityClass::BestEffort);
+        state.change_selected(10);
+        assert_eq!(state.io_class, IoPriorityClass::Idle);
+        assert_eq!(state.changed_nice(), None);
+
+        state.selected_field = PriorityField::IoLevel;
+        state.change_selected(10);
+        assert_eq!(state.io_level, AppPriorityDialogState::MAX_IO_LEVEL);
+        state.change_selected(-10);
+        assert_eq!(state.io_level, 0);
+
+        state.selected_field = PriorityField::IncludeChildren;
+        state.change_selected(1);
+        assert!(state.include_children);
+        state.change_selected(-1);
+        assert!(!state.include_children);
+    }
+
+    /// An affinity dialog with `count` cores in rows of `columns`, where the cores have gaps in their IDs like
+    /// when some are offline.
+    fn affinity_state(count: usize, columns: usize) -> AppAffinityDialogState {
+        AppAffinityDialogState {
+            cores: (0..count)
+                .map(|index| AffinityCore {
+                    name: format!("CPU{}", index),
+                    id: index * 2,
+                    is_allowed: false,
+                })
+                .collect(),
+            columns,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_affinity_dialog_move() {
+        let mut state = affinity_state(7, 3);
+        assert_eq!(state.rows(), 3);
+
+        state.move_row(1);
+        assert_eq!(state.selected_core, 3);
+        state.move_row(1);
+        assert_eq!(state.selected_core, 6);
+
+        // Moving off the grid, even into the gap at the end of the last row, does nothing.
+        state.move_row(1);
+        assert_eq!(state.selected_core, 6);
+        state.move_selection(-1);
+        state.move_row(1);
+        assert_eq!(state.selected_core, 5);
+        state.move_row(-2);
+        assert_eq!(state.selected_core, 5);
+        state.move_row(-1);
+        assert_eq!(state.selected_core, 2);
+        state.move_row(-1);
+        assert_eq!(state.selected_core, 2);
+
+        state.move_selection(isize::MAX);
+        assert_eq!(state.selected_core, 6);
+        state.move_selection(isize::MIN);
+        assert_eq!(state.selected_core, 0);
+    }
+
+    #[test]
+    fn test_affinity_dialog_scroll() {
+        let mut state = affinity_state(20, 2);
+
+        state.scroll_to_selected(3);
+        assert_eq!(state.scroll_row, 0);
+
+        // The grid only scrolls once the selected core's row is out of view.
+        state.selected_core = 5;
+        state.scroll_to_selected(3);
+        assert_eq!(state.scroll_row, 0);
+        state.selected_core = 6;
+        state.scroll_to_selected(3);
+        assert_eq!(state.scroll_row, 1);
+        state.selected_core = 19;
+        state.scroll_to_selected(3);
+        assert_eq!(state.scroll_row, 7);
+        state.selected_core = 4;
+        state.scroll_to_selected(3);
+        assert_eq!(state.scroll_row, 2);
+
+        // Growing the dialog doesn't leave empty rows at the end.
+        state.scroll_to_selected(20);
+        assert_eq!(state.scroll_row, 0);
+    }
+
+    #[test]
+    fn test_affinity_dialog_toggle() {
+        let mut state = affinity_state(4, 2);
+        assert!(state.allowed_cores().is_empty());
+
+        state.selected_core = 1;
+        state.toggle_selected();
+        assert_eq!(state.allowed_cores(), vec![2]);
+
+        // Toggling all allows every core unless they all are already.
+        state.toggle_all();
+        assert_eq!(state.allowed_cores(), vec![0, 2, 4, 6]);
+        state.toggle_all();
+        assert!(state.allowed_cores().is_empty());
+    }
+
+    #[test]
+    fn test_process_details_pid() {
+        let mut state = AppProcessDetailsState {
+            process: Some(("bottom".to_string(), 42)),
+            ..Default::default()
+        };
+        assert_eq!(state.pid(), None);
+
+        state.is_showing_details = true;
+        assert_eq!(state.pid(), Some(42));
+    }
+}
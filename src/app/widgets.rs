@@ -6,3 +6,12 @@ pub use temperature_table_widget::*;
 
 pub mod disk_table_widget;
 pub use disk_table_widget::*;
+
+pub mod net_interface_table_widget;
+pub use net_interface_table_widget::*;
+
+pub mod cgroup_table_widget;
+pub use cgroup_table_widget::*;
+
+pub mod sensor_table_widget;
+pub use sensor_table_widget::*;
@@ -0,0 +1,37 @@
+use crate::components::text_table::{
+    CellContent, TableComponentColumn, TableComponentState, WidthBounds,
+};
+
+pub struct CgroupWidgetState {
+    pub table_state: TableComponentState,
+}
+
+impl Default for CgroupWidgetState {
+    fn default() -> Self {
+        const CGROUP_HEADERS: [&str; 8] = [
+            "Cgroup", "CPU%", "Mem", "Mem Max", "Mem%", "R/s", "W/s", "PIDs",
+        ];
+        const WIDTHS: [WidthBounds; CGROUP_HEADERS.len()] = [
+            WidthBounds::soft_from_str(CGROUP_HEADERS[0], Some(0.4)),
+            WidthBounds::Hard(6),
+            WidthBounds::Hard(8),
+            WidthBounds::Hard(8),
+            WidthBounds::Hard(6),
+            WidthBounds::Hard(8),
+            WidthBounds::Hard(8),
+            WidthBounds::Hard(5),
+        ];
+
+        CgroupWidgetState {
+            table_state: TableComponentState::new(
+                CGROUP_HEADERS
+                    .iter()
+                    .zip(WIDTHS)
+                    .map(|(header, width)| {
+                        TableComponentColumn::new_custom(CellContent::new(*header, None), width)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
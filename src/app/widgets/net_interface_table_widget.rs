@@ -0,0 +1,44 @@
+use crate::components::text_table::{
+    CellContent, TableComponentColumn, TableComponentState, WidthBounds,
+};
+
+pub struct NetInterfaceWidgetState {
+    pub table_state: TableComponentState,
+}
+
+impl Default for NetInterfaceWidgetState {
+    fn default() -> Self {
+        const NET_INTERFACE_HEADERS: [&str; 8] = [
+            "Interface",
+            "RX",
+            "TX",
+            "Total RX",
+            "Total TX",
+            "Packets",
+            "Errors",
+            "Drops",
+        ];
+        const WIDTHS: [WidthBounds; NET_INTERFACE_HEADERS.len()] = [
+            WidthBounds::soft_from_str(NET_INTERFACE_HEADERS[0], Some(0.2)),
+            WidthBounds::Hard(10),
+            WidthBounds::Hard(10),
+            WidthBounds::Hard(8),
+            WidthBounds::Hard(8),
+            WidthBounds::soft_from_str(NET_INTERFACE_HEADERS[5], Some(0.15)),
+            WidthBounds::soft_from_str(NET_INTERFACE_HEADERS[6], Some(0.1)),
+            WidthBounds::soft_from_str(NET_INTERFACE_HEADERS[7], Some(0.1)),
+        ];
+
+        NetInterfaceWidgetState {
+            table_state: TableComponentState::new(
+                NET_INTERFACE_HEADERS
+                    .iter()
+                    .zip(WIDTHS)
+                    .map(|(header, width)| {
+                        TableComponentColumn::new_custom(CellContent::new(*header, None), width)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -62,6 +62,35 @@ pub enum ProcWidgetMode {
     Normal,
 }
 
+/// What processes are grouped by while in [`ProcWidgetMode::Grouped`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupKey {
+    Name,
+    Command,
+    /// The container or systemd unit of a process, see [`ProcessHarvest::cgroup_label`].
+    Cgroup,
+}
+
+impl GroupKey {
+    /// Returns what identifies the group a process belongs to.
+    pub fn id(self, process: &ProcessHarvest) -> &str {
+        match self {
+            GroupKey::Name => &process.name,
+            GroupKey::Command => &process.command,
+            GroupKey::Cgroup => process.cgroup_label(),
+        }
+    }
+
+    /// Returns the mapping from each group to the PIDs in it.
+    pub fn pid_map(self, process_data: &ProcessData) -> &StringPidMap {
+        match self {
+            GroupKey::Name => &process_data.name_pid_map,
+            GroupKey::Command => &process_data.cmd_pid_map,
+            GroupKey::Cgroup => &process_data.cgroup_pid_map,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum ProcWidgetColumn {
     CpuPercent,
@@ -74,6 +103,13 @@ pub enum ProcWidgetColumn {
     TotalWrite,
     State,
     User,
+    VirtualMemory,
+    ResidentMemory,
+    SharedMemory,
+    SwapMemory,
+    ProportionalMemory,
+    CpuHistory,
+    Cgroup,
 }
 
 impl ProcWidgetColumn {
@@ -90,6 +126,13 @@ impl ProcWidgetColumn {
     const PID: CellContent = CellContent::Simple(Cow::Borrowed("PID"));
     const COUNT: CellContent = CellContent::Simple(Cow::Borrowed("Count"));
     const USER: CellContent = CellContent::Simple(Cow::Borrowed("User"));
+    const VIRT: CellContent = CellContent::Simple(Cow::Borrowed("Virt"));
+    const RSS: CellContent = CellContent::Simple(Cow::Borrowed("RSS"));
+    const SHARED: CellContent = CellContent::Simple(Cow::Borrowed("Shared"));
+    const SWAP: CellContent = CellContent::Simple(Cow::Borrowed("Swap"));
+    const PSS: CellContent = CellContent::Simple(Cow::Borrowed("PSS"));
+    const CPU_HISTORY: CellContent = CellContent::Simple(Cow::Borrowed("CPU Hist"));
+    const CGROUP: CellContent = CellContent::Simple(Cow::Borrowed("Cgroup"));
 
     const SHORTCUT_CPU_PERCENT: CellContent = CellContent::Simple(Cow::Borrowed("CPU%(c)"));
     const SHORTCUT_MEM_PERCENT: CellContent = CellContent::Simple(Cow::Borrowed("Mem%(m)"));
@@ -128,13 +171,20 @@ impl ProcWidgetColumn {
             ProcWidgetColumn::TotalWrite => &Self::TOTAL_WRITE,
             ProcWidgetColumn::State => &Self::STATE,
             ProcWidgetColumn::User => &Self::USER,
+            ProcWidgetColumn::VirtualMemory => &Self::VIRT,
+            ProcWidgetColumn::ResidentMemory => &Self::RSS,
+            ProcWidgetColumn::SharedMemory => &Self::SHARED,
+            ProcWidgetColumn::SwapMemory => &Self::SWAP,
+            ProcWidgetColumn::ProportionalMemory => &Self::PSS,
+            ProcWidgetColumn::CpuHistory => &Self::CPU_HISTORY,
+            ProcWidgetColumn::Cgroup => &Self::CGROUP,
         }
     }
 
     /// Sorts the given data in-place.
     pub fn sort(
-        &self, sort_descending: bool, data: &mut [&ProcessHarvest], is_using_command: bool,
-        cmd_pid_map: &StringPidMap, name_pid_map: &StringPidMap,
+        &self, sort_descending: bool, data: &mut [&ProcessHarvest], group_key: Option<GroupKey>,
+        process_data: &ProcessData,
     ) {
         match self {
             ProcWidgetColumn::CpuPercent => {
@@ -158,32 +208,23 @@ impl ProcWidgetColumn {
             ProcWidgetColumn::PidOrCount { is_count } => {
                 data.sort_by_cached_key(|c| c.name.to_lowercase());
                 if *is_count {
-                    if is_using_command {
-                        if sort_descending {
-                            data.sort_by_cached_key(|p| {
-                                Reverse(cmd_pid_map.get(&p.command).map(|v| v.len()).unwrap_or(0))
-                            })
-                        } else {
-                            data.sort_by_cached_key(|p| {
-                                cmd_pid_map.get(&p.command).map(|v| v.len()).unwrap_or(0)
-                            })
-                        }
+                    let count = |p: &ProcessHarvest| row_pids(p, process_data, group_key).len();
+                    if sort_descending {
+                        data.sort_by_cached_key(|p| Reverse(count(p)));
                     } else {
-                        #[allow(clippy::collapsible-else-if)]
-                        if sort_descending {
-                            data.sort_by_cached_key(|p| {
-                                Reverse(name_pid_map.get(&p.name).map(|v| v.len()).unwrap_or(0))
-                            })
-                        } else {
-                            data.sort_by_cached_key(|p| {
-                                name_pid_map.get(&p.name).map(|v| v.len()).unwrap_or(0)
-                            })
-                        }
+                        data.sort_by_cached_key(|p| count(p));
                     }
                 } else {
                     data.sort_by(|a, b| sort_partial_fn(sort_descending)(a.pid, b.pid));
                 }
             }
+            ProcWidgetColumn::ProcNameOrCommand { .. } if group_key == Some(GroupKey::Cgroup) => {
+                if sort_descending {
+                    data.sort_by_cached_key(|p| Reverse(p.cgroup_label().to_lowercase()));
+                } else {
+                    data.sort_by_cached_key(|p| p.cgroup_label().to_lowercase());
+                }
+            }
             ProcWidgetColumn::ProcNameOrCommand { is_command } => {
                 if *is_command {
                     if sort_descending {
@@ -248,6 +289,79 @@ impl ProcWidgetColumn {
                     }
                 }
             }
+            ProcWidgetColumn::VirtualMemory => {
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                if sort_descending {
+                    data.sort_by_key(|a| Reverse(a.virt_bytes));
+                } else {
+                    data.sort_by_key(|a| a.virt_bytes);
+                }
+            }
+            ProcWidgetColumn::ResidentMemory => {
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                if sort_descending {
+                    data.sort_by_key(|a| Reverse(a.mem_usage_bytes));
+                } else {
+                    data.sort_by_key(|a| a.mem_usage_bytes);
+                }
+            }
+            ProcWidgetColumn::SharedMemory => {
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                if sort_descending {
+                    data.sort_by_key(|a| Reverse(a.shared_bytes));
+                } else {
+                    data.sort_by_key(|a| a.shared_bytes);
+                }
+            }
+            ProcWidgetColumn::SwapMemory => {
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                if sort_descending {
+                    data.sort_by_key(|a| Reverse(a.swap_bytes));
+                } else {
+                    data.sort_by_key(|a| a.swap_bytes);
+                }
+            }
+            ProcWidgetColumn::ProportionalMemory => {
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                if sort_descending {
+                    data.sort_by_key(|a| Reverse(a.pss_bytes));
+                } else {
+                    data.sort_by_key(|a| a.pss_bytes);
+                }
+            }
+            ProcWidgetColumn::CpuHistory => {
+                // Sort by the average over the drawn history, so rows don't jump around with every spike.
+                let averages: FxHashMap<Pid, f64> = data
+                    .iter()
+                    .map(|p| {
+                        let history = row_cpu_history(
+                            p,
+                            process_data,
+                            group_key,
+                            ProcWidget::CPU_HISTORY_LEN,
+                        );
+                        let average = if history.is_empty() {
+                            0.0
+                        } else {
+                            history.iter().sum::<f64>() / history.len() as f64
+                        };
+                        (p.pid, average)
+                    })
+                    .collect();
+
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                data.sort_by(|a, b| {
+                    sort_partial_fn(sort_descending)(averages[&a.pid], averages[&b.pid])
+                });
+            }
+            ProcWidgetColumn::Cgroup => {
+                data.sort_by_cached_key(|p| p.name.to_lowercase());
+                if sort_descending {
+                    data.sort_by_cached_key(|p| Reverse(p.cgroup_label().to_lowercase()));
+                } else {
+                    data.sort_by_cached_key(|p| p.cgroup_label().to_lowercase());
+                }
+            }
         }
     }
 
@@ -261,16 +375,73 @@ impl ProcWidgetColumn {
             | ProcWidgetColumn::WritePerSecond
             | ProcWidgetColumn::TotalRead
             | ProcWidgetColumn::TotalWrite
-            | ProcWidgetColumn::Memory { .. } => SortOrder::Descending,
+            | ProcWidgetColumn::Memory { .. }
+            | ProcWidgetColumn::VirtualMemory
+            | ProcWidgetColumn::ResidentMemory
+            | ProcWidgetColumn::SharedMemory
+            | ProcWidgetColumn::SwapMemory
+            | ProcWidgetColumn::ProportionalMemory
+            | ProcWidgetColumn::CpuHistory => SortOrder::Descending,
 
             ProcWidgetColumn::PidOrCount { is_count: false }
             | ProcWidgetColumn::ProcNameOrCommand { .. }
             | ProcWidgetColumn::State
-            | ProcWidgetColumn::User => SortOrder::Ascending,
+            | ProcWidgetColumn::User
+            | ProcWidgetColumn::Cgroup => SortOrder::Ascending,
         }
     }
 }
 
+/// Returns the PIDs behind a row of the table: every process in the same group while grouping, and otherwise just
+/// the process itself.
+fn row_pids<'a>(
+    process: &'a ProcessHarvest, process_data: &'a ProcessData, group_key: Option<GroupKey>,
+) -> &'a [Pid] {
+    match group_key {
+        Some(group_key) => group_key
+            .pid_map(process_data)
+            .get(group_key.id(process))
+            .map(Vec::as_slice)
+            .unwrap_or_default(),
+        None => std::slice::from_ref(&process.pid),
+    }
+}
+
+/// Returns the CPU usage of a row of the table over the last `len` samples, oldest first.
+fn row_cpu_history(
+    process: &ProcessHarvest, process_data: &ProcessData, group_key: Option<GroupKey>, len: usize,
+) -> Vec<f64> {
+    process_data
+        .combined_history(row_pids(process, process_data, group_key), len)
+        .iter()
+        .map(|sample| sample.cpu_usage_percent)
+        .collect()
+}
+
+/// Draws the last `len` values as a line of bars, right-aligned so the newest value is always at the end. Values
+/// are scaled so the highest of them is a full bar, as a process rarely uses more than a small share of all CPUs.
+fn sparkline(values: &[f64], len: usize) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let values = &values[values.len().saturating_sub(len)..];
+    let max_value = values.iter().copied().fold(0.0, f64::max);
+    if max_value <= 0.0 {
+        return format!(
+            "{:>width$}",
+            BARS[0].to_string().repeat(values.len()),
+            width = len
+        );
+    }
+
+    let mut line = " ".repeat(len - values.len());
+    line.extend(values.iter().map(|value| {
+        let level = (value / max_value * (BARS.len() - 1) as f64).round();
+        BARS[(level.max(0.0) as usize).min(BARS.len() - 1)]
+    }));
+
+    line
+}
+
 impl TableComponentHeader for ProcWidgetColumn {
     fn header_text(&self) -> &CellContent {
         match self {
@@ -302,10 +473,29 @@ impl TableComponentHeader for ProcWidgetColumn {
             ProcWidgetColumn::TotalWrite => &Self::TOTAL_WRITE,
             ProcWidgetColumn::State => &Self::STATE,
             ProcWidgetColumn::User => &Self::USER,
+            ProcWidgetColumn::VirtualMemory => &Self::VIRT,
+            ProcWidgetColumn::ResidentMemory => &Self::RSS,
+            ProcWidgetColumn::SharedMemory => &Self::SHARED,
+            ProcWidgetColumn::SwapMemory => &Self::SWAP,
+            ProcWidgetColumn::ProportionalMemory => &Self::PSS,
+            ProcWidgetColumn::CpuHistory => &Self::CPU_HISTORY,
+            ProcWidgetColumn::Cgroup => &Self::CGROUP,
         }
     }
 }
 
+/// The settings a process widget starts out with.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProcWidgetOptions {
+    pub is_case_sensitive: bool,
+    pub is_match_whole_word: bool,
+    pub is_use_regex: bool,
+    pub show_memory_as_values: bool,
+    pub is_command: bool,
+    pub show_pss: bool,
+    pub show_cpu_history: bool,
+}
+
 pub struct ProcWidget {
     pub mode: ProcWidgetMode,
 
@@ -313,11 +503,36 @@ pub struct ProcWidget {
     pub table_state: TableComponentState<ProcWidgetColumn>,
     pub sort_table_state: TableComponentState,
 
+    /// The PIDs of processes whose threads are currently shown below them.
+    pub expanded_thread_pids: FxHashSet<Pid>,
+
+    /// The PIDs of processes marked in the table, which actions apply to instead of the selected row.
+    pub marked_pids: FxHashSet<Pid>,
+
+    /// Whether processes are grouped by their container or systemd unit instead of by name while grouped.
+    pub is_grouping_by_cgroup: bool,
+
     pub is_sort_open: bool,
     pub force_rerender: bool,
     pub force_update_data: bool,
 
     pub table_data: TableData,
+
+    /// How each row of [`ProcWidget::table_data`] is drawn.
+    pub row_states: Vec<ProcRowState>,
+}
+
+/// How a row of the process table stands out from the others.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcRowState {
+    /// Whether the row is only shown because a process below it in the tree matches the search.
+    pub is_disabled: bool,
+
+    /// Whether the process, or every process in its group, is marked.
+    pub is_marked: bool,
+
+    /// Whether the process, or any process in its group, is stopped.
+    pub is_stopped: bool,
 }
 
 impl ProcWidget {
@@ -335,11 +550,35 @@ impl ProcWidget {
     pub const STATE: usize = 9;
     #[cfg(not(target_family = "unix"))]
     pub const STATE: usize = 8;
+    pub const VIRT: usize = Self::STATE + 1;
+    pub const RSS: usize = Self::STATE + 2;
+    #[cfg(target_os = "linux")]
+    pub const SHARED: usize = Self::STATE + 3;
+    #[cfg(target_os = "linux")]
+    pub const SWAP: usize = Self::STATE + 4;
+    #[cfg(target_os = "linux")]
+    pub const PSS: usize = Self::STATE + 5;
+    #[cfg(target_os = "linux")]
+    pub const CPU_HISTORY: usize = Self::STATE + 6;
+    #[cfg(not(target_os = "linux"))]
+    pub const CPU_HISTORY: usize = Self::STATE + 3;
+    #[cfg(target_os = "linux")]
+    pub const CGROUP: usize = Self::STATE + 7;
+
+    /// How many of the most recent samples are drawn in the CPU history column.
+    pub const CPU_HISTORY_LEN: usize = 10;
+
+    pub fn init(mode: ProcWidgetMode, options: ProcWidgetOptions) -> Self {
+        let ProcWidgetOptions {
+            is_case_sensitive,
+            is_match_whole_word,
+            is_use_regex,
+            show_memory_as_values,
+            is_command,
+            show_pss,
+            show_cpu_history,
+        } = options;
 
-    pub fn init(
-        mode: ProcWidgetMode, is_case_sensitive: bool, is_match_whole_word: bool,
-        is_use_regex: bool, show_memory_as_values: bool, is_command: bool,
-    ) -> Self {
         let mut process_search_state = ProcessSearchState::default();
 
         if is_case_sensitive {
@@ -385,6 +624,20 @@ impl ProcWidget {
                 #[cfg(target_family = "unix")]
                 TableComponentColumn::new_soft(ProcWidgetColumn::User, Some(0.05)),
                 TableComponentColumn::new_hard(ProcWidgetColumn::State, 7),
+                TableComponentColumn::new_hard(ProcWidgetColumn::VirtualMemory, 8),
+                TableComponentColumn::new_hard(ProcWidgetColumn::ResidentMemory, 8),
+                #[cfg(target_os = "linux")]
+                TableComponentColumn::new_hard(ProcWidgetColumn::SharedMemory, 8),
+                #[cfg(target_os = "linux")]
+                TableComponentColumn::new_hard(ProcWidgetColumn::SwapMemory, 8),
+                #[cfg(target_os = "linux")]
+                TableComponentColumn::new_hard(ProcWidgetColumn::ProportionalMemory, 8),
+                TableComponentColumn::new_hard(
+                    ProcWidgetColumn::CpuHistory,
+                    Self::CPU_HISTORY_LEN as u16,
+                ),
+                #[cfg(target_os = "linux")]
+                TableComponentColumn::new_soft(ProcWidgetColumn::Cgroup, Some(0.2)),
             ];
 
             let default_sort_orderings = columns
@@ -399,16 +652,30 @@ impl ProcWidget {
             )))
         };
 
-        ProcWidget {
+        let mut proc_widget = ProcWidget {
             proc_search: process_search_state,
             table_state,
             sort_table_state,
+            expanded_thread_pids: FxHashSet::default(),
+            marked_pids: FxHashSet::default(),
+            is_grouping_by_cgroup: false,
             is_sort_open: false,
             mode,
             force_rerender: true,
             force_update_data: false,
             table_data: TableData::default(),
+            row_states: Vec::default(),
+        };
+
+        // The PSS is slow to collect, so it is only shown if asked for.
+        if cfg!(target_os = "linux") && !show_pss {
+            proc_widget.hide_column(Self::PSS);
         }
+        if !show_cpu_history {
+            proc_widget.hide_column(Self::CPU_HISTORY);
+        }
+
+        proc_widget
     }
 
     pub fn is_using_command(&self) -> bool {
@@ -425,15 +692,35 @@ impl ProcWidget {
         }
     }
 
+    /// Returns what processes are grouped by, if they are grouped.
+    pub fn group_key(&self) -> Option<GroupKey> {
+        if let ProcWidgetMode::Grouped = self.mode {
+            Some(if self.is_grouping_by_cgroup {
+                GroupKey::Cgroup
+            } else if self.is_using_command() {
+                GroupKey::Command
+            } else {
+                GroupKey::Name
+            })
+        } else {
+            None
+        }
+    }
+
     /// This function *only* updates the displayed process data. If there is a need to update the actual *stored* data,
     /// call it before this function.
     pub fn update_displayed_process_data(&mut self, data_collection: &DataCollection) {
+        // Marks are kept by PID, so they only need to be dropped once their process is gone.
+        let process_harvest = &data_collection.process_data.process_harvest;
+        self.marked_pids
+            .retain(|pid| process_harvest.contains_key(pid));
+
         let search_query = if self.proc_search.search_state.is_invalid_or_blank_search() {
             &None
         } else {
             &self.proc_search.search_state.query
         };
-        let table_data = match &self.mode {
+        let (table_data, row_states) = match &self.mode {
             ProcWidgetMode::Tree { collapsed_pids } => {
                 self.get_tree_table_data(collapsed_pids, data_collection, search_query)
             }
@@ -451,12 +738,13 @@ impl ProcWidget {
 
         // Finally, move this data to the widget itself.
         self.table_data = table_data;
+        self.row_states = row_states;
     }
 
     fn get_tree_table_data(
         &self, collapsed_pids: &FxHashSet<Pid>, data_collection: &DataCollection,
         search_query: &Option<Query>,
-    ) -> TableData {
+    ) -> (TableData, Vec<ProcRowState>) {
         const BRANCH_ENDING: char = '└';
         const BRANCH_VERTICAL: char = '│';
         const BRANCH_SPLIT: char = '├';
@@ -464,8 +752,6 @@ impl ProcWidget {
 
         let ProcessData {
             process_harvest,
-            cmd_pid_map,
-            name_pid_map,
             process_parent_mapping,
             orphan_pids,
             ..
@@ -551,6 +837,7 @@ impl ProcWidget {
         };
 
         let mut resulting_strings = vec![];
+        let mut row_states = vec![];
         let mut prefixes = vec![];
         let mut stack = orphan_pids
             .iter()
@@ -597,15 +884,15 @@ impl ProcWidget {
                     )
                 };
 
-                let process_text = self.process_to_text(
+                let (process_text, row_state) = self.process_to_text(
                     &summed_process,
                     &mut col_widths,
-                    cmd_pid_map,
-                    name_pid_map,
+                    &data_collection.process_data,
                     Some(prefix),
                     is_disabled,
                 );
                 resulting_strings.push(process_text);
+                row_states.push(row_state);
             } else {
                 let prefix = if prefixes.is_empty() {
                     String::default()
@@ -617,15 +904,46 @@ impl ProcWidget {
                         BRANCH_HORIZONTAL
                     )
                 };
-                let process_text = self.process_to_text(
+                let (process_text, row_state) = self.process_to_text(
                     process,
                     &mut col_widths,
-                    cmd_pid_map,
-                    name_pid_map,
+                    &data_collection.process_data,
                     Some(prefix),
                     is_disabled,
                 );
                 resulting_strings.push(process_text);
+                row_states.push(row_state);
+
+                if self.expanded_thread_pids.contains(&process.pid) {
+                    let indent = if prefixes.is_empty() {
+                        String::default()
+                    } else {
+                        format!(
+                            "{}{}",
+                            prefixes.join(""),
+                            if is_last {
+                                "   ".to_string()
+                            } else {
+                                format!("{}  ", BRANCH_VERTICAL)
+                            }
+                        )
+                    };
+                    let has_children = filtered_tree
+                        .get(&process.pid)
+                        .map(|children| !children.is_empty())
+                        .unwrap_or(false);
+
+                    for (thread_text, row_state) in self.threads_to_text(
+                        process,
+                        &mut col_widths,
+                        data_collection,
+                        &indent,
+                        has_children,
+                    ) {
+                        resulting_strings.push(thread_text);
+                        row_states.push(row_state);
+                    }
+                }
 
                 if let Some(children_pids) = filtered_tree.get(&process.pid) {
                     if prefixes.is_empty() {
@@ -658,15 +976,18 @@ impl ProcWidget {
             }
         }
 
-        TableData {
-            data: resulting_strings,
-            col_widths,
-        }
+        (
+            TableData {
+                data: resulting_strings,
+                col_widths,
+            },
+            row_states,
+        )
     }
 
     fn get_normal_table_data(
         &self, data_collection: &DataCollection, search_query: &Option<Query>,
-    ) -> TableData {
+    ) -> (TableData, Vec<ProcRowState>) {
         let mut id_pid_map: FxHashMap<String, ProcessHarvest>;
         let filtered_iter = data_collection
             .process_data
@@ -679,19 +1000,15 @@ impl ProcWidget {
                     .unwrap_or(true)
             });
 
-        let mut filtered_data = if let ProcWidgetMode::Grouped = self.mode {
+        let mut filtered_data = if let Some(group_key) = self.group_key() {
             id_pid_map = FxHashMap::default();
             filtered_iter.for_each(|process| {
-                let id = if self.is_using_command() {
-                    &process.command
-                } else {
-                    &process.name
-                };
+                let id = group_key.id(process);
 
                 if let Some(grouped_process_harvest) = id_pid_map.get_mut(id) {
                     grouped_process_harvest.add(process);
                 } else {
-                    id_pid_map.insert(id.clone(), process.clone());
+                    id_pid_map.insert(id.to_string(), process.clone());
                 }
             });
 
@@ -705,9 +1022,6 @@ impl ProcWidget {
     }
 
     fn try_sort(&self, filtered_data: &mut [&ProcessHarvest], data_collection: &DataCollection) {
-        let cmd_pid_map = &data_collection.process_data.cmd_pid_map;
-        let name_pid_map = &data_collection.process_data.name_pid_map;
-
         if let SortState::Sortable(state) = &self.table_state.sort_state {
             let index = state.current_index;
             let order = &state.order;
@@ -716,18 +1030,27 @@ impl ProcWidget {
                 column.header.sort(
                     order.is_descending(),
                     filtered_data,
-                    self.is_using_command(),
-                    cmd_pid_map,
-                    name_pid_map,
+                    self.group_key(),
+                    &data_collection.process_data,
                 );
             }
         }
     }
 
     fn process_to_text(
-        &self, process: &ProcessHarvest, col_widths: &mut [usize], cmd_pid_map: &StringPidMap,
-        name_pid_map: &StringPidMap, proc_prefix: Option<String>, is_disabled: bool,
-    ) -> TableRow {
+        &self, process: &ProcessHarvest, col_widths: &mut [usize], process_data: &ProcessData,
+        proc_prefix: Option<String>, is_disabled: bool,
+    ) -> (TableRow, ProcRowState) {
+        let pids = row_pids(process, process_data, self.group_key());
+        let row_state = ProcRowState {
+            is_disabled,
+            is_marked: !pids.is_empty() && pids.iter().all(|pid| self.marked_pids.contains(pid)),
+            is_stopped: process.is_stopped()
+                || pids.iter().any(|pid| {
+                    matches!(process_data.process_harvest.get(pid), Some(p) if p.is_stopped())
+                }),
+        };
+
         let mut contents = Vec::with_capacity(self.num_shown_columns());
 
         contents.extend(
@@ -749,27 +1072,16 @@ impl ProcWidget {
                         }
                         ProcWidgetColumn::PidOrCount { is_count } => {
                             if is_count {
-                                if self.is_using_command() {
-                                    cmd_pid_map
-                                        .get(&process.command)
-                                        .map(|v| v.len())
-                                        .unwrap_or(0)
-                                        .to_string()
-                                        .into()
-                                } else {
-                                    name_pid_map
-                                        .get(&process.name)
-                                        .map(|v| v.len())
-                                        .unwrap_or(0)
-                                        .to_string()
-                                        .into()
-                                }
+                                pids.len().to_string().into()
                             } else {
                                 process.pid.to_string().into()
                             }
                         }
                         ProcWidgetColumn::ProcNameOrCommand { is_command } => {
-                            let val = if is_command {
+                            let group_key = self.group_key();
+                            let val = if group_key == Some(GroupKey::Cgroup) {
+                                process.cgroup_label().to_string()
+                            } else if is_command {
                                 process.command.clone()
                             } else {
                                 process.name.clone()
@@ -807,6 +1119,32 @@ impl ProcWidget {
                                 "".into()
                             }
                         }
+                        ProcWidgetColumn::VirtualMemory => {
+                            binary_byte_string(process.virt_bytes).into()
+                        }
+                        ProcWidgetColumn::ResidentMemory => {
+                            binary_byte_string(process.mem_usage_bytes).into()
+                        }
+                        ProcWidgetColumn::SharedMemory => {
+                            binary_byte_string(process.shared_bytes).into()
+                        }
+                        ProcWidgetColumn::SwapMemory => {
+                            binary_byte_string(process.swap_bytes).into()
+                        }
+                        ProcWidgetColumn::ProportionalMemory => {
+                            binary_byte_string(process.pss_bytes).into()
+                        }
+                        ProcWidgetColumn::CpuHistory => sparkline(
+                            &row_cpu_history(
+                                process,
+                                process_data,
+                                self.group_key(),
+                                Self::CPU_HISTORY_LEN,
+                            ),
+                            Self::CPU_HISTORY_LEN,
+                        )
+                        .into(),
+                        ProcWidgetColumn::Cgroup => process.cgroup_label().to_string().into(),
                     };
 
                     if let Some(curr) = col_widths.get_mut(itx) {
@@ -817,36 +1155,86 @@ impl ProcWidget {
                 }),
         );
 
-        if is_disabled {
-            TableRow::Styled(contents, tui::style::Style::default())
+        // The style itself is picked when drawing, based on the row state.
+        if row_state == ProcRowState::default() {
+            (TableRow::Raw(contents), row_state)
         } else {
-            TableRow::Raw(contents)
+            (
+                TableRow::Styled(contents, tui::style::Style::default()),
+                row_state,
+            )
         }
     }
 
     fn harvest_to_table_data(
         &self, process_data: &[&ProcessHarvest], data_collection: &DataCollection,
-    ) -> TableData {
-        let cmd_pid_map = &data_collection.process_data.cmd_pid_map;
-        let name_pid_map = &data_collection.process_data.name_pid_map;
-
+    ) -> (TableData, Vec<ProcRowState>) {
         let mut col_widths = vec![0; self.table_state.columns.len()];
+        let mut data = Vec::with_capacity(process_data.len());
+        let mut row_states = Vec::with_capacity(process_data.len());
+
+        for process in process_data {
+            let (process_text, row_state) = self.process_to_text(
+                process,
+                &mut col_widths,
+                &data_collection.process_data,
+                None,
+                false,
+            );
+            data.push(process_text);
+            row_states.push(row_state);
+
+            if matches!(self.mode, ProcWidgetMode::Normal)
+                && self.expanded_thread_pids.contains(&process.pid)
+            {
+                for (thread_text, row_state) in
+                    self.threads_to_text(process, &mut col_widths, data_collection, "", false)
+                {
+                    data.push(thread_text);
+                    row_states.push(row_state);
+                }
+            }
+        }
+
+        (TableData { data, col_widths }, row_states)
+    }
+
+    /// Converts the threads of a process into rows, drawn as branches under the owning process. `indent` is
+    /// placed before each branch, and `has_siblings_after` should be set if more rows follow at the same level
+    /// as the threads (e.g. child processes in tree mode).
+    fn threads_to_text(
+        &self, process: &ProcessHarvest, col_widths: &mut [usize],
+        data_collection: &DataCollection, indent: &str, has_siblings_after: bool,
+    ) -> Vec<(TableRow, ProcRowState)> {
+        const BRANCH_ENDING: char = '└';
+        const BRANCH_SPLIT: char = '├';
+        const BRANCH_HORIZONTAL: char = '─';
+
+        let mut threads = process.threads.iter().collect_vec();
+        self.try_sort(&mut threads, data_collection);
+
+        let num_threads = threads.len();
+        threads
+            .into_iter()
+            .enumerate()
+            .map(|(index, thread)| {
+                let is_last = index + 1 == num_threads && !has_siblings_after;
+                let prefix = format!(
+                    "{}{}{} ",
+                    indent,
+                    if is_last { BRANCH_ENDING } else { BRANCH_SPLIT },
+                    BRANCH_HORIZONTAL
+                );
 
-        let data = process_data
-            .iter()
-            .map(|process| {
                 self.process_to_text(
-                    process,
-                    &mut col_widths,
-                    cmd_pid_map,
-                    name_pid_map,
-                    None,
+                    thread,
+                    col_widths,
+                    &data_collection.process_data,
+                    Some(prefix),
                     false,
                 )
             })
-            .collect();
-
-        TableData { data, col_widths }
+            .collect()
     }
 
     fn get_mut_proc_col(&mut self, index: usize) -> Option<&mut ProcWidgetColumn> {
@@ -923,6 +1311,54 @@ impl ProcWidget {
         }
     }
 
+    /// Shows or hides the threads of the currently selected process. If a thread is selected, the threads of its
+    /// process are hidden. Does nothing while grouped.
+    pub fn toggle_threads(&mut self, process_data: &ProcessData) {
+        if !matches!(self.mode, ProcWidgetMode::Grouped) {
+            let current_posn = self.table_state.current_scroll_position;
+            if let Some(current_row) = self.table_data.data.get(current_posn) {
+                if let Ok(pid) = current_row.row()[ProcWidget::PID_OR_COUNT]
+                    .main_text()
+                    .parse::<Pid>()
+                {
+                    let owner_pid = self
+                        .expanded_thread_pids
+                        .iter()
+                        .copied()
+                        .find(|owner_pid| {
+                            matches!(
+                                process_data.process_harvest.get(owner_pid),
+                                Some(owner) if owner.threads.iter().any(|thread| thread.pid == pid)
+                            )
+                        })
+                        .unwrap_or(pid);
+
+                    if !self.expanded_thread_pids.remove(&owner_pid) {
+                        self.expanded_thread_pids.insert(owner_pid);
+                    }
+                    self.force_data_update();
+                }
+            }
+        }
+    }
+
+    /// Marks the given processes, or unmarks them if they are all marked already.
+    pub fn toggle_marked(&mut self, pids: &[Pid]) {
+        if pids.iter().all(|pid| self.marked_pids.contains(pid)) {
+            for pid in pids {
+                self.marked_pids.remove(pid);
+            }
+        } else {
+            self.marked_pids.extend(pids);
+        }
+        self.force_data_update();
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked_pids.clear();
+        self.force_data_update();
+    }
+
     pub fn toggle_command(&mut self) {
         if let Some(col) = self.table_state.columns.get_mut(Self::PROC_NAME_OR_CMD) {
             if let ProcWidgetColumn::ProcNameOrCommand { is_command } = &mut col.header {
@@ -973,6 +1409,9 @@ impl ProcWidget {
                     #[cfg(target_family = "unix")]
                     self.show_column(Self::USER);
                     self.show_column(Self::STATE);
+                    #[cfg(target_os = "linux")]
+                    self.show_column(Self::CGROUP);
+                    self.is_grouping_by_cgroup = false;
                     self.mode = ProcWidgetMode::Normal;
                 }
                 self.force_rerender_and_update();
@@ -980,6 +1419,22 @@ impl ProcWidget {
         }
     }
 
+    /// Toggles grouping processes by their container or systemd unit. This works like [`ProcWidget::toggle_tab`],
+    /// and also hides the cgroup column as it would just repeat the name of each group.
+    pub fn toggle_cgroup_grouping(&mut self) {
+        if self.is_grouping_by_cgroup {
+            self.toggle_tab();
+        } else if !matches!(self.mode, ProcWidgetMode::Tree { .. }) {
+            if !matches!(self.mode, ProcWidgetMode::Grouped) {
+                self.toggle_tab();
+            }
+            #[cfg(target_os = "linux")]
+            self.hide_column(Self::CGROUP);
+            self.is_grouping_by_cgroup = true;
+            self.force_rerender_and_update();
+        }
+    }
+
     pub fn get_search_cursor_position(&self) -> usize {
         self.proc_search.search_state.grapheme_cursor.cur_cursor()
     }
@@ -1093,9 +1548,211 @@ impl ProcWidget {
 mod test {
     use super::*;
 
+    /// A process widget in the given mode, with the default settings.
+    fn proc_widget(mode: ProcWidgetMode) -> ProcWidget {
+        ProcWidget::init(mode, ProcWidgetOptions::default())
+    }
+
+    fn process(pid: Pid, name: &str) -> ProcessHarvest {
+        ProcessHarvest {
+            pid,
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// A data collection that has harvested the given processes.
+    fn data_collection(processes: Vec<ProcessHarvest>) -> DataCollection {
+        let mut data_collection = DataCollection::default();
+        data_collection.eat_data(Box::new(crate::app::data_harvester::Data {
+            list_of_processes: Some(processes),
+            ..Default::default()
+        }));
+        data_collection
+    }
+
     #[test]
     fn test_sort() {}
 
+    #[test]
+    fn sparkline() {
+        assert_eq!(super::sparkline(&[], 3), "   ");
+        assert_eq!(super::sparkline(&[0.0, 0.0], 3), " ▁▁");
+        assert_eq!(super::sparkline(&[0.0, 2.0], 3), " ▁█");
+        assert_eq!(super::sparkline(&[0.0, 0.5, 1.0, -1.0], 3), "▅█▁");
+    }
+
+    #[test]
+    fn toggle_marked() {
+        let mut proc = proc_widget(ProcWidgetMode::Grouped);
+
+        proc.toggle_marked(&[1, 2]);
+        assert_eq!(proc.marked_pids, vec![1, 2].into_iter().collect());
+
+        // A partly marked group gets marked completely, and a fully marked one gets unmarked.
+        proc.toggle_marked(&[2, 3]);
+        assert_eq!(proc.marked_pids, vec![1, 2, 3].into_iter().collect());
+        proc.toggle_marked(&[2, 3]);
+        assert_eq!(proc.marked_pids, vec![1].into_iter().collect());
+
+        proc.clear_marks();
+        assert!(proc.marked_pids.is_empty());
+    }
+
+    #[test]
+    fn marked_rows() {
+        let data_collection =
+            data_collection(vec![process(1, "a"), process(2, "b"), process(3, "b")]);
+
+        let mut proc = proc_widget(ProcWidgetMode::Grouped);
+
+        // A group is only marked once all of its processes are, and names are left alone either way.
+        proc.toggle_marked(&[1, 2]);
+        proc.update_displayed_process_data(&data_collection);
+        let marked_names = proc
+            .table_data
+            .data
+            .iter()
+            .zip(&proc.row_states)
+            .map(|(row, row_state)| {
+                (
+                    row.row()[ProcWidget::PROC_NAME_OR_CMD]
+                        .main_text()
+                        .to_string(),
+                    row_state.is_marked,
+                )
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            marked_names,
+            vec![("a".to_string(), true), ("b".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn toggle_threads() {
+        let process =
+            |pid: Pid, cpu_usage_percent: f64, threads: Vec<ProcessHarvest>| ProcessHarvest {
+                cpu_usage_percent,
+                threads,
+                ..process(pid, "")
+            };
+        let shown_pids = |proc: &ProcWidget| {
+            proc.table_data
+                .data
+                .iter()
+                .map(|row| row.row()[ProcWidget::PID_OR_COUNT].main_text().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let data_collection = data_collection(vec![
+            process(
+                1,
+                2.0,
+                vec![process(1, 1.5, vec![]), process(4, 0.5, vec![])],
+            ),
+            process(2, 1.0, vec![]),
+        ]);
+        let process_data = &data_collection.process_data;
+
+        let mut proc = proc_widget(ProcWidgetMode::Normal);
+        proc.update_displayed_process_data(&data_collection);
+        assert_eq!(shown_pids(&proc), vec!["1", "2"]);
+
+        // The threads are shown under their process, sorted like processes are.
+        proc.toggle_threads(process_data);
+        proc.update_displayed_process_data(&data_collection);
+        assert_eq!(proc.expanded_thread_pids, vec![1].into_iter().collect());
+        assert_eq!(shown_pids(&proc), vec!["1", "1", "4", "2"]);
+
+        // Toggling on a thread hides the threads of its process.
+        proc.table_state.current_scroll_position = 2;
+        proc.toggle_threads(process_data);
+        proc.update_displayed_process_data(&data_collection);
+        assert!(proc.expanded_thread_pids.is_empty());
+        assert_eq!(shown_pids(&proc), vec!["1", "2"]);
+        assert_eq!(proc.table_state.current_scroll_position, 1);
+
+        // Threads aren't shown while grouped.
+        let mut proc = proc_widget(ProcWidgetMode::Grouped);
+        proc.update_displayed_process_data(&data_collection);
+        proc.toggle_threads(process_data);
+        assert!(proc.expanded_thread_pids.is_empty());
+    }
+
+    #[test]
+    fn stopped_rows() {
+        let process = |pid: Pid, name: &str, state: char| ProcessHarvest {
+            process_state: (String::default(), state),
+            ..process(pid, name)
+        };
+        let stopped_names = |proc: &ProcWidget| {
+            proc.table_data
+                .data
+                .iter()
+                .zip(&proc.row_states)
+                .filter(|(_, row_state)| row_state.is_stopped)
+                .map(|(row, _)| {
+                    row.row()[ProcWidget::PROC_NAME_OR_CMD]
+                        .main_text()
+                        .to_string()
+                })
+                .sorted()
+                .collect::<Vec<_>>()
+        };
+
+        let data_collection = data_collection(vec![
+            process(1, "a", 'S'),
+            process(2, "b", 'T'),
+            process(3, "b", 'S'),
+            process(4, "c", 'R'),
+        ]);
+
+        // Stopped processes are told apart by their state, and their name is left alone.
+        let mut proc = proc_widget(ProcWidgetMode::Normal);
+        proc.update_displayed_process_data(&data_collection);
+        assert_eq!(proc.row_states.len(), proc.table_data.data.len());
+        assert_eq!(stopped_names(&proc), vec!["b"]);
+
+        // A group is stopped if any of its processes are.
+        let mut proc = proc_widget(ProcWidgetMode::Grouped);
+        proc.update_displayed_process_data(&data_collection);
+        assert_eq!(proc.table_data.data.len(), 3);
+        assert_eq!(stopped_names(&proc), vec!["b"]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn pss_column() {
+        let proc = proc_widget(ProcWidgetMode::Normal);
+        assert!(proc.table_state.columns[ProcWidget::PSS].is_hidden);
+
+        let proc = ProcWidget::init(
+            ProcWidgetMode::Normal,
+            ProcWidgetOptions {
+                show_pss: true,
+                ..Default::default()
+            },
+        );
+        assert!(!proc.table_state.columns[ProcWidget::PSS].is_hidden);
+    }
+
+    #[test]
+    fn cpu_history_column() {
+        let proc = proc_widget(ProcWidgetMode::Normal);
+        assert!(proc.table_state.columns[ProcWidget::CPU_HISTORY].is_hidden);
+
+        let proc = ProcWidget::init(
+            ProcWidgetMode::Normal,
+            ProcWidgetOptions {
+                show_cpu_history: true,
+                ..Default::default()
+            },
+        );
+        assert!(!proc.table_state.columns[ProcWidget::CPU_HISTORY].is_hidden);
+    }
+
     #[test]
     fn assert_correct_columns() {
         #[track_caller]
@@ -1104,7 +1761,16 @@ mod test {
             let is_command = is_cmd;
             let show_percentage = !mem_as_val;
 
-            let proc = ProcWidget::init(mode, false, false, false, mem_as_val, is_command);
+            let proc = ProcWidget::init(
+                mode,
+                ProcWidgetOptions {
+                    show_memory_as_values: mem_as_val,
+                    is_command,
+                    show_pss: true,
+                    show_cpu_history: true,
+                    ..Default::default()
+                },
+            );
             let columns = &proc.table_state.columns;
 
             assert_eq!(
@@ -1150,6 +1816,43 @@ mod test {
                 columns[ProcWidget::STATE].header,
                 ProcWidgetColumn::State
             ));
+            assert!(matches!(
+                columns[ProcWidget::VIRT].header,
+                ProcWidgetColumn::VirtualMemory
+            ));
+            assert!(matches!(
+                columns[ProcWidget::RSS].header,
+                ProcWidgetColumn::ResidentMemory
+            ));
+            #[cfg(target_os = "linux")]
+            {
+                assert!(matches!(
+                    columns[ProcWidget::SHARED].header,
+                    ProcWidgetColumn::SharedMemory
+                ));
+                assert!(matches!(
+                    columns[ProcWidget::SWAP].header,
+                    ProcWidgetColumn::SwapMemory
+                ));
+                assert!(matches!(
+                    columns[ProcWidget::PSS].header,
+                    ProcWidgetColumn::ProportionalMemory
+                ));
+            }
+            assert!(matches!(
+                columns[ProcWidget::CPU_HISTORY].header,
+                ProcWidgetColumn::CpuHistory
+            ));
+            #[cfg(target_os = "linux")]
+            {
+                assert!(matches!(
+                    columns[ProcWidget::CGROUP].header,
+                    ProcWidgetColumn::Cgroup
+                ));
+                assert_eq!(columns.len(), ProcWidget::CGROUP + 1);
+            }
+            #[cfg(not(target_os = "linux"))]
+            assert_eq!(columns.len(), ProcWidget::CPU_HISTORY + 1);
         }
 
         test_columns(ProcWidgetMode::Grouped, true, true);
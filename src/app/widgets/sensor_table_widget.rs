@@ -0,0 +1,30 @@
+use crate::components::text_table::{
+    CellContent, TableComponentColumn, TableComponentState, WidthBounds,
+};
+
+pub struct SensorWidgetState {
+    pub table_state: TableComponentState,
+}
+
+impl Default for SensorWidgetState {
+    fn default() -> Self {
+        const SENSOR_HEADERS: [&str; 3] = ["Sensor", "Type", "Value"];
+        const WIDTHS: [WidthBounds; SENSOR_HEADERS.len()] = [
+            WidthBounds::soft_from_str(SENSOR_HEADERS[0], Some(0.7)),
+            WidthBounds::Hard(7),
+            WidthBounds::Hard(10),
+        ];
+
+        SensorWidgetState {
+            table_state: TableComponentState::new(
+                SENSOR_HEADERS
+                    .iter()
+                    .zip(WIDTHS)
+                    .map(|(header, width)| {
+                        TableComponentColumn::new_custom(CellContent::new(*header, None), width)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
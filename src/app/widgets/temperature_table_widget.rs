@@ -8,10 +8,12 @@ pub struct TempWidgetState {
 
 impl Default for TempWidgetState {
     fn default() -> Self {
-        const TEMP_HEADERS: [&str; 2] = ["Sensor", "Temp"];
+        const TEMP_HEADERS: [&str; 4] = ["Sensor", "Temp", "High", "Crit"];
         const WIDTHS: [WidthBounds; TEMP_HEADERS.len()] = [
-            WidthBounds::soft_from_str(TEMP_HEADERS[0], Some(0.8)),
+            WidthBounds::soft_from_str(TEMP_HEADERS[0], Some(0.6)),
             WidthBounds::soft_from_str(TEMP_HEADERS[1], None),
+            WidthBounds::soft_from_str(TEMP_HEADERS[2], None),
+            WidthBounds::soft_from_str(TEMP_HEADERS[3], None),
         ];
 
         TempWidgetState {
@@ -10,6 +10,7 @@ use std::{
     boxed::Box,
     io::{stdout, Write},
     panic,
+    path::Path,
     sync::{
         atomic::{AtomicBool, Ordering},
         mpsc, Arc, Condvar, Mutex,
@@ -33,8 +34,13 @@ fn main() -> Result<()> {
         utils::logging::init_logger(log::LevelFilter::Debug, std::ffi::OsStr::new("debug.log"))?;
     }
 
-    // Check if the current environment is in a terminal.
-    check_if_terminal();
+    // Check if the current environment is in a terminal. This doesn't matter if we're only printing a snapshot
+    // or serving metrics.
+    let is_snapshot = matches.is_present("snapshot");
+    let metrics_addr = matches.value_of("serve_metrics");
+    if !is_snapshot && metrics_addr.is_none() {
+        check_if_terminal();
+    }
 
     // Read from config file.
     let config_path = read_config(matches.value_of("config_location"))
@@ -57,9 +63,70 @@ fn main() -> Result<()> {
         config_path,
     )?;
 
+    if is_snapshot {
+        return print_snapshot(&app.app_config_fields, app.filters.clone())
+            .context("Unable to print a snapshot of the collected data.");
+    }
+
+    if let Some(metrics_addr) = metrics_addr {
+        let num_processes = match matches.value_of("metrics_processes") {
+            Some(num_processes) => num_processes
+                .parse::<usize>()
+                .context("The number of processes to export must be a non-negative integer.")?,
+            None => 0,
+        };
+        let options = metrics::MetricsOptions {
+            num_processes,
+            temperature_type: app.app_config_fields.temperature_type.clone(),
+        };
+
+        return metrics::serve_metrics(
+            metrics_addr,
+            &app.app_config_fields,
+            app.filters.clone(),
+            options,
+        )
+        .context("Unable to serve metrics.");
+    }
+
+    // Read the recording to replay or open the file to record to before touching the terminal, so errors are visible.
+    let replay_frames = match matches.value_of("replay") {
+        Some(path) => Some(
+            replay::read_recording(Path::new(path))
+                .context("Unable to read the given recording.")?,
+        ),
+        None => None,
+    };
+    let recorder = match matches.value_of("record") {
+        Some(path) => Some(
+            replay::Recorder::new(Path::new(path))
+                .context("Unable to open the given file to record to.")?,
+        ),
+        None => None,
+    };
+
+    let csv_log = match (matches.value_of("log_csv"), matches.value_of("log_tsv")) {
+        (Some(dir), _) => Some((dir, ',')),
+        (None, Some(dir)) => Some((dir, '\t')),
+        (None, None) => None,
+    }
+    .map(|(dir, delimiter)| {
+        csv_log::CsvLog::new(
+            Path::new(dir),
+            delimiter,
+            app.app_config_fields.temperature_type.clone(),
+        )
+    })
+    .transpose()
+    .context("Unable to open the given directory to log to.")?;
+
     // Create painter and set colours.
-    let mut painter =
-        canvas::Painter::init(widget_layout, &config, get_color_scheme(&matches, &config)?)?;
+    let mut painter = canvas::Painter::init(
+        widget_layout,
+        &config,
+        get_color_scheme(&matches, &config)?,
+        &app.key_bindings,
+    )?;
 
     // Create termination mutex and cvar
     #[allow(clippy::mutex_atomic)]
@@ -95,17 +162,50 @@ fn main() -> Result<()> {
         })
     };
 
+    // Logging is done on its own thread, and is sent harvests straight from the collection thread, so it never
+    // holds up drawing.
+    let csv_log_sender = csv_log.map(|csv_log| {
+        let (csv_log_sender, csv_log_receiver) = mpsc::channel();
+        csv_log::create_csv_log_thread(csv_log, csv_log_receiver);
+        csv_log_sender
+    });
+
+    // Recording is also done on its own thread, and is sent harvests the same way.
+    let (record_thread, recording_sender) = match recorder {
+        Some(recorder) => {
+            let (recording_sender, recording_receiver) = mpsc::channel();
+            (
+                Some(replay::create_record_thread(recorder, recording_receiver)),
+                Some(recording_sender),
+            )
+        }
+        None => (None, None),
+    };
+
     // Event loop
     let (collection_thread_ctrl_sender, collection_thread_ctrl_receiver) = mpsc::channel();
-    let _collection_thread = create_collection_thread(
-        sender,
-        collection_thread_ctrl_receiver,
-        thread_termination_lock.clone(),
-        thread_termination_cvar.clone(),
-        &app.app_config_fields,
-        app.filters.clone(),
-        app.used_widgets.clone(),
-    );
+    let _collection_thread = match replay_frames {
+        Some(frames) => {
+            app.is_replaying = true;
+            replay::create_replay_thread(
+                sender,
+                collection_thread_ctrl_receiver,
+                thread_termination_lock.clone(),
+                frames,
+                app.app_config_fields.default_time_value,
+            )
+        }
+        None => create_collection_thread(
+            sender,
+            collection_thread_ctrl_receiver,
+            thread_termination_lock.clone(),
+            thread_termination_cvar.clone(),
+            &app.app_config_fields,
+            app.filters.clone(),
+            app.used_widgets.clone(),
+            recording_sender.into_iter().chain(csv_log_sender).collect(),
+        ),
+    };
 
     // Set up up tui and crossterm
     let mut stdout_val = stdout();
@@ -140,6 +240,12 @@ fn main() -> Result<()> {
                     handle_mouse_event(event, &mut app);
                     update_data(&mut app);
                 }
+                BottomEvent::ProcessDetails(process_details) => {
+                    // Details for a process that is no longer being looked at are dropped.
+                    if app.process_details_state.pid() == Some(process_details.pid) {
+                        app.data_collection.process_details = Some(*process_details);
+                    }
+                }
                 BottomEvent::Update(data) => {
                     app.data_collection.eat_data(data);
 
@@ -173,16 +279,61 @@ fn main() -> Result<()> {
                             if let Some(total_tx_display) = network_data.total_tx_display {
                                 app.converted_data.total_tx_display = total_tx_display;
                             }
+                            app.converted_data.interface_graph_data = convert_interface_data_points(
+                                &app.data_collection,
+                                &app.net_state.graphed_interfaces,
+                                &app.app_config_fields.network_scale_type,
+                                &app.app_config_fields.network_unit_type,
+                                app.app_config_fields.network_use_binary_prefix,
+                            );
+
+                            let (net_interface_data, net_interface_names) =
+                                convert_net_interface_row(
+                                    &app.data_collection,
+                                    &app.app_config_fields.network_unit_type,
+                                    app.app_config_fields.network_use_binary_prefix,
+                                );
+                            app.converted_data.net_interface_data = net_interface_data;
+                            app.converted_data.net_interface_names = net_interface_names;
                         }
 
                         // Disk
                         if app.used_widgets.use_disk {
                             app.converted_data.disk_data = convert_disk_row(&app.data_collection);
+                            app.converted_data.disk_io_data =
+                                convert_disk_io_data_points(&app.data_collection);
+                        }
+
+                        // Pressure
+                        if app.used_widgets.use_pressure {
+                            app.converted_data.pressure_data =
+                                convert_pressure_data_points(&app.data_collection);
+                        }
+
+                        // CPU frequencies
+                        if app.used_widgets.use_cpu_freq {
+                            app.converted_data.cpu_frequency_data =
+                                convert_cpu_frequency_data_points(&app.data_collection);
+                        }
+
+                        // Cgroups
+                        if app.used_widgets.use_cgroup {
+                            app.cgroup_state.force_update = true;
+                        }
+
+                        // Sensors
+                        if app.used_widgets.use_sensors {
+                            app.converted_data.sensor_data =
+                                convert_sensor_rows(&app.data_collection);
                         }
 
                         // Temperatures
                         if app.used_widgets.use_temp {
-                            app.converted_data.temp_sensor_data = convert_temp_row(&app);
+                            let (temp_sensor_data, temp_sensor_levels) = convert_temp_row(&app);
+                            app.converted_data.temp_sensor_data = temp_sensor_data;
+                            app.converted_data.temp_sensor_levels = temp_sensor_levels;
+                            app.converted_data.temp_graph_data =
+                                convert_temp_graph_data_points(&app);
                         }
 
                         // Memory
@@ -196,6 +347,8 @@ fn main() -> Result<()> {
 
                             app.converted_data.mem_labels = memory_labels;
                             app.converted_data.swap_labels = swap_labels;
+                            app.converted_data.mem_breakdown =
+                                convert_mem_breakdown(&app.data_collection);
                         }
 
                         if app.used_widgets.use_cpu {
@@ -204,6 +357,8 @@ fn main() -> Result<()> {
                             convert_cpu_data_points(
                                 &app.data_collection,
                                 &mut app.converted_data.cpu_data,
+                                app.cpu_state.grouping,
+                                &app.cpu_state.expanded_groups,
                             );
                             app.converted_data.load_avg_data = app.data_collection.load_avg_harvest;
                         }
@@ -246,5 +401,10 @@ fn main() -> Result<()> {
 
     cleanup_terminal(&mut terminal)?;
 
+    // Wait for the rest of the recording to be written out.
+    if let Some(record_thread) = record_thread {
+        let _ = record_thread.join();
+    }
+
     Ok(())
 }
@@ -4,7 +4,7 @@ use std::str::FromStr;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    text::{Span, Spans},
+    text::{Span, Spans, Text},
     widgets::Paragraph,
     Frame, Terminal,
 };
@@ -14,7 +14,8 @@ use canvas_colours::*;
 use crate::{
     app::{
         self,
-        layout_manager::{BottomColRow, BottomLayout, BottomWidgetType},
+        keybindings::KeyBindings,
+        layout_manager::{BottomColRow, BottomLayout, BottomWidgetType, WidgetDirection},
         App,
     },
     constants::*,
@@ -64,6 +65,7 @@ pub struct Painter {
     pub colours: CanvasColours,
     height: u16,
     width: u16,
+    help_text: Vec<Vec<String>>,
     styled_help_text: Vec<Spans<'static>>,
     is_mac_os: bool, // TODO: This feels out of place...
 
@@ -79,6 +81,7 @@ pub struct Painter {
 impl Painter {
     pub fn init(
         widget_layout: BottomLayout, config: &Config, colour_scheme: ColourScheme,
+        key_bindings: &KeyBindings,
     ) -> anyhow::Result<Self> {
         // Now for modularity; we have to also initialize the base layouts!
         // We want to do this ONCE and reuse; after this we can just construct
@@ -151,6 +154,7 @@ impl Painter {
             colours: CanvasColours::default(),
             height: 0,
             width: 0,
+            help_text: key_bindings.help_text(HELP_TEXT),
             styled_help_text: Vec::default(),
             is_mac_os: cfg!(target_os = "macos"),
             row_constraints,
@@ -181,6 +185,126 @@ impl Painter {
         }
     }
 
+    /// The styles of the `index`-th of several pairs of graphed lines, like the RX and TX lines of an interface or
+    /// the read and write lines of a disk. Each pair gets the next two CPU colours.
+    pub fn line_pair_styles(&self, index: usize) -> (tui::style::Style, tui::style::Style) {
+        let mut styles = self
+            .colours
+            .cpu_colour_styles
+            .iter()
+            .cycle()
+            .skip(2 * index);
+        (
+            styles.next().copied().unwrap_or(self.colours.rx_style),
+            styles.next().copied().unwrap_or(self.colours.tx_style),
+        )
+    }
+
+    /// Draws a graph with its legend beside it, taking up `legend_ratio` of the width. The legend's widget ID is the
+    /// graph's plus one, and the legend is hidden if there isn't enough room for it.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_graph_with_legend<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+        legend_ratio: f64, draw_graph: fn(&Self, &mut Frame<'_, B>, &mut App, Rect, u64),
+        draw_legend: fn(&Self, &mut Frame<'_, B>, &mut App, Rect, u64),
+    ) {
+        let legend_width = (draw_loc.width as f64 * legend_ratio) as u16;
+
+        if legend_width < 6 {
+            // Skip drawing legend
+            if app_state.current_widget.widget_id == (widget_id + 1) {
+                if app_state.app_config_fields.left_legend {
+                    app_state.move_widget_selection(&WidgetDirection::Right);
+                } else {
+                    app_state.move_widget_selection(&WidgetDirection::Left);
+                }
+            }
+            draw_graph(self, f, app_state, draw_loc, widget_id);
+            app_state.set_graph_legend_hidden(widget_id, true);
+
+            // Update draw loc in widget map
+            if app_state.should_get_widget_bounds() {
+                if let Some(bottom_widget) = app_state.widget_map.get_mut(&widget_id) {
+                    bottom_widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                    bottom_widget.bottom_right_corner =
+                        Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+                }
+            }
+        } else {
+            let graph_width = draw_loc.width - legend_width;
+            let (graph_index, legend_index, constraints) =
+                if app_state.app_config_fields.left_legend {
+                    (
+                        1,
+                        0,
+                        [
+                            Constraint::Length(legend_width),
+                            Constraint::Length(graph_width),
+                        ],
+                    )
+                } else {
+                    (
+                        0,
+                        1,
+                        [
+                            Constraint::Length(graph_width),
+                            Constraint::Length(legend_width),
+                        ],
+                    )
+                };
+
+            let partitioned_draw_loc = Layout::default()
+                .margin(0)
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .split(draw_loc);
+
+            draw_graph(
+                self,
+                f,
+                app_state,
+                partitioned_draw_loc[graph_index],
+                widget_id,
+            );
+            draw_legend(
+                self,
+                f,
+                app_state,
+                partitioned_draw_loc[legend_index],
+                widget_id + 1,
+            );
+
+            if app_state.should_get_widget_bounds() {
+                // Update draw loc in widget map
+                if let Some(graph_widget) = app_state.widget_map.get_mut(&widget_id) {
+                    graph_widget.top_left_corner = Some((
+                        partitioned_draw_loc[graph_index].x,
+                        partitioned_draw_loc[graph_index].y,
+                    ));
+                    graph_widget.bottom_right_corner = Some((
+                        partitioned_draw_loc[graph_index].x
+                            + partitioned_draw_loc[graph_index].width,
+                        partitioned_draw_loc[graph_index].y
+                            + partitioned_draw_loc[graph_index].height,
+                    ));
+                }
+
+                if let Some(legend_widget) = app_state.widget_map.get_mut(&(widget_id + 1)) {
+                    legend_widget.top_left_corner = Some((
+                        partitioned_draw_loc[legend_index].x,
+                        partitioned_draw_loc[legend_index].y,
+                    ));
+                    legend_widget.bottom_right_corner = Some((
+                        partitioned_draw_loc[legend_index].x
+                            + partitioned_draw_loc[legend_index].width,
+                        partitioned_draw_loc[legend_index].y
+                            + partitioned_draw_loc[legend_index].height,
+                    ));
+                }
+            }
+        }
+    }
+
     fn generate_config_colours(&mut self, config: &Config) -> anyhow::Result<()> {
         if let Some(colours) = &config.colors {
             self.colours.set_colours_from_palette(colours)?;
@@ -228,34 +352,75 @@ impl Painter {
         let mut styled_help_spans = Vec::new();
 
         // Init help text:
-        (*HELP_TEXT).iter().enumerate().for_each(|(itx, section)| {
-            if itx == 0 {
-                styled_help_spans.extend(
-                    section
-                        .iter()
-                        .map(|&text| Span::styled(text, self.colours.text_style))
-                        .collect::<Vec<_>>(),
-                );
-            } else {
-                // Not required check but it runs only a few times... so whatever ig, prevents me from
-                // being dumb and leaving a help text section only one line long.
-                if section.len() > 1 {
-                    styled_help_spans.push(Span::raw(""));
-                    styled_help_spans
-                        .push(Span::styled(section[0], self.colours.table_header_style));
+        self.help_text
+            .iter()
+            .enumerate()
+            .for_each(|(itx, section)| {
+                if itx == 0 {
                     styled_help_spans.extend(
-                        section[1..]
+                        section
                             .iter()
-                            .map(|&text| Span::styled(text, self.colours.text_style))
+                            .map(|text| Span::styled(text.clone(), self.colours.text_style))
                             .collect::<Vec<_>>(),
                     );
+                } else {
+                    // Not required check but it runs only a few times... so whatever ig, prevents me from
+                    // being dumb and leaving a help text section only one line long.
+                    if section.len() > 1 {
+                        styled_help_spans.push(Span::raw(""));
+                        styled_help_spans.push(Span::styled(
+                            section[0].clone(),
+                            self.colours.table_header_style,
+                        ));
+                        styled_help_spans.extend(
+                            section[1..]
+                                .iter()
+                                .map(|text| Span::styled(text.clone(), self.colours.text_style))
+                                .collect::<Vec<_>>(),
+                        );
+                    }
                 }
-            }
-        });
+            });
 
         self.styled_help_text = styled_help_spans.into_iter().map(Spans::from).collect();
     }
 
+    /// Gets where to draw a dialog showing `text` in the middle of the terminal, sized like the kill dialog but
+    /// tall enough for all of its lines.
+    fn get_centered_dialog_loc(terminal_size: Rect, text: Option<&Text<'_>>) -> Rect {
+        let terminal_width = terminal_size.width;
+        let terminal_height = terminal_size.height;
+
+        let text_width = if terminal_width < 100 {
+            terminal_width * 90 / 100
+        } else {
+            terminal_width * 50 / 100
+        };
+        let text_height = text.map(|text| text.height() as u16 + 2).unwrap_or(7);
+
+        let vertical_bordering = terminal_height.saturating_sub(text_height) / 2;
+        let vertical_dialog_chunk = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(vertical_bordering),
+                Constraint::Length(text_height),
+                Constraint::Length(vertical_bordering),
+            ])
+            .split(terminal_size);
+
+        let horizontal_bordering = terminal_width.saturating_sub(text_width) / 2;
+        let middle_dialog_chunk = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(horizontal_bordering),
+                Constraint::Length(text_width),
+                Constraint::Length(horizontal_bordering),
+            ])
+            .split(vertical_dialog_chunk[1]);
+
+        middle_dialog_chunk[1]
+    }
+
     fn draw_frozen_indicator<B: Backend>(&self, f: &mut Frame<'_, B>, draw_loc: Rect) {
         f.render_widget(
             Paragraph::new(Span::styled(
@@ -311,7 +476,7 @@ impl Painter {
             }
 
             if app_state.help_dialog_state.is_showing_help {
-                let gen_help_len = GENERAL_HELP_TEXT.len() as u16 + 3;
+                let gen_help_len = self.help_text[1].len() as u16 + 3;
                 let border_len = terminal_height.saturating_sub(gen_help_len) / 2;
                 let vertical_dialog_chunk = Layout::default()
                     .direction(Direction::Vertical)
@@ -364,6 +529,15 @@ impl Painter {
                     22
                 };
 
+                // Make room for any processes listed below the usual blank line and question.
+                let listed_height = match &dd_text {
+                    Some(dd_text) if app_state.dd_err.is_none() => {
+                        dd_text.height().saturating_sub(2) as u16
+                    }
+                    _ => 0,
+                };
+                let text_height = (text_height + listed_height).min(terminal_height);
+
                 // let (text_width, text_height) = if let Some(dd_text) = &dd_text {
                 //     let width = if current_width < 100 {
                 //         current_width * 90 / 100
@@ -418,6 +592,53 @@ impl Painter {
                 // This is a bit nasty, but it works well... I guess.
                 app_state.delete_dialog_state.is_showing_dd =
                     self.draw_dd_dialog(f, dd_text, app_state, middle_dialog_chunk[1]);
+            } else if app_state.priority_dialog_state.is_showing_priority {
+                let priority_text = self.get_priority_spans(app_state);
+                let dialog_loc =
+                    Self::get_centered_dialog_loc(terminal_size, priority_text.as_ref());
+
+                app_state.priority_dialog_state.is_showing_priority =
+                    self.draw_priority_dialog(f, priority_text, app_state, dialog_loc);
+            } else if app_state.affinity_dialog_state.is_showing_affinity {
+                Self::update_affinity_grid(
+                    app_state,
+                    Self::get_centered_dialog_loc(terminal_size, None).width,
+                    terminal_height,
+                );
+                let affinity_text = self.get_affinity_spans(app_state);
+                let dialog_loc =
+                    Self::get_centered_dialog_loc(terminal_size, affinity_text.as_ref());
+
+                app_state.affinity_dialog_state.is_showing_affinity =
+                    self.draw_affinity_dialog(f, affinity_text, app_state, dialog_loc);
+            } else if app_state.process_details_state.is_showing_details {
+                let vertical_dialog_chunk = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Percentage(5),
+                        Constraint::Percentage(90),
+                        Constraint::Percentage(5),
+                    ])
+                    .split(terminal_size);
+
+                let middle_dialog_chunk = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints(if terminal_width < 100 {
+                        [
+                            Constraint::Percentage(0),
+                            Constraint::Percentage(100),
+                            Constraint::Percentage(0),
+                        ]
+                    } else {
+                        [
+                            Constraint::Percentage(10),
+                            Constraint::Percentage(80),
+                            Constraint::Percentage(10),
+                        ]
+                    })
+                    .split(vertical_dialog_chunk[1]);
+
+                self.draw_process_details_dialog(f, app_state, middle_dialog_chunk[1]);
             } else if app_state.is_expanded {
                 if let Some(frozen_draw_loc) = frozen_draw_loc {
                     self.draw_frozen_indicator(f, frozen_draw_loc);
@@ -435,6 +656,39 @@ impl Painter {
                         rect[0],
                         app_state.current_widget.widget_id - 1,
                     ),
+                    DiskIo => {
+                        self.draw_disk_io(f, app_state, rect[0], app_state.current_widget.widget_id)
+                    }
+                    DiskIoLegend => self.draw_disk_io(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id - 1,
+                    ),
+                    TempGraph => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    TempGraphLegend => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id - 1,
+                    ),
+                    Pressure => self.draw_pressure_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    CpuFreq => self.draw_cpu_freq_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
                     Mem | BasicMem => self.draw_memory_graph(
                         f,
                         app_state,
@@ -448,6 +702,27 @@ impl Painter {
                         true,
                         app_state.current_widget.widget_id,
                     ),
+                    NetInterfaces => self.draw_net_interface_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    Cgroups => self.draw_cgroup_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    Sensors => self.draw_sensor_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
                     Temp => self.draw_temp_table(
                         f,
                         app_state,
@@ -502,12 +777,20 @@ impl Painter {
                     }
                 };
 
+                // Two rows for RAM and swap, and then the memory breakdown two parts to a row.
+                let mem_height = 2 + app_state
+                    .converted_data
+                    .mem_breakdown
+                    .as_ref()
+                    .map(|breakdown| breakdown.details.chunks(2).len())
+                    .unwrap_or(0) as u16;
+
                 let vertical_chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .margin(0)
                     .constraints([
                         Constraint::Length(cpu_height),
-                        Constraint::Length(2),
+                        Constraint::Length(mem_height),
                         Constraint::Length(2),
                         Constraint::Min(5),
                     ])
@@ -700,6 +983,37 @@ impl Painter {
                     Disk => {
                         self.draw_disk_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
                     }
+                    DiskIo => self.draw_disk_io(f, app_state, *widget_draw_loc, widget.widget_id),
+                    TempGraph => {
+                        self.draw_temp_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    Pressure => {
+                        self.draw_pressure_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    CpuFreq => {
+                        self.draw_cpu_freq_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    NetInterfaces => self.draw_net_interface_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
+                    Cgroups => self.draw_cgroup_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
+                    Sensors => self.draw_sensor_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
                     Proc => self.draw_process_widget(
                         f,
                         app_state,
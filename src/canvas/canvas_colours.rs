@@ -1,4 +1,4 @@
-use crate::{options::ConfigColours, utils::error};
+use crate::{data_conversion::CPU_TIME_KINDS, options::ConfigColours, utils::error};
 use anyhow::Context;
 use colour_utils::*;
 use tui::style::{Color, Style};
@@ -11,6 +11,8 @@ pub struct CanvasColours {
     pub table_header_style: Style,
     pub ram_style: Style,
     pub swap_style: Style,
+    pub buffers_style: Style,
+    pub cache_style: Style,
     pub rx_style: Style,
     pub tx_style: Style,
     pub total_rx_style: Style,
@@ -18,6 +20,10 @@ pub struct CanvasColours {
     pub all_colour_style: Style,
     pub avg_colour_style: Style,
     pub cpu_colour_styles: Vec<Style>,
+    /// The colours of each kind of CPU time, in the order of [`CPU_TIME_KINDS`].
+    ///
+    /// [`CPU_TIME_KINDS`]: crate::data_conversion::CPU_TIME_KINDS
+    pub cpu_time_styles: Vec<Style>,
     pub border_style: Style,
     pub highlighted_border_style: Style,
     pub text_style: Style,
@@ -26,6 +32,10 @@ pub struct CanvasColours {
     pub high_battery_colour: Style,
     pub medium_battery_colour: Style,
     pub low_battery_colour: Style,
+    pub temp_warning_style: Style,
+    pub temp_critical_style: Style,
+    pub stopped_process_style: Style,
+    pub marked_process_style: Style,
     pub invalid_query_style: Style,
     pub disabled_text_style: Style,
 }
@@ -43,6 +53,8 @@ impl Default for CanvasColours {
             table_header_style: Style::default().fg(STANDARD_HIGHLIGHT_COLOUR),
             ram_style: Style::default().fg(STANDARD_FIRST_COLOUR),
             swap_style: Style::default().fg(STANDARD_SECOND_COLOUR),
+            buffers_style: Style::default().fg(Color::LightCyan),
+            cache_style: Style::default().fg(Color::LightGreen),
             rx_style: Style::default().fg(STANDARD_FIRST_COLOUR),
             tx_style: Style::default().fg(STANDARD_SECOND_COLOUR),
             total_rx_style: Style::default().fg(STANDARD_THIRD_COLOUR),
@@ -61,6 +73,13 @@ impl Default for CanvasColours {
                 Style::default().fg(Color::Blue),
                 Style::default().fg(Color::Red),
             ],
+            cpu_time_styles: vec![
+                Style::default().fg(Color::LightGreen),
+                Style::default().fg(Color::LightRed),
+                Style::default().fg(Color::LightYellow),
+                Style::default().fg(Color::LightMagenta),
+                Style::default().fg(Color::LightBlue),
+            ],
             border_style: Style::default().fg(text_colour),
             highlighted_border_style: Style::default().fg(STANDARD_HIGHLIGHT_COLOUR),
             text_style: Style::default().fg(text_colour),
@@ -69,6 +88,10 @@ impl Default for CanvasColours {
             high_battery_colour: Style::default().fg(Color::Green),
             medium_battery_colour: Style::default().fg(Color::Yellow),
             low_battery_colour: Style::default().fg(Color::Red),
+            temp_warning_style: Style::default().fg(Color::Yellow),
+            temp_critical_style: Style::default().fg(Color::Red),
+            stopped_process_style: Style::default().fg(Color::Magenta),
+            marked_process_style: Style::default().fg(Color::LightYellow),
             invalid_query_style: Style::default().fg(tui::style::Color::Red),
             disabled_text_style: Style::default().fg(Color::DarkGray),
         }
@@ -107,6 +130,11 @@ impl CanvasColours {
                 .context("Update 'cpu_core_colors' in your config file..")?;
         }
 
+        if let Some(cpu_time_colors) = &colours.cpu_time_colors {
+            self.set_cpu_time_colours(cpu_time_colors)
+                .context("Update 'cpu_time_colors' in your config file.")?;
+        }
+
         if let Some(ram_color) = &colours.ram_color {
             self.set_ram_colour(ram_color)
                 .context("Update 'ram_color' in your config file..")?;
@@ -117,6 +145,16 @@ impl CanvasColours {
                 .context("Update 'swap_color' in your config file..")?;
         }
 
+        if let Some(buffers_color) = &colours.buffers_color {
+            self.set_buffers_colour(buffers_color)
+                .context("Update 'buffers_color' in your config file.")?;
+        }
+
+        if let Some(cache_color) = &colours.cache_color {
+            self.set_cache_colour(cache_color)
+                .context("Update 'cache_color' in your config file.")?;
+        }
+
         if let Some(rx_color) = &colours.rx_color {
             self.set_rx_colour(rx_color)
                 .context("Update 'rx_color' in your config file..")?;
@@ -167,6 +205,26 @@ impl CanvasColours {
                 .context("Update 'low_battery_color' in your config file.")?;
         }
 
+        if let Some(temp_warning_color) = &colours.temp_warning_color {
+            self.set_temp_warning_colour(temp_warning_color)
+                .context("Update 'temp_warning_color' in your config file.")?;
+        }
+
+        if let Some(temp_critical_color) = &colours.temp_critical_color {
+            self.set_temp_critical_colour(temp_critical_color)
+                .context("Update 'temp_critical_color' in your config file.")?;
+        }
+
+        if let Some(stopped_process_color) = &colours.stopped_process_color {
+            self.set_stopped_process_colour(stopped_process_color)
+                .context("Update 'stopped_process_color' in your config file.")?;
+        }
+
+        if let Some(marked_process_color) = &colours.marked_process_color {
+            self.set_marked_process_colour(marked_process_color)
+                .context("Update 'marked_process_color' in your config file.")?;
+        }
+
         if let Some(disabled_text_color) = &colours.disabled_text_color {
             self.set_disabled_text_colour(disabled_text_color)
                 .context("Update 'disabled_text_color' in your config file.")?;
@@ -220,6 +278,16 @@ impl CanvasColours {
         Ok(())
     }
 
+    pub fn set_buffers_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.buffers_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_cache_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.cache_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
     pub fn set_rx_colour(&mut self, colour: &str) -> error::Result<()> {
         self.rx_style = get_style_from_config(colour)?;
         Ok(())
@@ -258,6 +326,23 @@ impl CanvasColours {
         Ok(())
     }
 
+    pub fn set_cpu_time_colours(&mut self, colours: &[String]) -> error::Result<()> {
+        if colours.len() != CPU_TIME_KINDS.len() {
+            return Err(error::BottomError::ConfigError(format!(
+                "expected {} colours, one for each of {}, but got {}.",
+                CPU_TIME_KINDS.len(),
+                CPU_TIME_KINDS.join(", "),
+                colours.len()
+            )));
+        }
+
+        self.cpu_time_styles = colours
+            .iter()
+            .map(|colour| get_style_from_config(colour))
+            .collect::<error::Result<Vec<Style>>>()?;
+        Ok(())
+    }
+
     pub fn set_scroll_entry_text_color(&mut self, colour: &str) -> error::Result<()> {
         self.currently_selected_text_colour = get_colour_from_config(colour)?;
         self.currently_selected_text_style = Style::default()
@@ -298,4 +383,24 @@ impl CanvasColours {
         self.low_battery_colour = get_style_from_config(colour)?;
         Ok(())
     }
+
+    pub fn set_temp_warning_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.temp_warning_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_temp_critical_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.temp_critical_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_stopped_process_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.stopped_process_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_marked_process_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.marked_process_style = get_style_from_config(colour)?;
+        Ok(())
+    }
 }
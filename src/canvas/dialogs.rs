@@ -1,2 +1,36 @@
+use tui::{
+    text::{Span, Spans},
+    widgets::{Block, Borders},
+};
+
+use crate::canvas::Painter;
+
+pub mod affinity_dialog;
 pub mod dd_dialog;
 pub mod help_dialog;
+pub mod priority_dialog;
+pub mod process_details_dialog;
+
+impl Painter {
+    /// The border of a dialog `width` wide, with `title` on the left of its top edge and how to close it on the
+    /// right.
+    pub fn dialog_block(&self, title: &str, width: u16) -> Block<'static> {
+        const CLOSE_HINT: &str = " Esc to close ";
+
+        let title = format!(" {} ", title);
+        let line_len =
+            usize::from(width).saturating_sub(title.chars().count() + CLOSE_HINT.len() + 4);
+
+        Block::default()
+            .title(Spans::from(vec![
+                Span::styled(title, self.colours.widget_title_style),
+                Span::styled(
+                    format!("─{}─{}", "─".repeat(line_len), CLOSE_HINT),
+                    self.colours.border_style,
+                ),
+            ]))
+            .style(self.colours.border_style)
+            .borders(Borders::ALL)
+            .border_style(self.colours.border_style)
+    }
+}
@@ -0,0 +1,180 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    terminal::Frame,
+    text::{Span, Spans, Text},
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::{
+    app::{AffinityCore, App, ProcessSelection},
+    canvas::Painter,
+};
+
+/// The space between two cores in the grid.
+const CORE_GAP: usize = 2;
+
+/// How many lines of the dialog are not part of the grid: the borders, the question above it, the settings and
+/// hints below it, and the line saying which rows are shown.
+const NON_GRID_LINES: u16 = 10;
+
+/// The width of a core in the grid, with room for the "[x] " checkbox in front of its name.
+fn affinity_cell_width(cores: &[AffinityCore]) -> usize {
+    cores.iter().map(|core| core.name.len()).max().unwrap_or(0) + 4
+}
+
+impl Painter {
+    /// Works out how many cores fit in a row of a dialog `width` wide, and stores it for moving up and down
+    /// the grid. The grid is then scrolled so the selected core is shown in a dialog at most `height` tall.
+    pub fn update_affinity_grid(app_state: &mut App, width: u16, height: u16) {
+        let state = &mut app_state.affinity_dialog_state;
+        let cell_width = affinity_cell_width(&state.cores);
+        state.columns = std::cmp::max(
+            usize::from(width.saturating_sub(2)) / (cell_width + CORE_GAP),
+            1,
+        );
+        state.visible_rows = usize::from(height.saturating_sub(NON_GRID_LINES));
+        state.scroll_to_selected(state.visible_rows);
+    }
+
+    pub fn get_affinity_spans(&self, app_state: &App) -> Option<Text<'_>> {
+        let state = &app_state.affinity_dialog_state;
+
+        if let Some(affinity_err) = &app_state.dialog_err {
+            return Some(Text::from(vec![
+                Spans::default(),
+                Spans::from("Failed to change the CPU affinity."),
+                Spans::from(affinity_err.clone()),
+                Spans::from("Please press ENTER or ESC to close this dialog."),
+            ]));
+        } else if let Some((name, pids)) = &state.process {
+            if let Some(first_pid) = pids.first() {
+                let mut spans = vec![
+                    Spans::default(),
+                    match state.selection {
+                        ProcessSelection::Grouped => {
+                            if pids.len() != 1 {
+                                Spans::from(format!(
+                                    "Choose the cores {} processes with the name \"{}\" may run on",
+                                    pids.len(),
+                                    name
+                                ))
+                            } else {
+                                Spans::from(format!(
+                                    "Choose the cores 1 process with the name \"{}\" may run on",
+                                    name
+                                ))
+                            }
+                        }
+                        ProcessSelection::Single => Spans::from(format!(
+                            "Choose the cores \"{}\" with PID {} may run on",
+                            name, first_pid
+                        )),
+                        ProcessSelection::Marked => {
+                            if pids.len() != 1 {
+                                Spans::from(format!(
+                                    "Choose the cores {} marked processes may run on",
+                                    pids.len()
+                                ))
+                            } else {
+                                Spans::from("Choose the cores 1 marked process may run on")
+                            }
+                        }
+                    },
+                    Spans::default(),
+                ];
+
+                let cell_width = affinity_cell_width(&state.cores);
+                let columns = std::cmp::max(state.columns, 1);
+                let visible_rows = std::cmp::max(state.visible_rows, 1);
+                let rows = state.rows();
+                let grid = state
+                    .cores
+                    .chunks(columns)
+                    .enumerate()
+                    .skip(state.scroll_row)
+                    .take(visible_rows);
+                spans.extend(grid.map(|(row, cores)| {
+                    let mut cells = Vec::with_capacity(columns * 2);
+                    for (column, core) in cores.iter().enumerate() {
+                        if column != 0 {
+                            cells.push(Span::raw(" ".repeat(CORE_GAP)));
+                        }
+                        let style = if row * columns + column == state.selected_core {
+                            self.colours.currently_selected_text_style
+                        } else {
+                            self.colours.text_style
+                        };
+                        cells.push(Span::styled(
+                            format!(
+                                "[{}] {:<width$}",
+                                if core.is_allowed { 'x' } else { ' ' },
+                                core.name,
+                                width = cell_width - 4
+                            ),
+                            style,
+                        ));
+                    }
+
+                    // Pad out the last row so it lines up with the others once centered.
+                    let missing = columns - cores.len();
+                    if missing > 0 {
+                        cells.push(Span::raw(" ".repeat(missing * (cell_width + CORE_GAP))));
+                    }
+
+                    Spans::from(cells)
+                }));
+
+                if rows > visible_rows {
+                    spans.push(Spans::from(format!(
+                        "Rows {}-{} of {}",
+                        state.scroll_row + 1,
+                        std::cmp::min(state.scroll_row + visible_rows, rows),
+                        rows
+                    )));
+                }
+                spans.push(Spans::default());
+                spans.push(Spans::from(if state.include_threads {
+                    "Apply to: all threads"
+                } else {
+                    "Apply to: main thread only"
+                }));
+                spans.push(Spans::from(
+                    "SPACE to toggle a core, a to toggle all, t to toggle threads.",
+                ));
+                spans.push(Spans::from("Press ENTER to confirm."));
+
+                return Some(Text::from(spans));
+            }
+        }
+
+        None
+    }
+
+    pub fn draw_affinity_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, affinity_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool {
+        if let Some(affinity_text) = affinity_text {
+            let title = if app_state.dialog_err.is_some() {
+                "Error"
+            } else {
+                "CPU Affinity"
+            };
+
+            f.render_widget(
+                Paragraph::new(affinity_text)
+                    .block(self.dialog_block(title, draw_loc.width))
+                    .style(self.colours.text_style)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: false }),
+                draw_loc,
+            );
+
+            return true;
+        }
+
+        // Like the kill dialog, this closes the dialog if the process could not be found.
+        false
+    }
+}
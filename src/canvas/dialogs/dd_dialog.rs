@@ -5,56 +5,103 @@ use tui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     terminal::Frame,
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Paragraph, Wrap},
 };
 
 use crate::{
-    app::{widgets::ProcWidgetMode, App, KillSignal},
+    app::{App, KillSignal, ProcessAction, ProcessSelection},
     canvas::Painter,
 };
 
-const DD_BASE: &str = " Confirm Kill Process ── Esc to close ";
-const DD_ERROR_BASE: &str = " Error ── Esc to close ";
+/// How many marked processes are listed in the dialog at most, so it does not grow past the screen.
+const DD_MAX_LISTED_PROCESSES: usize = 10;
 
 impl Painter {
     pub fn get_dd_spans(&self, app_state: &App) -> Option<Text<'_>> {
         if let Some(dd_err) = &app_state.dd_err {
             return Some(Text::from(vec![
                 Spans::default(),
-                Spans::from("Failed to kill process."),
+                Spans::from(format!(
+                    "Failed to {} process.",
+                    app_state.delete_dialog_state.action.verb()
+                )),
                 Spans::from(dd_err.clone()),
                 Spans::from("Please press ENTER or ESC to close this dialog."),
             ]));
         } else if let Some(to_kill_processes) = app_state.get_to_delete_processes() {
             if let Some(first_pid) = to_kill_processes.1.first() {
-                return Some(Text::from(vec![
-                    Spans::from(""),
-                    if app_state
-                        .proc_state
-                        .widget_states
-                        .get(&app_state.current_widget.widget_id)
-                        .map(|p| matches!(p.mode, ProcWidgetMode::Grouped))
-                        .unwrap_or(false)
-                    {
-                        if to_kill_processes.1.len() != 1 {
-                            Spans::from(format!(
+                let (name, pids) = &to_kill_processes;
+                let question = match (
+                    app_state.delete_dialog_state.action,
+                    app_state.delete_dialog_state.selection,
+                ) {
+                    (ProcessAction::KillTree, ProcessSelection::Grouped) => format!(
+                        "Kill all processes with the name \"{}\" and their descendants ({} in total)?  Press ENTER to confirm.",
+                        name,
+                        pids.len()
+                    ),
+                    (ProcessAction::KillTree, ProcessSelection::Single) => format!(
+                        "Kill process \"{}\" and its descendants ({} in total)?  Press ENTER to confirm.",
+                        name,
+                        pids.len()
+                    ),
+                    (ProcessAction::KillTree, ProcessSelection::Marked) => format!(
+                        "Kill the marked processes and their descendants ({} in total)?  Press ENTER to confirm.",
+                        pids.len()
+                    ),
+                    (_, ProcessSelection::Grouped) => {
+                        if pids.len() != 1 {
+                            format!(
                                 "Kill {} processes with the name \"{}\"?  Press ENTER to confirm.",
-                                to_kill_processes.1.len(),
-                                to_kill_processes.0
-                            ))
+                                pids.len(),
+                                name
+                            )
                         } else {
-                            Spans::from(format!(
+                            format!(
                                 "Kill 1 process with the name \"{}\"?  Press ENTER to confirm.",
-                                to_kill_processes.0
-                            ))
+                                name
+                            )
+                        }
+                    }
+                    (_, ProcessSelection::Single) => format!(
+                        "Kill process \"{}\" with PID {}?  Press ENTER to confirm.",
+                        name, first_pid
+                    ),
+                    (_, ProcessSelection::Marked) => {
+                        if pids.len() != 1 {
+                            format!(
+                                "Kill {} marked processes?  Press ENTER to confirm.",
+                                pids.len()
+                            )
+                        } else {
+                            "Kill 1 marked process?  Press ENTER to confirm.".to_string()
+                        }
+                    }
+                };
+
+                let mut spans = vec![Spans::from(""), Spans::from(question)];
+
+                // Marked processes can be spread all over the table, so list what is about to be killed.
+                if let ProcessSelection::Marked = app_state.delete_dialog_state.selection {
+                    let process_harvest = &app_state.data_collection.process_data.process_harvest;
+                    spans.push(Spans::default());
+                    spans.extend(pids.iter().take(DD_MAX_LISTED_PROCESSES).map(|pid| {
+                        match process_harvest.get(pid) {
+                            Some(process) => {
+                                Spans::from(format!("\"{}\" with PID {}", process.name, pid))
+                            }
+                            None => Spans::from(format!("PID {}", pid)),
                         }
-                    } else {
-                        Spans::from(format!(
-                            "Kill process \"{}\" with PID {}?  Press ENTER to confirm.",
-                            to_kill_processes.0, first_pid
-                        ))
-                    },
-                ]));
+                    }));
+                    if pids.len() > DD_MAX_LISTED_PROCESSES {
+                        spans.push(Spans::from(format!(
+                            "+{} more",
+                            pids.len() - DD_MAX_LISTED_PROCESSES
+                        )));
+                    }
+                }
+
+                return Some(Text::from(spans));
             }
         }
 
@@ -316,44 +363,14 @@ impl Painter {
     ) -> bool {
         if let Some(dd_text) = dd_text {
             let dd_title = if app_state.dd_err.is_some() {
-                Spans::from(vec![
-                    Span::styled(" Error ", self.colours.widget_title_style),
-                    Span::styled(
-                        format!(
-                            "─{}─ Esc to close ",
-                            "─".repeat(
-                                usize::from(draw_loc.width)
-                                    .saturating_sub(DD_ERROR_BASE.chars().count() + 2)
-                            )
-                        ),
-                        self.colours.border_style,
-                    ),
-                ])
+                "Error"
             } else {
-                Spans::from(vec![
-                    Span::styled(" Confirm Kill Process ", self.colours.widget_title_style),
-                    Span::styled(
-                        format!(
-                            "─{}─ Esc to close ",
-                            "─".repeat(
-                                usize::from(draw_loc.width)
-                                    .saturating_sub(DD_BASE.chars().count() + 2)
-                            )
-                        ),
-                        self.colours.border_style,
-                    ),
-                ])
+                "Confirm Kill Process"
             };
 
             f.render_widget(
                 Paragraph::new(dd_text)
-                    .block(
-                        Block::default()
-                            .title(dd_title)
-                            .style(self.colours.border_style)
-                            .borders(Borders::ALL)
-                            .border_style(self.colours.border_style),
-                    )
+                    .block(self.dialog_block(dd_title, draw_loc.width))
                     .style(self.colours.text_style)
                     .alignment(Alignment::Center)
                     .wrap(Wrap { trim: true }),
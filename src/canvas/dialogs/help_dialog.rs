@@ -1,6 +1,6 @@
 use unicode_width::UnicodeWidthStr;
 
-use crate::{app::App, canvas::Painter, constants};
+use crate::{app::App, canvas::Painter};
 use tui::{
     backend::Backend,
     layout::{Alignment, Rect},
@@ -38,7 +38,7 @@ impl Painter {
             let paragraph_width = std::cmp::max(draw_loc.width.saturating_sub(2), 1);
             let mut prev_section_len = 0;
 
-            constants::HELP_TEXT
+            self.help_text
                 .iter()
                 .enumerate()
                 .for_each(|(itx, section)| {
@@ -46,14 +46,16 @@ impl Painter {
 
                     if itx == 0 {
                         section.iter().for_each(|text_line| {
-                            buffer += UnicodeWidthStr::width(*text_line).saturating_sub(1) as u16
+                            buffer += UnicodeWidthStr::width(text_line.as_str()).saturating_sub(1)
+                                as u16
                                 / paragraph_width;
                         });
 
                         app_state.help_dialog_state.index_shortcuts[itx] = 0;
                     } else {
                         section.iter().for_each(|text_line| {
-                            buffer += UnicodeWidthStr::width(*text_line).saturating_sub(1) as u16
+                            buffer += UnicodeWidthStr::width(text_line.as_str()).saturating_sub(1)
+                                as u16
                                 / paragraph_width;
                         });
 
@@ -68,7 +70,7 @@ impl Painter {
 
             app_state.help_dialog_state.scroll_state.max_scroll_index =
                 (self.styled_help_text.len() as u16
-                    + (constants::HELP_TEXT.len() as u16 - 5)
+                    + (self.help_text.len() as u16 - 5)
                     + overflow_buffer)
                     .saturating_sub(draw_loc.height);
 
@@ -0,0 +1,124 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    terminal::Frame,
+    text::{Span, Spans, Text},
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::{
+    app::{App, PriorityField, ProcessSelection},
+    canvas::Painter,
+};
+
+impl Painter {
+    pub fn get_priority_spans(&self, app_state: &App) -> Option<Text<'_>> {
+        let state = &app_state.priority_dialog_state;
+
+        if let Some(priority_err) = &app_state.dialog_err {
+            return Some(Text::from(vec![
+                Spans::default(),
+                Spans::from("Failed to change priority."),
+                Spans::from(priority_err.clone()),
+                Spans::from("Please press ENTER or ESC to close this dialog."),
+            ]));
+        } else if let Some((name, pids)) = &state.process {
+            if let Some(first_pid) = pids.first() {
+                let mut spans = vec![
+                    Spans::default(),
+                    match state.selection {
+                        ProcessSelection::Grouped => {
+                            if pids.len() != 1 {
+                                Spans::from(format!(
+                                    "Change the priority of {} processes with the name \"{}\"",
+                                    pids.len(),
+                                    name
+                                ))
+                            } else {
+                                Spans::from(format!(
+                                    "Change the priority of 1 process with the name \"{}\"",
+                                    name
+                                ))
+                            }
+                        }
+                        ProcessSelection::Single => Spans::from(format!(
+                            "Change the priority of \"{}\" with PID {}",
+                            name, first_pid
+                        )),
+                        ProcessSelection::Marked => {
+                            if pids.len() != 1 {
+                                Spans::from(format!(
+                                    "Change the priority of {} marked processes",
+                                    pids.len()
+                                ))
+                            } else {
+                                Spans::from("Change the priority of 1 marked process")
+                            }
+                        }
+                    },
+                    Spans::default(),
+                ];
+
+                spans.extend(state.fields().into_iter().map(|field| {
+                    let (label, value) = match field {
+                        PriorityField::Nice => ("Nice value", state.nice.to_string()),
+                        PriorityField::IoClass => ("I/O class", state.io_class.name().to_string()),
+                        PriorityField::IoLevel => ("I/O level", state.io_level.to_string()),
+                        PriorityField::IncludeChildren => (
+                            "Apply to",
+                            if state.include_children {
+                                "Process and children".to_string()
+                            } else {
+                                "Process only".to_string()
+                            },
+                        ),
+                    };
+                    let value_style = if field == state.selected_field {
+                        self.colours.currently_selected_text_style
+                    } else {
+                        self.colours.text_style
+                    };
+
+                    Spans::from(vec![
+                        Span::raw(format!("{}: ", label)),
+                        Span::styled(format!("< {} >", value), value_style),
+                    ])
+                }));
+
+                spans.push(Spans::default());
+                spans.push(Spans::from("Press ENTER to confirm."));
+
+                return Some(Text::from(spans));
+            }
+        }
+
+        None
+    }
+
+    pub fn draw_priority_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, priority_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool {
+        if let Some(priority_text) = priority_text {
+            let title = if app_state.dialog_err.is_some() {
+                "Error"
+            } else {
+                "Change Priority"
+            };
+
+            f.render_widget(
+                Paragraph::new(priority_text)
+                    .block(self.dialog_block(title, draw_loc.width))
+                    .style(self.colours.text_style)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+
+            return true;
+        }
+
+        // Like the kill dialog, this closes the dialog if the process could not be found.
+        false
+    }
+}
@@ -0,0 +1,351 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use time::OffsetDateTime;
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Paragraph, Sparkline, Wrap},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    app::{data_harvester::processes::ProcessDetails, App},
+    canvas::Painter,
+    data_conversion::binary_byte_string,
+};
+
+const DETAILS_BASE: &str = " Process Details ── Esc to close ";
+
+/// The height of the CPU and memory graphs, including their titles.
+const GRAPH_HEIGHT: u16 = 6;
+
+/// The width of the labels in front of each detail.
+const LABEL_WIDTH: usize = 13;
+
+/// Formats a number of seconds like `1d 2h 3m 4s`, leaving out any leading units that are zero.
+fn format_duration(secs: u64) -> String {
+    let (days, hours, minutes, secs) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{}d {}h {}m {}s", days, hours, minutes, secs)
+    } else if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, secs)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Formats a time in seconds since the Unix epoch as a UTC date and time.
+fn format_start_time(start_time: u64) -> Option<String> {
+    let start_time = OffsetDateTime::from_unix_timestamp(start_time as i64).ok()?;
+    start_time
+        .format(&time::macros::format_description!(
+            "[year]-[month]-[day] [hour]:[minute]:[second] UTC"
+        ))
+        .ok()
+}
+
+fn format_limit(value: Option<u64>) -> String {
+    value
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "unlimited".to_string())
+}
+
+impl Painter {
+    fn details_label<'a>(&self, label: &'a str, value: String) -> Spans<'a> {
+        Spans::from(vec![
+            Span::styled(
+                format!("{:<width$}", label, width = LABEL_WIDTH),
+                self.colours.table_header_style,
+            ),
+            Span::raw(value),
+        ])
+    }
+
+    fn get_process_details_spans(&self, app_state: &App, details: &ProcessDetails) -> Text<'_> {
+        let unknown = || "unknown".to_string();
+        let mut spans = vec![];
+
+        if let Some((name, pid)) = &app_state.process_details_state.process {
+            let parent = app_state
+                .data_collection
+                .process_data
+                .process_harvest
+                .get(pid)
+                .and_then(|process| process.parent_pid);
+            spans.push(self.details_label("Name", name.clone()));
+            spans.push(self.details_label(
+                "PID",
+                match parent {
+                    Some(parent) => format!("{} (parent {})", pid, parent),
+                    None => pid.to_string(),
+                },
+            ));
+        }
+
+        spans.push(self.details_label(
+            "Command",
+            if details.command_line.is_empty() {
+                unknown()
+            } else {
+                details.command_line.join(" ")
+            },
+        ));
+        spans.push(self.details_label("Executable", details.exe.clone().unwrap_or_else(unknown)));
+        spans.push(self.details_label("Directory", details.cwd.clone().unwrap_or_else(unknown)));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.as_secs())
+            .unwrap_or(0);
+        spans.push(
+            self.details_label(
+                "Started",
+                details
+                    .start_time
+                    .and_then(|start_time| {
+                        Some(format!(
+                            "{} ({} ago)",
+                            format_start_time(start_time)?,
+                            format_duration(now.saturating_sub(start_time))
+                        ))
+                    })
+                    .unwrap_or_else(unknown),
+            ),
+        );
+        spans.push(self.details_label(
+            "CPU time",
+            format!(
+                "{}.{:02}",
+                format_duration(details.cpu_time_secs as u64),
+                (details.cpu_time_secs.fract() * 100.0) as u64
+            ),
+        ));
+        spans.push(self.details_label("Threads", details.num_threads.to_string()));
+        spans.push(
+            self.details_label(
+                "Open files",
+                details
+                    .num_fds
+                    .map(|num_fds| num_fds.to_string())
+                    .unwrap_or_else(unknown),
+            ),
+        );
+
+        spans.push(Spans::default());
+        spans.push(Spans::from(Span::styled(
+            "Cgroups",
+            self.colours.table_header_style,
+        )));
+        if details.cgroups.is_empty() {
+            spans.push(Spans::from(unknown()));
+        }
+        spans.extend(
+            details
+                .cgroups
+                .iter()
+                .map(|cgroup| Spans::from(cgroup.clone())),
+        );
+
+        spans.push(Spans::default());
+        let name_width = details
+            .limits
+            .iter()
+            .map(|limit| limit.name.len())
+            .max()
+            .unwrap_or(0)
+            .max(LABEL_WIDTH);
+        spans.push(Spans::from(Span::styled(
+            format!(
+                "{:<name_width$}  {:>20}  {:>20}",
+                "Limits",
+                "Soft",
+                "Hard",
+                name_width = name_width
+            ),
+            self.colours.table_header_style,
+        )));
+        if details.limits.is_empty() {
+            spans.push(Spans::from(unknown()));
+        }
+        spans.extend(details.limits.iter().map(|limit| {
+            Spans::from(format!(
+                "{:<name_width$}  {:>20}  {:>20}",
+                limit.name,
+                format_limit(limit.soft),
+                format_limit(limit.hard),
+                name_width = name_width
+            ))
+        }));
+
+        spans.push(Spans::default());
+        spans.push(Spans::from(Span::styled(
+            "Environment",
+            self.colours.table_header_style,
+        )));
+        if details.environment.is_empty() {
+            spans.push(Spans::from(unknown()));
+        }
+        spans.extend(
+            details
+                .environment
+                .iter()
+                .map(|variable| Spans::from(variable.clone())),
+        );
+
+        Text::from(spans)
+    }
+
+    /// Draws the recent CPU and memory usage of the process, newest on the right.
+    fn draw_process_details_graphs<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &App, draw_loc: Rect,
+    ) {
+        let history = match &app_state.process_details_state.process {
+            Some((_, pid)) => app_state
+                .data_collection
+                .process_data
+                .combined_history(&[*pid], usize::MAX),
+            None => vec![],
+        };
+        let graph_locs = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(draw_loc);
+        let width = usize::from(graph_locs[0].width.saturating_sub(2));
+        let skip = history.len().saturating_sub(width);
+
+        // CPU usage is kept to a tenth of a percent, and the graph always goes up to at least 100%.
+        let cpu_data = history
+            .iter()
+            .skip(skip)
+            .map(|sample| (sample.cpu_usage_percent * 10.0) as u64)
+            .collect::<Vec<_>>();
+        let cpu_max = cpu_data.iter().copied().max().unwrap_or(0).max(1000);
+        let cpu_title = match history.last() {
+            Some(sample) => format!(" CPU {:.1}% ", sample.cpu_usage_percent),
+            None => " CPU ".to_string(),
+        };
+
+        let mem_data = history
+            .iter()
+            .skip(skip)
+            .map(|sample| sample.mem_usage_bytes)
+            .collect::<Vec<_>>();
+        let mem_max = mem_data.iter().copied().max().unwrap_or(0).max(1);
+        let mem_title = match history.last() {
+            Some(sample) => format!(
+                " Memory {} (peak {}) ",
+                binary_byte_string(sample.mem_usage_bytes),
+                binary_byte_string(mem_max)
+            ),
+            None => " Memory ".to_string(),
+        };
+
+        for (title, data, max, style, loc) in [
+            (
+                cpu_title,
+                cpu_data,
+                cpu_max,
+                self.colours.avg_colour_style,
+                graph_locs[0],
+            ),
+            (
+                mem_title,
+                mem_data,
+                mem_max,
+                self.colours.ram_style,
+                graph_locs[1],
+            ),
+        ] {
+            f.render_widget(
+                Sparkline::default()
+                    .block(
+                        Block::default()
+                            .title(Span::styled(title, self.colours.widget_title_style))
+                            .borders(Borders::ALL)
+                            .border_style(self.colours.border_style),
+                    )
+                    .data(&data)
+                    .max(max)
+                    .style(style),
+                loc,
+            );
+        }
+    }
+
+    pub fn draw_process_details_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect,
+    ) {
+        let details_title = Spans::from(vec![
+            Span::styled(" Process Details ", self.colours.widget_title_style),
+            Span::styled(
+                format!(
+                    "─{}─ Esc to close ",
+                    "─".repeat(
+                        usize::from(draw_loc.width)
+                            .saturating_sub(DETAILS_BASE.chars().count() + 2)
+                    )
+                ),
+                self.colours.border_style,
+            ),
+        ]);
+        let block = Block::default()
+            .title(details_title)
+            .style(self.colours.border_style)
+            .borders(Borders::ALL)
+            .border_style(self.colours.border_style);
+        let inner_loc = block.inner(draw_loc);
+        f.render_widget(block, draw_loc);
+
+        let split_loc = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(GRAPH_HEIGHT), Constraint::Min(0)])
+            .split(inner_loc);
+        self.draw_process_details_graphs(f, app_state, split_loc[0]);
+
+        let text_loc = split_loc[1];
+        let details_text = match &app_state.data_collection.process_details {
+            Some(details) => self.get_process_details_spans(app_state, details),
+            None => Text::from(vec![
+                Spans::default(),
+                Spans::from(if cfg!(target_os = "linux") {
+                    "Collecting details..."
+                } else {
+                    "Process details are only supported on Linux."
+                }),
+            ]),
+        };
+
+        // Count the lines after wrapping, so the last one can be scrolled to.
+        let text_width = usize::from(std::cmp::max(text_loc.width, 1));
+        let wrapped_height: usize = details_text
+            .lines
+            .iter()
+            .map(|line| {
+                let width: usize = line
+                    .0
+                    .iter()
+                    .map(|span| UnicodeWidthStr::width(span.content.as_ref()))
+                    .sum();
+                width.saturating_sub(1) / text_width + 1
+            })
+            .sum();
+        let scroll_state = &mut app_state.process_details_state.scroll_state;
+        scroll_state.max_scroll_index = (wrapped_height as u16).saturating_sub(text_loc.height) + 1;
+        if scroll_state.current_scroll_index >= scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index = scroll_state.max_scroll_index - 1;
+        }
+
+        f.render_widget(
+            Paragraph::new(details_text)
+                .style(self.colours.text_style)
+                .alignment(Alignment::Left)
+                .wrap(Wrap { trim: false })
+                .scroll((scroll_state.current_scroll_index, 0)),
+            text_loc,
+        );
+    }
+}
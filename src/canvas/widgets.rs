@@ -1,11 +1,18 @@
 pub mod basic_table_arrows;
 pub mod battery_display;
+pub mod cgroup_table;
 pub mod cpu_basic;
+pub mod cpu_freq_graph;
 pub mod cpu_graph;
+pub mod disk_io_graph;
 pub mod disk_table;
 pub mod mem_basic;
 pub mod mem_graph;
+pub mod net_interface_table;
 pub mod network_basic;
 pub mod network_graph;
+pub mod pressure_graph;
 pub mod process_table;
+pub mod sensor_table;
+pub mod temp_graph;
 pub mod temp_table;
@@ -1,4 +1,4 @@
-use std::cmp::min;
+use std::cmp::{max, min};
 
 use crate::{
     app::App,
@@ -60,16 +60,29 @@ impl Painter {
                 const BAR_BOUND_SPACE: usize = 2;
                 const PERCENTAGE_SPACE: usize = 4;
                 const MARGIN_SPACE: usize = 2;
-
-                const COMBINED_SPACING: usize =
-                    CPU_NAME_SPACE + BAR_BOUND_SPACE + PERCENTAGE_SPACE + MARGIN_SPACE;
-                const REDUCED_SPACING: usize = CPU_NAME_SPACE + PERCENTAGE_SPACE + MARGIN_SPACE;
+                const FREQUENCY_SPACE: usize = 7;
+
+                // The names of groups of CPUs can be longer than those of single CPUs.
+                let cpu_name_space = cpu_data
+                    .iter()
+                    .map(|cpu| cpu.short_cpu_name.len())
+                    .fold(CPU_NAME_SPACE, max);
+                let combined_spacing =
+                    cpu_name_space + BAR_BOUND_SPACE + PERCENTAGE_SPACE + MARGIN_SPACE;
+                let reduced_spacing = cpu_name_space + PERCENTAGE_SPACE + MARGIN_SPACE;
                 let chunk_width: usize = chunks[0].width.into();
 
                 // Inspired by htop.
                 // We do +4 as if it's too few bars in the bar length, it's kinda pointless.
-                let cpu_bars = if chunk_width >= COMBINED_SPACING + 4 {
-                    let bar_length = chunk_width - COMBINED_SPACING;
+                let cpu_bars = if chunk_width >= combined_spacing + 4 {
+                    // Make room for frequencies too if they are known, as long as the bars aren't too short.
+                    let show_frequency = chunk_width >= combined_spacing + FREQUENCY_SPACE + 4
+                        && cpu_data.iter().any(|cpu| !cpu.frequency_value.is_empty());
+                    let bar_length = if show_frequency {
+                        chunk_width - combined_spacing - FREQUENCY_SPACE
+                    } else {
+                        chunk_width - combined_spacing
+                    };
                     (0..num_cpus)
                         .map(|cpu_index| {
                             let use_percentage =
@@ -78,26 +91,29 @@ impl Painter {
                                 } else {
                                     0.0
                                 };
+                            let frequency = if show_frequency {
+                                format!(
+                                    "{:>width$}",
+                                    cpu_data[cpu_index].frequency_value,
+                                    width = FREQUENCY_SPACE
+                                )
+                            } else {
+                                String::new()
+                            };
 
                             let num_bars = calculate_basic_use_bars(use_percentage, bar_length);
                             format!(
-                                "{:3}[{}{}{:3.0}%]",
-                                if app_state.app_config_fields.show_average_cpu {
-                                    if cpu_index == 0 {
-                                        "AVG".to_string()
-                                    } else {
-                                        (cpu_index - 1).to_string()
-                                    }
-                                } else {
-                                    cpu_index.to_string()
-                                },
+                                "{:name_width$}[{}{}{}{:3.0}%]",
+                                cpu_data[cpu_index].short_cpu_name,
                                 "|".repeat(num_bars),
                                 " ".repeat(bar_length - num_bars),
+                                frequency,
                                 use_percentage.round(),
+                                name_width = cpu_name_space,
                             )
                         })
                         .collect::<Vec<_>>()
-                } else if chunk_width >= REDUCED_SPACING {
+                } else if chunk_width >= reduced_spacing {
                     (0..num_cpus)
                         .map(|cpu_index| {
                             let use_percentage =
@@ -108,17 +124,10 @@ impl Painter {
                                 };
 
                             format!(
-                                "{:3} {:3.0}%",
-                                if app_state.app_config_fields.show_average_cpu {
-                                    if cpu_index == 0 {
-                                        "AVG".to_string()
-                                    } else {
-                                        (cpu_index - 1).to_string()
-                                    }
-                                } else {
-                                    cpu_index.to_string()
-                                },
+                                "{:name_width$} {:3.0}%",
+                                cpu_data[cpu_index].short_cpu_name,
                                 use_percentage.round(),
+                                name_width = cpu_name_space,
                             )
                         })
                         .collect::<Vec<_>>()
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::App,
+    canvas::{drawing_utils::should_hide_x_label, Painter},
+    components::time_graph::{GraphData, TimeGraph},
+    utils::gen_util::partial_ordering,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+impl Painter {
+    pub fn draw_cpu_freq_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(cpu_freq_widget_state) =
+            app_state.cpu_freq_state.widget_states.get_mut(&widget_id)
+        {
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, cpu_freq_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut cpu_freq_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let frequency_data = &app_state.converted_data.cpu_frequency_data;
+            let points = frequency_data
+                .iter()
+                .enumerate()
+                .map(|(index, cpu)| GraphData {
+                    points: &cpu.frequency_data[..],
+                    style: self.colours.cpu_colour_styles
+                        [index % self.colours.cpu_colour_styles.len()],
+                    name: Some(format!("{}: {}", cpu.name, cpu.legend_value).into()),
+                    filled: false,
+                })
+                .collect::<Vec<_>>();
+
+            // Scale to the highest frequency any CPU can run at, or failing that, the highest shown one, rounded
+            // up to the next half GHz.
+            let max_frequency = frequency_data
+                .iter()
+                .filter_map(|cpu| cpu.max_frequency)
+                .chain(
+                    points
+                        .iter()
+                        .flat_map(|cpu| cpu.points.iter().map(|(_, frequency)| *frequency)),
+                )
+                .max_by(|a, b| partial_ordering(a, b))
+                .unwrap_or(0.0);
+            let max_range = ((max_frequency * 2.0).ceil() / 2.0).max(0.5);
+
+            let y_labels: Vec<Cow<'_, str>> = vec![
+                "0GHz".into(),
+                format!("{:.1}GHz", max_range / 2.0).into(),
+                format!("{:.1}GHz", max_range).into(),
+            ];
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds: [0.0, max_range],
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: " CPU Frequency ".into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -1,23 +1,21 @@
 use std::{borrow::Cow, iter};
 
 use crate::{
-    app::{layout_manager::WidgetDirection, App, CpuWidgetState},
+    app::{App, CpuWidgetState},
     canvas::{drawing_utils::should_hide_x_label, Painter},
     components::{
         text_table::{CellContent, TextTable},
-        time_graph::{GraphData, TimeGraph},
+        time_graph::{GraphData, Point, TimeGraph},
+    },
+    data_conversion::{
+        convert_cpu_breakdown_points, ConvertedCpuData, TableData, TableRow, CPU_TIME_KINDS,
     },
-    data_conversion::{ConvertedCpuData, TableData, TableRow},
 };
 
 use concat_string::concat_string;
 
 use itertools::Either;
-use tui::{
-    backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
-    terminal::Frame,
-};
+use tui::{backend::Backend, layout::Rect, terminal::Frame};
 
 const AVG_POSITION: usize = 1;
 const ALL_POSITION: usize = 0;
@@ -26,101 +24,20 @@ impl Painter {
     pub fn draw_cpu<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
-        let legend_width = (draw_loc.width as f64 * 0.15) as u16;
-
-        if legend_width < 6 {
-            // Skip drawing legend
-            if app_state.current_widget.widget_id == (widget_id + 1) {
-                if app_state.app_config_fields.left_legend {
-                    app_state.move_widget_selection(&WidgetDirection::Right);
-                } else {
-                    app_state.move_widget_selection(&WidgetDirection::Left);
-                }
-            }
-            self.draw_cpu_graph(f, app_state, draw_loc, widget_id);
-            if let Some(cpu_widget_state) = app_state.cpu_state.widget_states.get_mut(&widget_id) {
-                cpu_widget_state.is_legend_hidden = true;
-            }
-
-            // Update draw loc in widget map
-            if app_state.should_get_widget_bounds() {
-                if let Some(bottom_widget) = app_state.widget_map.get_mut(&widget_id) {
-                    bottom_widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
-                    bottom_widget.bottom_right_corner =
-                        Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
-                }
-            }
-        } else {
-            let graph_width = draw_loc.width - legend_width;
-            let (graph_index, legend_index, constraints) =
-                if app_state.app_config_fields.left_legend {
-                    (
-                        1,
-                        0,
-                        [
-                            Constraint::Length(legend_width),
-                            Constraint::Length(graph_width),
-                        ],
-                    )
-                } else {
-                    (
-                        0,
-                        1,
-                        [
-                            Constraint::Length(graph_width),
-                            Constraint::Length(legend_width),
-                        ],
-                    )
-                };
-
-            let partitioned_draw_loc = Layout::default()
-                .margin(0)
-                .direction(Direction::Horizontal)
-                .constraints(constraints)
-                .split(draw_loc);
-
-            self.draw_cpu_graph(f, app_state, partitioned_draw_loc[graph_index], widget_id);
-            self.draw_cpu_legend(
-                f,
-                app_state,
-                partitioned_draw_loc[legend_index],
-                widget_id + 1,
-            );
-
-            if app_state.should_get_widget_bounds() {
-                // Update draw loc in widget map
-                if let Some(cpu_widget) = app_state.widget_map.get_mut(&widget_id) {
-                    cpu_widget.top_left_corner = Some((
-                        partitioned_draw_loc[graph_index].x,
-                        partitioned_draw_loc[graph_index].y,
-                    ));
-                    cpu_widget.bottom_right_corner = Some((
-                        partitioned_draw_loc[graph_index].x
-                            + partitioned_draw_loc[graph_index].width,
-                        partitioned_draw_loc[graph_index].y
-                            + partitioned_draw_loc[graph_index].height,
-                    ));
-                }
-
-                if let Some(legend_widget) = app_state.widget_map.get_mut(&(widget_id + 1)) {
-                    legend_widget.top_left_corner = Some((
-                        partitioned_draw_loc[legend_index].x,
-                        partitioned_draw_loc[legend_index].y,
-                    ));
-                    legend_widget.bottom_right_corner = Some((
-                        partitioned_draw_loc[legend_index].x
-                            + partitioned_draw_loc[legend_index].width,
-                        partitioned_draw_loc[legend_index].y
-                            + partitioned_draw_loc[legend_index].height,
-                    ));
-                }
-            }
-        }
+        self.draw_graph_with_legend(
+            f,
+            app_state,
+            draw_loc,
+            widget_id,
+            0.15,
+            Self::draw_cpu_graph,
+            Self::draw_cpu_legend,
+        );
     }
 
     fn generate_points<'a>(
         &self, cpu_widget_state: &CpuWidgetState, cpu_data: &'a [ConvertedCpuData],
-        show_avg_cpu: bool,
+        breakdown_data: &'a [Vec<Point>], show_avg_cpu: bool,
     ) -> Vec<GraphData<'a>> {
         let show_avg_offset = if show_avg_cpu { AVG_POSITION } else { 0 };
 
@@ -146,6 +63,7 @@ impl Painter {
                         points: &cpu.cpu_data[..],
                         style,
                         name: None,
+                        filled: false,
                     }
                 })
                 .collect::<Vec<_>>()
@@ -158,11 +76,30 @@ impl Painter {
                     [(offset_position - show_avg_offset) % self.colours.cpu_colour_styles.len()]
             };
 
-            vec![GraphData {
-                points: &cpu.cpu_data[..],
-                style,
-                name: None,
-            }]
+            if breakdown_data.is_empty() {
+                vec![GraphData {
+                    points: &cpu.cpu_data[..],
+                    style,
+                    name: None,
+                    filled: false,
+                }]
+            } else {
+                // Show how the time of a single CPU was spent as areas stacked so that the top of the steal area
+                // is the usage of the CPU. The top area is drawn first so the ones under it cover its bottom.
+                breakdown_data
+                    .iter()
+                    .zip(CPU_TIME_KINDS)
+                    .zip(&cpu.breakdown_legend_values)
+                    .zip(&self.colours.cpu_time_styles)
+                    .rev()
+                    .map(|(((points, kind), legend_value), style)| GraphData {
+                        points: &points[..],
+                        style: *style,
+                        name: Some(concat_string!(kind, ":", legend_value).into()),
+                        filled: true,
+                    })
+                    .collect()
+            }
         } else {
             vec![]
         }
@@ -185,9 +122,22 @@ impl Painter {
                 draw_loc,
             );
 
+            // Only the selected CPU is broken down, so its breakdown is converted as it is drawn.
+            let current_scroll_position = cpu_widget_state.table_state.current_scroll_position;
+            let breakdown_data = match cpu_data.get(current_scroll_position) {
+                Some(ConvertedCpuData {
+                    cpu_index: Some(cpu_index),
+                    ..
+                }) if current_scroll_position != ALL_POSITION => {
+                    convert_cpu_breakdown_points(&app_state.data_collection, *cpu_index)
+                }
+                _ => vec![],
+            };
+
             let points = self.generate_points(
                 cpu_widget_state,
                 cpu_data,
+                &breakdown_data,
                 app_state.app_config_fields.show_average_cpu,
             );
 
@@ -224,15 +174,30 @@ impl Painter {
     fn draw_cpu_legend<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
-        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        let mut recalculate_column_widths = app_state.should_get_widget_bounds();
         if let Some(cpu_widget_state) = app_state.cpu_state.widget_states.get_mut(&(widget_id - 1))
         {
             // TODO: This line (and the one above, see caller) is pretty dumb but I guess needed.
             cpu_widget_state.is_legend_hidden = false;
 
+            // Only show frequencies if they are known and there is room for them next to the usage.
+            const FREQ_WIDTH_CHECK: u16 = 20;
+            let hide_frequency = draw_loc.width < FREQ_WIDTH_CHECK
+                || app_state
+                    .converted_data
+                    .cpu_data
+                    .iter()
+                    .all(|cpu| cpu.frequency_value.is_empty());
+            if let Some(freq_column) = cpu_widget_state.table_state.columns.get_mut(2) {
+                if freq_column.is_hidden != hide_frequency {
+                    freq_column.is_hidden = hide_frequency;
+                    recalculate_column_widths = true;
+                }
+            }
+
             let show_avg_cpu = app_state.app_config_fields.show_average_cpu;
             let cpu_data = {
-                let col_widths = vec![1, 3]; // TODO: Should change this to take const generics (usize) and an array.
+                let col_widths = vec![1, 3, 6]; // TODO: Should change this to take const generics (usize) and an array.
                 let colour_iter = if show_avg_cpu {
                     Either::Left(
                         iter::once(&self.colours.all_colour_style)
@@ -258,6 +223,7 @@ impl Painter {
                                 } else {
                                     cpu.legend_value.clone().into()
                                 }),
+                                CellContent::Simple("".into()),
                             ];
                             TableRow::Styled(row, *style)
                         }))
@@ -269,6 +235,7 @@ impl Painter {
                                     main: cpu.cpu_name.clone().into(),
                                 },
                                 CellContent::Simple(cpu.legend_value.clone().into()),
+                                CellContent::Simple(cpu.frequency_value.clone().into()),
                             ];
                             TableRow::Styled(row, *style)
                         }))
@@ -0,0 +1,212 @@
+use crate::{
+    app::{App, AxisScaling, DiskIoWidgetState},
+    canvas::{drawing_utils::should_hide_x_label, Painter},
+    components::{
+        text_table::{CellContent, TextTable},
+        time_graph::{GraphData, TimeGraph},
+    },
+    data_conversion::{ConvertedDiskIoData, TableData, TableRow},
+    units::data_units::DataUnit,
+    utils::gen_util::KILO_LIMIT_F64,
+};
+
+use concat_string::concat_string;
+
+use tui::{backend::Backend, layout::Rect, terminal::Frame};
+
+use super::network_graph::{adjust_network_data_point, get_max_entry};
+
+const ALL_POSITION: usize = 0;
+
+impl Painter {
+    pub fn draw_disk_io<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        self.draw_graph_with_legend(
+            f,
+            app_state,
+            draw_loc,
+            widget_id,
+            0.3,
+            Self::draw_disk_io_graph,
+            Self::draw_disk_io_legend,
+        );
+    }
+
+    /// Returns the read and write lines of every disk if "All" is selected in the legend, and otherwise only
+    /// those of the selected disk.
+    fn generate_disk_io_points<'a>(
+        &self, disk_io_widget_state: &DiskIoWidgetState, disk_io_data: &'a [ConvertedDiskIoData],
+    ) -> Vec<GraphData<'a>> {
+        let current_scroll_position = disk_io_widget_state.table_state.current_scroll_position;
+        disk_io_data
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                current_scroll_position == ALL_POSITION || current_scroll_position == index + 1
+            })
+            .flat_map(|(index, disk_io)| {
+                let (read_style, write_style) = self.line_pair_styles(index);
+                [
+                    GraphData {
+                        points: &disk_io.read[..],
+                        style: read_style,
+                        name: None,
+                        filled: false,
+                    },
+                    GraphData {
+                        points: &disk_io.write[..],
+                        style: write_style,
+                        name: None,
+                        filled: false,
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    fn draw_disk_io_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(disk_io_widget_state) =
+            app_state.disk_io_state.widget_states.get_mut(&widget_id)
+        {
+            let disk_io_data = &app_state.converted_data.disk_io_data;
+            let time_start = -(disk_io_widget_state.current_display_time as f64);
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, disk_io_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut disk_io_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let points = self.generate_disk_io_points(disk_io_widget_state, disk_io_data);
+
+            // Scale to the largest rate being drawn.
+            let max_entry = points
+                .chunks(2)
+                .map(|read_write| {
+                    get_max_entry(
+                        read_write[0].points,
+                        read_write[1].points,
+                        time_start,
+                        &AxisScaling::Linear,
+                        false,
+                    )
+                    .1
+                })
+                .reduce(f64::max)
+                .unwrap_or(KILO_LIMIT_F64);
+            let (max_range, labels) =
+                adjust_network_data_point(max_entry, &AxisScaling::Linear, &DataUnit::Byte, false);
+            let y_labels = labels.iter().map(|label| label.into()).collect::<Vec<_>>();
+
+            let title = match disk_io_widget_state
+                .table_state
+                .current_scroll_position
+                .checked_sub(1)
+                .and_then(|index| disk_io_data.get(index))
+            {
+                Some(disk_io) => concat_string!(" Disk I/O ─ ", disk_io.name, " ").into(),
+                None => " Disk I/O ".into(),
+            };
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds: [0.0, max_range],
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title,
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: None,
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+    }
+
+    fn draw_disk_io_legend<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(disk_io_widget_state) = app_state
+            .disk_io_state
+            .widget_states
+            .get_mut(&(widget_id - 1))
+        {
+            disk_io_widget_state.is_legend_hidden = false;
+
+            let disk_io_data = {
+                let all_row = TableRow::Styled(
+                    vec![
+                        CellContent::Simple("All".into()),
+                        CellContent::Simple("".into()),
+                        CellContent::Simple("".into()),
+                    ],
+                    self.colours.all_colour_style,
+                );
+                let data = std::iter::once(all_row)
+                    .chain(
+                        app_state
+                            .converted_data
+                            .disk_io_data
+                            .iter()
+                            .enumerate()
+                            .map(|(index, disk_io)| {
+                                let row = vec![
+                                    CellContent::Simple(disk_io.name.clone().into()),
+                                    CellContent::Simple(disk_io.read_label.clone().into()),
+                                    CellContent::Simple(disk_io.write_label.clone().into()),
+                                ];
+                                TableRow::Styled(row, self.line_pair_styles(index).0)
+                            }),
+                    )
+                    .collect::<Vec<_>>();
+
+                let mut col_widths = vec![0; 3];
+                for row in &data {
+                    for (col_width, cell) in col_widths.iter_mut().zip(row.row()) {
+                        *col_width = std::cmp::max(*col_width, cell.len());
+                    }
+                }
+
+                TableData { data, col_widths }
+            };
+
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let border_style = if is_on_widget {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            TextTable {
+                table_gap: app_state.app_config_fields.table_gap,
+                is_force_redraw: app_state.is_force_redraw,
+                recalculate_column_widths,
+                header_style: self.colours.table_header_style,
+                border_style,
+                highlighted_text_style: self.colours.currently_selected_text_style,
+                title: None,
+                is_on_widget,
+                draw_border: true,
+                show_table_scroll_position: app_state.app_config_fields.show_table_scroll_position,
+                title_style: self.colours.widget_title_style,
+                text_style: self.colours.text_style,
+                left_to_right: false,
+            }
+            .draw_text_table(
+                f,
+                draw_loc,
+                &mut disk_io_widget_state.table_state,
+                &disk_io_data,
+                None,
+            );
+        }
+    }
+}
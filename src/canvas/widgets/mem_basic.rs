@@ -102,11 +102,26 @@ impl Painter {
             )
         };
 
-        let mem_text = vec![
+        let mut mem_text = vec![
             Spans::from(Span::styled(mem_label, self.colours.ram_style)),
             Spans::from(Span::styled(swap_label, self.colours.swap_style)),
         ];
 
+        // Then the breakdown, if there is one, two parts to a row.
+        if let Some(breakdown) = &app_state.converted_data.mem_breakdown {
+            let column_width = usize::from(margined_loc[0].width / 2);
+            for row in breakdown.details.chunks(2) {
+                let text = row
+                    .iter()
+                    .map(|(name, size)| {
+                        let part = format!("{} {}", name, size);
+                        format!("{:<width$}", part, width = column_width)
+                    })
+                    .collect::<String>();
+                mem_text.push(Spans::from(Span::styled(text, self.colours.text_style)));
+            }
+        }
+
         f.render_widget(
             Paragraph::new(mem_text).block(Block::default()),
             margined_loc[0],
@@ -29,13 +29,32 @@ impl Painter {
                 draw_loc,
             );
             let points = {
-                let mut points = Vec::with_capacity(2);
+                let mut points = Vec::with_capacity(4);
                 if let Some((label_percent, label_frac)) = &app_state.converted_data.mem_labels {
+                    // The buffers and cache are stacked on top of the used memory as filled areas, so draw
+                    // them first and let the used memory cover the bottom of them.
+                    let breakdown = app_state.converted_data.mem_breakdown.as_ref();
+                    if let Some(breakdown) = breakdown {
+                        points.push(GraphData {
+                            points: &breakdown.cache_data,
+                            style: self.colours.cache_style,
+                            name: Some(format!("CACHE:{}", breakdown.cache_label).into()),
+                            filled: true,
+                        });
+                        points.push(GraphData {
+                            points: &breakdown.buffers_data,
+                            style: self.colours.buffers_style,
+                            name: Some(format!("BUF:{}", breakdown.buffers_label).into()),
+                            filled: true,
+                        });
+                    }
+
                     let mem_label = format!("RAM:{}{}", label_percent, label_frac);
                     points.push(GraphData {
                         points: &app_state.converted_data.mem_data,
                         style: self.colours.ram_style,
                         name: Some(mem_label.into()),
+                        filled: breakdown.is_some(),
                     });
                 }
                 if let Some((label_percent, label_frac)) = &app_state.converted_data.swap_labels {
@@ -44,6 +63,7 @@ impl Painter {
                         points: &app_state.converted_data.swap_data,
                         style: self.colours.swap_style,
                         name: Some(swap_label.into()),
+                        filled: false,
                     });
                 }
 
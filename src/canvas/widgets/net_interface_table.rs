@@ -0,0 +1,81 @@
+use tui::{backend::Backend, layout::Rect, terminal::Frame};
+
+use crate::{
+    app,
+    canvas::Painter,
+    components::text_table::{TextTable, TextTableTitle},
+    data_conversion::{TableData, TableRow},
+};
+
+impl Painter {
+    pub fn draw_net_interface_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(net_interface_widget_state) = app_state
+            .net_interface_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let is_on_widget = app_state.current_widget.widget_id == widget_id;
+            let (border_style, highlighted_text_style) = if is_on_widget {
+                (
+                    self.colours.highlighted_border_style,
+                    self.colours.currently_selected_text_style,
+                )
+            } else {
+                (self.colours.border_style, self.colours.text_style)
+            };
+
+            // Graphed interfaces are shown in the same colour as their RX line in the network graph.
+            let graphed_interfaces = &app_state.net_state.graphed_interfaces;
+            let net_interface_data = &app_state.converted_data.net_interface_data;
+            let table_data = TableData {
+                data: net_interface_data
+                    .data
+                    .iter()
+                    .zip(&app_state.converted_data.net_interface_names)
+                    .map(|(row, name)| {
+                        match graphed_interfaces
+                            .iter()
+                            .position(|graphed| graphed == name)
+                        {
+                            Some(index) => {
+                                TableRow::Styled(row.row().to_vec(), self.line_pair_styles(index).0)
+                            }
+                            None => TableRow::Raw(row.row().to_vec()),
+                        }
+                    })
+                    .collect(),
+                col_widths: net_interface_data.col_widths.clone(),
+            };
+
+            TextTable {
+                table_gap: app_state.app_config_fields.table_gap,
+                is_force_redraw: app_state.is_force_redraw,
+                recalculate_column_widths,
+                header_style: self.colours.table_header_style,
+                border_style,
+                highlighted_text_style,
+                title: Some(TextTableTitle {
+                    title: " Interfaces ".into(),
+                    is_expanded: app_state.is_expanded,
+                }),
+                is_on_widget,
+                draw_border,
+                show_table_scroll_position: app_state.app_config_fields.show_table_scroll_position,
+                title_style: self.colours.widget_title_style,
+                text_style: self.colours.text_style,
+                left_to_right: true,
+            }
+            .draw_text_table(
+                f,
+                draw_loc,
+                &mut net_interface_widget_state.table_state,
+                &table_data,
+                app_state.widget_map.get_mut(&widget_id),
+            );
+        }
+    }
+}
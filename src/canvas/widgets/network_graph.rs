@@ -78,6 +78,23 @@ impl Painter {
                 app_state.app_config_fields.network_use_binary_prefix,
             );
 
+            // Make sure any graphed interfaces fit too.
+            let max_entry = app_state
+                .converted_data
+                .interface_graph_data
+                .iter()
+                .map(|interface| {
+                    get_max_entry(
+                        &interface.rx,
+                        &interface.tx,
+                        time_start,
+                        &app_state.app_config_fields.network_scale_type,
+                        app_state.app_config_fields.network_use_binary_prefix,
+                    )
+                    .1
+                })
+                .fold(max_entry, f64::max);
+
             let (max_range, labels) = adjust_network_data_point(
                 max_entry,
                 &app_state.app_config_fields.network_scale_type,
@@ -95,17 +112,19 @@ impl Painter {
             };
 
             // TODO: Add support for clicking on legend to only show that value on chart.
-            let points = if app_state.app_config_fields.use_old_network_legend && !hide_legend {
+            let mut points = if app_state.app_config_fields.use_old_network_legend && !hide_legend {
                 vec![
                     GraphData {
                         points: network_data_rx,
                         style: self.colours.rx_style,
                         name: Some(format!("RX: {:7}", app_state.converted_data.rx_display).into()),
+                        filled: false,
                     },
                     GraphData {
                         points: network_data_tx,
                         style: self.colours.tx_style,
                         name: Some(format!("TX: {:7}", app_state.converted_data.tx_display).into()),
+                        filled: false,
                     },
                     GraphData {
                         points: &[],
@@ -114,6 +133,7 @@ impl Painter {
                             format!("Total RX: {:7}", app_state.converted_data.total_rx_display)
                                 .into(),
                         ),
+                        filled: false,
                     },
                     GraphData {
                         points: &[],
@@ -122,6 +142,7 @@ impl Painter {
                             format!("Total TX: {:7}", app_state.converted_data.total_tx_display)
                                 .into(),
                         ),
+                        filled: false,
                     },
                 ]
             } else {
@@ -130,14 +151,36 @@ impl Painter {
                         points: network_data_rx,
                         style: self.colours.rx_style,
                         name: Some((&app_state.converted_data.rx_display).into()),
+                        filled: false,
                     },
                     GraphData {
                         points: network_data_tx,
                         style: self.colours.tx_style,
                         name: Some((&app_state.converted_data.tx_display).into()),
+                        filled: false,
                     },
                 ]
             };
+            for (index, interface) in app_state
+                .converted_data
+                .interface_graph_data
+                .iter()
+                .enumerate()
+            {
+                let (rx_style, tx_style) = self.line_pair_styles(index);
+                points.push(GraphData {
+                    points: &interface.rx,
+                    style: rx_style,
+                    name: Some(format!("{} RX: {}", interface.name, interface.rx_display).into()),
+                    filled: false,
+                });
+                points.push(GraphData {
+                    points: &interface.tx,
+                    style: tx_style,
+                    name: Some(format!("{} TX: {}", interface.name, interface.tx_display).into()),
+                    filled: false,
+                });
+            }
 
             TimeGraph {
                 use_dot: app_state.app_config_fields.use_dot,
@@ -198,7 +241,7 @@ impl Painter {
 }
 
 /// Returns the max data point and time given a time.
-fn get_max_entry(
+pub(super) fn get_max_entry(
     rx: &[Point], tx: &[Point], time_start: f64, network_scale_type: &AxisScaling,
     network_use_binary_prefix: bool,
 ) -> (f64, f64) {
@@ -318,7 +361,7 @@ fn get_max_entry(
 }
 
 /// Returns the required max data point and labels.
-fn adjust_network_data_point(
+pub(super) fn adjust_network_data_point(
     max_entry: f64, network_scale_type: &AxisScaling, network_unit_type: &DataUnit,
     network_use_binary_prefix: bool,
 ) -> (f64, Vec<String>) {
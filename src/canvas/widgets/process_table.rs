@@ -1,5 +1,5 @@
 use crate::{
-    app::App,
+    app::{widgets::ProcRowState, App},
     canvas::{drawing_utils::get_search_start_position, Painter},
     components::text_table::{TextTable, TextTableTitle},
     constants::*,
@@ -9,6 +9,7 @@ use crate::{
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
     terminal::Frame,
     text::{Span, Spans},
     widgets::{Block, Borders, Paragraph},
@@ -20,6 +21,20 @@ use unicode_width::UnicodeWidthStr;
 const SORT_MENU_WIDTH: u16 = 7;
 
 impl Painter {
+    /// The style of a row of the process table that stands out. Rows that are disabled are greyed out over
+    /// anything else, and marking a row takes priority over it being stopped.
+    fn process_row_style(&self, row_state: &ProcRowState) -> Style {
+        if row_state.is_disabled {
+            self.colours.disabled_text_style
+        } else if row_state.is_marked {
+            self.colours.marked_process_style
+        } else if row_state.is_stopped {
+            self.colours.stopped_process_style
+        } else {
+            Style::default()
+        }
+    }
+
     /// Draws and handles all process-related drawing.  Use this.
     /// - `widget_id` here represents the widget ID of the process widget itself!
     pub fn draw_process_widget<B: Backend>(
@@ -91,11 +106,15 @@ impl Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
-            // TODO: [Refactor] This is an ugly hack to add the disabled style...
-            // this could be solved by storing style locally to the widget.
-            for row in &mut proc_widget_state.table_data.data {
+            // Rows that stand out are already styled, so only the style itself has to be picked.
+            for (row, row_state) in proc_widget_state
+                .table_data
+                .data
+                .iter_mut()
+                .zip(&proc_widget_state.row_states)
+            {
                 if let TableRow::Styled(_, style) = row {
-                    *style = style.patch(self.colours.disabled_text_style);
+                    *style = self.process_row_style(row_state);
                 }
             }
 
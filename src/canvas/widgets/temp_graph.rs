@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::App,
+    canvas::{drawing_utils::should_hide_x_label, Painter},
+    components::{
+        text_table::{CellContent, TextTable},
+        time_graph::{GraphData, TimeGraph},
+    },
+    data_conversion::{temperature_unit, TableData, TableRow},
+    utils::gen_util::partial_ordering,
+};
+
+use concat_string::concat_string;
+
+use tui::{backend::Backend, layout::Rect, style::Style, terminal::Frame};
+
+impl Painter {
+    /// The style of the `index`-th sensor's line.
+    fn temp_graph_style(&self, index: usize) -> Style {
+        self.colours.cpu_colour_styles[index % self.colours.cpu_colour_styles.len()]
+    }
+
+    pub fn draw_temp_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        self.draw_graph_with_legend(
+            f,
+            app_state,
+            draw_loc,
+            widget_id,
+            0.25,
+            Self::draw_temp_graph_graph,
+            Self::draw_temp_graph_legend,
+        );
+    }
+
+    fn draw_temp_graph_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(temp_graph_widget_state) =
+            app_state.temp_graph_state.widget_states.get_mut(&widget_id)
+        {
+            let temp_graph_data = &app_state.converted_data.temp_graph_data;
+            let time_start = -(temp_graph_widget_state.current_display_time as f64);
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, temp_graph_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut temp_graph_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let points = temp_graph_data
+                .iter()
+                .enumerate()
+                .filter(|(_, sensor)| {
+                    !temp_graph_widget_state
+                        .hidden_sensors
+                        .contains(&sensor.name)
+                })
+                .map(|(index, sensor)| GraphData {
+                    points: &sensor.temp_data[..],
+                    style: self.temp_graph_style(index),
+                    name: None,
+                    filled: false,
+                })
+                .collect::<Vec<_>>();
+
+            // Scale to the hottest shown temperature in the time range, rounded up to the next ten degrees
+            // with some room above it.
+            let max_temp = points
+                .iter()
+                .flat_map(|sensor| sensor.points.iter())
+                .filter(|(time, _)| *time >= time_start)
+                .map(|(_, temp)| *temp)
+                .max_by(|a, b| partial_ordering(a, b))
+                .unwrap_or(0.0);
+            let max_range = (max_temp / 10.0).ceil() * 10.0 + 10.0;
+
+            let unit = temperature_unit(&app_state.app_config_fields.temperature_type);
+            let y_labels: Vec<Cow<'_, str>> = vec![
+                concat_string!("0", unit).into(),
+                concat_string!(((max_range / 2.0) as u64).to_string(), unit).into(),
+                concat_string!((max_range as u64).to_string(), unit).into(),
+            ];
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds: [0.0, max_range],
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: " Temperature ".into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: None,
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+    }
+
+    fn draw_temp_graph_legend<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(temp_graph_widget_state) = app_state
+            .temp_graph_state
+            .widget_states
+            .get_mut(&(widget_id - 1))
+        {
+            temp_graph_widget_state.is_legend_hidden = false;
+
+            let temp_graph_data = {
+                let mut col_widths = vec![0; 2];
+                let data = app_state
+                    .converted_data
+                    .temp_graph_data
+                    .iter()
+                    .enumerate()
+                    .map(|(index, sensor)| {
+                        let row = vec![
+                            CellContent::Simple(sensor.name.clone().into()),
+                            CellContent::Simple(sensor.legend_value.clone().into()),
+                        ];
+                        col_widths.iter_mut().zip(&row).for_each(|(curr, r)| {
+                            *curr = std::cmp::max(*curr, r.len());
+                        });
+
+                        // Sensors that are toggled off are greyed out.
+                        let style = if temp_graph_widget_state
+                            .hidden_sensors
+                            .contains(&sensor.name)
+                        {
+                            self.colours.disabled_text_style
+                        } else {
+                            self.temp_graph_style(index)
+                        };
+                        TableRow::Styled(row, style)
+                    })
+                    .collect();
+
+                TableData { data, col_widths }
+            };
+
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let border_style = if is_on_widget {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            TextTable {
+                table_gap: app_state.app_config_fields.table_gap,
+                is_force_redraw: app_state.is_force_redraw,
+                recalculate_column_widths,
+                header_style: self.colours.table_header_style,
+                border_style,
+                highlighted_text_style: self.colours.currently_selected_text_style,
+                title: None,
+                is_on_widget,
+                draw_border: true,
+                show_table_scroll_position: app_state.app_config_fields.show_table_scroll_position,
+                title_style: self.colours.widget_title_style,
+                text_style: self.colours.text_style,
+                left_to_right: false,
+            }
+            .draw_text_table(
+                f,
+                draw_loc,
+                &mut temp_graph_widget_state.table_state,
+                &temp_graph_data,
+                None,
+            );
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use tui::{backend::Backend, layout::Rect, terminal::Frame};
 
 use crate::{
-    app,
+    app::{self, data_harvester::temperature::TempLevel},
     canvas::Painter,
     components::text_table::{TextTable, TextTableTitle},
+    data_conversion::{TableData, TableRow},
 };
 
 impl Painter {
@@ -23,6 +24,34 @@ impl Painter {
             } else {
                 (self.colours.border_style, self.colours.text_style)
             };
+
+            // Sensors at or above their warning or critical temperature stand out.
+            let temp_sensor_data = &app_state.converted_data.temp_sensor_data;
+            let table_data = TableData {
+                data: temp_sensor_data
+                    .data
+                    .iter()
+                    .zip(
+                        app_state
+                            .converted_data
+                            .temp_sensor_levels
+                            .iter()
+                            .copied()
+                            .chain(std::iter::repeat(TempLevel::Normal)),
+                    )
+                    .map(|(row, level)| match level {
+                        TempLevel::Normal => TableRow::Raw(row.row().to_vec()),
+                        TempLevel::Warning => {
+                            TableRow::Styled(row.row().to_vec(), self.colours.temp_warning_style)
+                        }
+                        TempLevel::Critical => {
+                            TableRow::Styled(row.row().to_vec(), self.colours.temp_critical_style)
+                        }
+                    })
+                    .collect(),
+                col_widths: temp_sensor_data.col_widths.clone(),
+            };
+
             TextTable {
                 table_gap: app_state.app_config_fields.table_gap,
                 is_force_redraw: app_state.is_force_redraw,
@@ -45,7 +74,7 @@ impl Painter {
                 f,
                 draw_loc,
                 &mut temp_widget_state.table_state,
-                &app_state.converted_data.temp_sensor_data,
+                &table_data,
                 app_state.widget_map.get_mut(&widget_id),
             );
         }
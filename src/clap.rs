@@ -42,8 +42,22 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|        temp_graph        |
++--------------------------+
 |           disk           |
 +--------------------------+
+|        disk_io, io       |
++--------------------------+
+|  interface, interfaces   |
++--------------------------+
+|      cgroup, cgroups     |
++--------------------------+
+|      sensor, sensors     |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|      cpu_freq, freq      |
++--------------------------+
 |       batt, battery      |
 +--------------------------+
 "
@@ -75,8 +89,22 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|        temp_graph        |
++--------------------------+
 |           disk           |
 +--------------------------+
+|        disk_io, io       |
++--------------------------+
+|  interface, interfaces   |
++--------------------------+
+|      cgroup, cgroups     |
++--------------------------+
+|      sensor, sensors     |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|      cpu_freq, freq      |
++--------------------------+
 "
 };
 
@@ -175,12 +203,31 @@ pub fn build_app() -> Command<'static> {
         .help("Show processes as their commands by default.")
         .long_help("Show processes as their commands by default in the process widget.");
 
+    let show_pss = Arg::new("show_pss")
+        .long("show_pss")
+        .help("Shows the PSS column in the process widget.")
+        .long_help("Shows the proportional set size (PSS) column in the process widget. Reading the PSS of every process is slow, so it is hidden and not collected by default. Only supported on Linux.");
+
+    let show_cpu_history = Arg::new("show_cpu_history")
+        .long("show_cpu_history")
+        .help("Shows the CPU history column in the process widget.")
+        .long_help("Shows a column in the process widget with a small graph of the recent CPU usage of each process, scaled to the highest usage of each row.");
+
     let left_legend = Arg::new("left_legend")
         .short('l')
         .long("left_legend")
         .help("Puts the CPU chart legend to the left side.")
         .long_help("Puts the CPU chart legend to the left side rather than the right side.");
 
+    let record = Arg::new("record")
+        .long("record")
+        .takes_value(true)
+        .value_name("FILE")
+        .help("Records collected data to a file for replaying later.")
+        .long_help(
+            "Appends all collected data to the given file while running, so it can be looked at later using --replay.",
+        );
+
     let regex = Arg::new("regex")
         .short('R')
         .long("regex")
@@ -197,6 +244,47 @@ pub fn build_app() -> Command<'static> {
         .help("Shows the scroll position tracker in table widgets.")
         .long_help("Shows the list scroll position tracker in the widget title for table widgets.");
 
+    let replay = Arg::new("replay")
+        .long("replay")
+        .takes_value(true)
+        .value_name("FILE")
+        .conflicts_with_all(&["record", "serve_metrics", "snapshot"])
+        .help("Replays data recorded with --record instead of collecting data.")
+        .long_help(
+            "Replays data recorded with --record instead of collecting data, at the same pace it was recorded. \
+            While replaying, Space pauses and resumes, < and > slow down and speed up, and [ and ] seek \
+            backward and forward.",
+        );
+
+    let serve_metrics = Arg::new("serve_metrics")
+        .long("serve_metrics")
+        .visible_alias("serve-metrics")
+        .takes_value(true)
+        .value_name("ADDR")
+        .conflicts_with("snapshot")
+        .help("Serves collected data as OpenMetrics over HTTP at the given address.")
+        .long_help(
+            "Collects data without drawing anything, and serves the latest data in the OpenMetrics text format over HTTP at the given address (for example, 127.0.0.1:9090). Data is served at the /metrics path, and is collected at the refresh rate.",
+        );
+
+    let metrics_processes = Arg::new("metrics_processes")
+        .long("metrics_processes")
+        .takes_value(true)
+        .value_name("N")
+        .requires("serve_metrics")
+        .help("Exports the top N processes by CPU usage when serving metrics.")
+        .long_help(
+            "Exports the CPU and memory usage of the top N processes, sorted by CPU usage, when serving metrics. Defaults to 0, which exports no processes.",
+        );
+
+    let snapshot = Arg::new("snapshot")
+        .long("snapshot")
+        .visible_alias("json")
+        .help("Prints a snapshot of the collected data as JSON and exits.")
+        .long_help(
+            "Collects data once without drawing anything, prints it to stdout as JSON, and then exits. The time between the collection passes is the refresh rate.",
+        );
+
     let use_old_network_legend = Arg::new("use_old_network_legend")
         .long("use_old_network_legend")
         .help("DEPRECATED - uses a separate network legend.")
@@ -262,6 +350,28 @@ Defaults to \"default\".
 ",
         );
 
+    let log_csv = Arg::new("log_csv")
+        .long("log_csv")
+        .visible_alias("log-csv")
+        .takes_value(true)
+        .value_name("DIR")
+        .conflicts_with_all(&["replay", "serve_metrics", "snapshot"])
+        .help("Logs collected data to CSV files in the given directory.")
+        .long_help(
+            "Appends collected data to CSV files in the given directory while running, with one file for \
+            each of the CPU, memory, network, disk, temperature, and process data. The directory is created \
+            if it does not exist.",
+        );
+
+    let log_tsv = Arg::new("log_tsv")
+        .long("log_tsv")
+        .visible_alias("log-tsv")
+        .takes_value(true)
+        .value_name("DIR")
+        .conflicts_with_all(&["log_csv", "replay", "serve_metrics", "snapshot"])
+        .help("Logs collected data to TSV files in the given directory.")
+        .long_help("The same as --log_csv, but the files are tab-separated instead.");
+
     let mem_as_value = Arg::new("mem_as_value")
         .long("mem_as_value")
         .help("Defaults to showing process memory usage by value.")
@@ -379,10 +489,19 @@ use CPU (3) as the default instead.
         .arg(hide_table_gap)
         .arg(hide_time)
         .arg(show_table_scroll_position)
+        .arg(show_pss)
+        .arg(show_cpu_history)
         .arg(left_legend)
+        .arg(log_csv)
+        .arg(log_tsv)
+        .arg(metrics_processes)
         .arg(disable_advanced_kill)
         .arg(rate)
+        .arg(record)
         .arg(regex)
+        .arg(replay)
+        .arg(serve_metrics)
+        .arg(snapshot)
         .arg(time_delta)
         .arg(tree)
         .arg(network_use_bytes)
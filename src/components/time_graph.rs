@@ -23,6 +23,8 @@ pub struct GraphData<'a> {
     pub points: &'a [Point],
     pub style: Style,
     pub name: Option<Cow<'a, str>>,
+    /// Whether to fill the area under the points, which is drawn over by any data drawn after it.
+    pub filled: bool,
 }
 
 pub struct TimeGraph<'a> {
@@ -169,13 +171,15 @@ fn create_dataset<'a>(data: &'a GraphData<'a>, marker: Marker) -> Dataset<'a> {
         points,
         style,
         name,
+        filled,
     } = data;
 
     let dataset = Dataset::default()
         .style(*style)
         .data(points)
         .graph_type(GraphType::Line)
-        .marker(marker);
+        .marker(marker)
+        .filled(*filled);
 
     if let Some(name) = name {
         dataset.name(name.as_ref())
@@ -77,6 +77,8 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Whether to fill the area under the points, for stacked graphs
+    filled: bool,
 }
 
 impl<'a> Default for Dataset<'a> {
@@ -87,6 +89,7 @@ impl<'a> Default for Dataset<'a> {
             marker: symbols::Marker::Dot,
             graph_type: GraphType::Scatter,
             style: Style::default(),
+            filled: false,
         }
     }
 }
@@ -120,6 +123,11 @@ impl<'a> Dataset<'a> {
         self.style = style;
         self
     }
+
+    pub fn filled(mut self, filled: bool) -> Dataset<'a> {
+        self.filled = filled;
+        self
+    }
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -433,6 +441,30 @@ impl<'a> Widget for TimeChart<'a> {
 
                     let data_slice = &dataset.data[start_index..end_index];
 
+                    if dataset.filled {
+                        // Fill each column the canvas can draw at its resolution, so there are no gaps between
+                        // the points.
+                        let columns = usize::from(graph_area.width)
+                            * match dataset.marker {
+                                symbols::Marker::Braille => 2,
+                                _ => 1,
+                            };
+                        let width = end_bound - start_bound;
+
+                        for column in 0..columns {
+                            let x = start_bound + (column as f64 + 0.5) / columns as f64 * width;
+                            if let Some(y) = value_at(dataset.data, x) {
+                                ctx.draw(&Line {
+                                    x1: x,
+                                    y1: self.y_axis.bounds[0],
+                                    x2: x,
+                                    y2: y,
+                                    color: dataset.style.fg.unwrap_or(Color::Reset),
+                                });
+                            }
+                        }
+                    }
+
                     ctx.draw(&Points {
                         coords: data_slice,
                         color: dataset.style.fg.unwrap_or(Color::Reset),
@@ -597,6 +629,18 @@ fn interpolate_point(older_point: &(f64, f64), newer_point: &(f64, f64), x: f64)
     (older_point.1 + (x - older_point.0) * slope).max(0.0)
 }
 
+/// Returns the y-axis value at `x`, interpolated between the points on either side of it. Returns `None` if `x` is
+/// outside of `data`.
+fn value_at(data: &[(f64, f64)], x: f64) -> Option<f64> {
+    match data.binary_search_by(|(point_x, _y)| partial_ordering(point_x, &x)) {
+        Ok(index) => Some(data[index].1),
+        Err(index) if index > 0 && index < data.len() => {
+            Some(interpolate_point(&data[index - 1], &data[index], x))
+        }
+        Err(_) => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -615,6 +659,19 @@ mod test {
         assert_eq!(interpolate_point(&data[0], &data[1], -3.0), 8.0);
     }
 
+    #[test]
+    fn time_chart_test_value_at() {
+        let data = [(-3.0, 8.0), (-1.0, 6.0), (0.0, 5.0)];
+
+        assert_eq!(value_at(&data, -4.0), None);
+        assert_eq!(value_at(&data, -3.0), Some(8.0));
+        assert_eq!(value_at(&data, -2.0), Some(7.0));
+        assert_eq!(value_at(&data, -0.5), Some(5.5));
+        assert_eq!(value_at(&data, 0.0), Some(5.0));
+        assert_eq!(value_at(&data, 1.0), None);
+        assert_eq!(value_at(&[], 0.0), None);
+    }
+
     #[test]
     fn time_chart_empty_dataset() {
         let data = [];
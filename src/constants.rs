@@ -1,4 +1,10 @@
-use crate::options::ConfigColours;
+use crate::{
+    app::keybindings::{
+        Action,
+        HelpEntry::{self, Keys, Text},
+    },
+    options::ConfigColours,
+};
 use once_cell::sync::Lazy;
 
 // Default widget ID
@@ -18,6 +24,17 @@ pub const TICK_RATE_IN_MILLISECONDS: u64 = 200;
 pub const DEFAULT_REFRESH_RATE_IN_MILLISECONDS: u64 = 1000;
 pub const MAX_KEY_TIMEOUT_IN_MILLISECONDS: u64 = 1000;
 
+// How many times data is collected before a snapshot is printed
+pub const SNAPSHOT_UPDATE_PASSES: u64 = 1;
+
+// How far a replay jumps when seeking
+pub const REPLAY_SEEK_MILLISECONDS: u64 = 10 * 1000;
+// The slowest and fastest speeds a replay can run at
+pub const REPLAY_MIN_SPEED: f64 = 0.25;
+pub const REPLAY_MAX_SPEED: f64 = 16.0;
+// How often a recording is flushed to disk
+pub const RECORDING_FLUSH_MILLISECONDS: u64 = 5 * 1000;
+
 // Limits for when we should stop showing table gaps/labels (anything less means not shown)
 pub const TABLE_GAP_HEIGHT_LIMIT: u16 = 7;
 pub const TIME_LABEL_HEIGHT_LIMIT: u16 = 7;
@@ -40,6 +57,8 @@ pub static DEFAULT_LIGHT_MODE_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(||
     disabled_text_color: Some("gray".to_string()),
     ram_color: Some("blue".to_string()),
     swap_color: Some("red".to_string()),
+    buffers_color: Some("cyan".to_string()),
+    cache_color: Some("green".to_string()),
     rx_color: Some("blue".to_string()),
     tx_color: Some("red".to_string()),
     rx_total_color: Some("LightBlue".to_string()),
@@ -53,6 +72,13 @@ pub static DEFAULT_LIGHT_MODE_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(||
         "Blue".to_string(),
         "Red".to_string(),
     ]),
+    cpu_time_colors: Some(vec![
+        "green".to_string(),
+        "red".to_string(),
+        "yellow".to_string(),
+        "magenta".to_string(),
+        "blue".to_string(),
+    ]),
     ..ConfigColours::default()
 });
 
@@ -82,8 +108,17 @@ pub static GRUVBOX_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColo
         "#d65d03".to_string(),
         "#af3a03".to_string(),
     ]),
+    cpu_time_colors: Some(vec![
+        "#b8bb26".to_string(),
+        "#fb4934".to_string(),
+        "#fabd2f".to_string(),
+        "#d3869b".to_string(),
+        "#83a598".to_string(),
+    ]),
     ram_color: Some("#8ec07c".to_string()),
     swap_color: Some("#fabd2f".to_string()),
+    buffers_color: Some("#83a598".to_string()),
+    cache_color: Some("#b8bb26".to_string()),
     rx_color: Some("#8ec07c".to_string()),
     tx_color: Some("#fabd2f".to_string()),
     rx_total_color: Some("#689d6a".to_string()),
@@ -99,6 +134,10 @@ pub static GRUVBOX_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColo
     high_battery_color: Some("#98971a".to_string()),
     medium_battery_color: Some("#fabd2f".to_string()),
     low_battery_color: Some("#fb4934".to_string()),
+    temp_warning_color: Some("#fabd2f".to_string()),
+    temp_critical_color: Some("#fb4934".to_string()),
+    stopped_process_color: Some("#d3869b".to_string()),
+    marked_process_color: Some("#fe8019".to_string()),
 });
 
 pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -127,8 +166,17 @@ pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| Conf
         "#d65d03".to_string(),
         "#af3a03".to_string(),
     ]),
+    cpu_time_colors: Some(vec![
+        "#79740e".to_string(),
+        "#9d0006".to_string(),
+        "#b57614".to_string(),
+        "#8f3f71".to_string(),
+        "#076678".to_string(),
+    ]),
     ram_color: Some("#427b58".to_string()),
     swap_color: Some("#cc241d".to_string()),
+    buffers_color: Some("#076678".to_string()),
+    cache_color: Some("#79740e".to_string()),
     rx_color: Some("#427b58".to_string()),
     tx_color: Some("#cc241d".to_string()),
     rx_total_color: Some("#689d6a".to_string()),
@@ -144,6 +192,10 @@ pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| Conf
     high_battery_color: Some("#98971a".to_string()),
     medium_battery_color: Some("#d79921".to_string()),
     low_battery_color: Some("#cc241d".to_string()),
+    temp_warning_color: Some("#d79921".to_string()),
+    temp_critical_color: Some("#cc241d".to_string()),
+    stopped_process_color: Some("#8f3f71".to_string()),
+    marked_process_color: Some("#af3a03".to_string()),
 });
 
 pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -160,8 +212,17 @@ pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours
         "#d08770".to_string(),
         "#bf616a".to_string(),
     ]),
+    cpu_time_colors: Some(vec![
+        "#a3be8c".to_string(),
+        "#bf616a".to_string(),
+        "#ebcb8b".to_string(),
+        "#b48ead".to_string(),
+        "#5e81ac".to_string(),
+    ]),
     ram_color: Some("#88c0d0".to_string()),
     swap_color: Some("#d08770".to_string()),
+    buffers_color: Some("#5e81ac".to_string()),
+    cache_color: Some("#a3be8c".to_string()),
     rx_color: Some("#88c0d0".to_string()),
     tx_color: Some("#d08770".to_string()),
     rx_total_color: Some("#5e81ac".to_string()),
@@ -177,6 +238,10 @@ pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours
     high_battery_color: Some("#a3be8c".to_string()),
     medium_battery_color: Some("#ebcb8b".to_string()),
     low_battery_color: Some("#bf616a".to_string()),
+    temp_warning_color: Some("#ebcb8b".to_string()),
+    temp_critical_color: Some("#bf616a".to_string()),
+    stopped_process_color: Some("#b48ead".to_string()),
+    marked_process_color: Some("#d08770".to_string()),
 });
 
 pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -193,8 +258,17 @@ pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigC
         "#d08770".to_string(),
         "#bf616a".to_string(),
     ]),
+    cpu_time_colors: Some(vec![
+        "#a3be8c".to_string(),
+        "#bf616a".to_string(),
+        "#ebcb8b".to_string(),
+        "#b48ead".to_string(),
+        "#5e81ac".to_string(),
+    ]),
     ram_color: Some("#81a1c1".to_string()),
     swap_color: Some("#d08770".to_string()),
+    buffers_color: Some("#5e81ac".to_string()),
+    cache_color: Some("#a3be8c".to_string()),
     rx_color: Some("#81a1c1".to_string()),
     tx_color: Some("#d08770".to_string()),
     rx_total_color: Some("#5e81ac".to_string()),
@@ -210,159 +284,303 @@ pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigC
     high_battery_color: Some("#a3be8c".to_string()),
     medium_battery_color: Some("#ebcb8b".to_string()),
     low_battery_color: Some("#bf616a".to_string()),
+    temp_warning_color: Some("#ebcb8b".to_string()),
+    temp_critical_color: Some("#bf616a".to_string()),
+    stopped_process_color: Some("#b48ead".to_string()),
+    marked_process_color: Some("#d08770".to_string()),
 });
 
 // Help text
-pub const HELP_CONTENTS_TEXT: [&str; 8] = [
-    "Press the corresponding numbers to jump to the section, or scroll:",
-    "1 - General",
-    "2 - CPU widget",
-    "3 - Process widget",
-    "4 - Process search widget",
-    "5 - Process sort widget",
-    "6 - Battery widget",
-    "7 - Basic memory widget",
+pub const HELP_CONTENTS_TEXT: &[HelpEntry] = &[
+    Text("Press the corresponding numbers to jump to the section, or scroll:"),
+    Text("1 - General"),
+    Text("2 - CPU widget"),
+    Text("3 - Process widget"),
+    Text("4 - Process search widget"),
+    Text("5 - Process sort widget"),
+    Text("6 - Battery widget"),
+    Text("7 - Basic memory widget"),
+    Text("8 - Network interfaces widget"),
+    Text("9 - Cgroups widget"),
 ];
 
 // TODO [Help]: Search in help?
 // TODO [Help]: Move to using tables for easier formatting?
-pub const GENERAL_HELP_TEXT: [&str; 31] = [
-    "1 - General",
-    "q, Ctrl-c        Quit",
-    "Esc              Close dialog windows, search, widgets, or exit expanded mode",
-    "Ctrl-r           Reset display and any collected data",
-    "f                Freeze/unfreeze updating with new data",
-    "Ctrl-Left,       ",
-    "Shift-Left,      Move widget selection left",
-    "H, A             ",
-    "Ctrl-Right,      ",
-    "Shift-Right,     Move widget selection right",
-    "L, D             ",
-    "Ctrl-Up,         ",
-    "Shift-Up,        Move widget selection up",
-    "K, W             ",
-    "Ctrl-Down,       ",
-    "Shift-Down,      Move widget selection down",
-    "J, S             ",
-    "Left, h          Move left within widget",
-    "Down, j          Move down within widget",
-    "Up, k            Move up within widget",
-    "Right, l         Move right within widget",
-    "?                Open help menu",
-    "gg               Jump to the first entry",
-    "G                Jump to the last entry",
-    "e                Toggle expanding the currently selected widget",
-    "+                Zoom in on chart (decrease time range)",
-    "-                Zoom out on chart (increase time range)",
-    "=                Reset zoom",
-    "PgUp, PgDown     Scroll up/down a table by a page",
-    "Mouse scroll     Scroll through the tables or zoom in/out of charts by scrolling up/down",
-    "Mouse click      Selects the clicked widget, table entry, dialog option, or tab",
+pub const GENERAL_HELP_TEXT: &[HelpEntry] = &[
+    Text("1 - General"),
+    Keys(&[Action::Quit], &["Ctrl-c"], "Quit"),
+    Text("Esc              Close dialog windows, search, widgets, or exit expanded mode"),
+    Keys(
+        &[Action::Reset],
+        &[],
+        "Reset display and any collected data",
+    ),
+    Keys(
+        &[Action::Freeze],
+        &[],
+        "Freeze/unfreeze updating with new data",
+    ),
+    Keys(&[Action::MoveWidgetLeft], &[], "Move widget selection left"),
+    Keys(
+        &[Action::MoveWidgetRight],
+        &[],
+        "Move widget selection right",
+    ),
+    Keys(&[Action::MoveWidgetUp], &[], "Move widget selection up"),
+    Keys(&[Action::MoveWidgetDown], &[], "Move widget selection down"),
+    Keys(&[Action::Left], &[], "Move left within widget"),
+    Keys(&[Action::Down], &[], "Move down within widget"),
+    Keys(&[Action::Up], &[], "Move up within widget"),
+    Keys(&[Action::Right], &[], "Move right within widget"),
+    Keys(&[Action::Help], &[], "Open help menu"),
+    Keys(&[Action::SkipToFirst], &[], "Jump to the first entry"),
+    Keys(&[Action::SkipToLast], &[], "Jump to the last entry"),
+    Keys(
+        &[Action::Expand],
+        &[],
+        "Toggle expanding the currently selected widget",
+    ),
+    Keys(
+        &[Action::ZoomIn],
+        &[],
+        "Zoom in on chart (decrease time range)",
+    ),
+    Keys(
+        &[Action::ZoomOut],
+        &[],
+        "Zoom out on chart (increase time range)",
+    ),
+    Keys(&[Action::ResetZoom], &[], "Reset zoom"),
+    Keys(
+        &[Action::PageUp, Action::PageDown],
+        &[],
+        "Scroll up/down a table by a page",
+    ),
+    Text(
+        "Mouse scroll     Scroll through the tables or zoom in/out of charts by scrolling up/down",
+    ),
+    Text("Mouse click      Selects the clicked widget, table entry, dialog option, or tab"),
+    Keys(&[Action::ReplayPause], &[], "Pause/resume a replay"),
+    Keys(
+        &[Action::ReplaySlowDown, Action::ReplaySpeedUp],
+        &[],
+        "Slow down/speed up a replay",
+    ),
+    Keys(
+        &[Action::ReplaySeekBackward, Action::ReplaySeekForward],
+        &[],
+        "Seek backward/forward in a replay",
+    ),
+];
+
+pub const CPU_HELP_TEXT: &[HelpEntry] = &[
+    Text("2 - CPU widget\n"),
+    Text("Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart"),
+    Keys(
+        &[Action::CycleCpuGrouping],
+        &[],
+        "Group CPUs by NUMA node, socket, or physical core, or not at all",
+    ),
+    Text("Enter            Expand/collapse the selected group of CPUs in the legend"),
+];
+
+pub const PROCESS_HELP_TEXT: &[HelpEntry] = &[
+    Text("3 - Process widget"),
+    Keys(&[Action::KillProcess], &[], "Kill the selected process"),
+    Keys(
+        &[Action::KillProcessTree],
+        &[],
+        "Kill the selected process and all of its descendants",
+    ),
+    Keys(&[Action::StopProcess], &[], "Pause the selected process"),
+    Keys(
+        &[Action::ContinueProcess],
+        &[],
+        "Continue the selected process if it was paused",
+    ),
+    Keys(
+        &[Action::ChangePriority],
+        &[],
+        "Change the nice value and I/O priority of the selected process",
+    ),
+    Keys(
+        &[Action::ChangeAffinity],
+        &[],
+        "Change the cores the selected process may run on",
+    ),
+    Keys(
+        &[Action::ShowProcessDetails],
+        &[],
+        "Show the details of the selected process",
+    ),
+    Keys(
+        &[Action::ToggleMark],
+        &[],
+        "Mark the selected process, so actions apply to all marked processes",
+    ),
+    Keys(&[Action::ClearMarks], &[], "Unmark all processes"),
+    Keys(
+        &[Action::SortByCpu],
+        &[],
+        "Sort by CPU usage, press again to reverse sorting order",
+    ),
+    Keys(
+        &[Action::SortByMem],
+        &[],
+        "Sort by memory usage, press again to reverse sorting order",
+    ),
+    Keys(
+        &[Action::SortByPid],
+        &[],
+        "Sort by PID name, press again to reverse sorting order",
+    ),
+    Keys(
+        &[Action::SortByName],
+        &[],
+        "Sort by process name, press again to reverse sorting order",
+    ),
+    Text("Tab              Group/un-group processes with the same name"),
+    Keys(&[Action::Search], &[], "Open process search widget"),
+    Keys(
+        &[Action::ToggleCommand],
+        &[],
+        "Toggle between showing the full command or just the process name",
+    ),
+    Keys(&[Action::Sort], &[], "Open process sort widget"),
+    Keys(&[Action::InvertSort], &[], "Invert current sort"),
+    Keys(
+        &[Action::TogglePercentages],
+        &[],
+        "Toggle between values and percentages for memory usage",
+    ),
+    Keys(&[Action::ToggleTree], &[], "Toggle tree mode"),
+    Keys(
+        &[Action::ZoomIn, Action::ZoomOut],
+        &["click"],
+        "Collapse/expand a branch while in tree mode",
+    ),
+    Keys(
+        &[Action::ToggleThreads],
+        &[],
+        "Show/hide the threads of the selected process",
+    ),
+    Keys(
+        &[Action::ToggleCgroupGrouping],
+        &[],
+        "Toggle grouping processes by container or systemd unit",
+    ),
+    Text("click on header  Sorts the entries by that column, click again to invert the sort"),
 ];
 
-pub const CPU_HELP_TEXT: [&str; 2] = [
-    "2 - CPU widget\n",
-    "Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart",
+pub const SEARCH_HELP_TEXT: &[HelpEntry] = &[
+    Text("4 - Process search widget"),
+    Text("Esc              Close the search widget (retains the filter)"),
+    Text("Ctrl-a           Skip to the start of the search query"),
+    Text("Ctrl-e           Skip to the end of the search query"),
+    Text("Ctrl-u           Clear the current search query"),
+    Text("Ctrl-w           Delete a word behind the cursor"),
+    Text("Ctrl-h           Delete the character behind the cursor"),
+    Text("Backspace        Delete the character behind the cursor"),
+    Text("Delete           Delete the character at the cursor"),
+    Text("Alt-c, F1        Toggle matching case"),
+    Text("Alt-w, F2        Toggle matching the entire word"),
+    Text("Alt-r, F3        Toggle using regex"),
+    Text("Left, Alt-h      Move cursor left"),
+    Text("Right, Alt-l     Move cursor right"),
+    Text(""),
+    Text("Supported search types:"),
+    Text("<by name/cmd>    ex: btm"),
+    Text("pid              ex: pid 825"),
+    Text("cpu, cpu%        ex: cpu > 4.2"),
+    Text("mem, mem%        ex: mem < 4.2"),
+    Text("memb             ex: memb < 100 kb"),
+    Text("read, r/s        ex: read >= 1 b"),
+    Text("write, w/s       ex: write <= 1 tb"),
+    Text("tread, t.read    ex: tread = 1"),
+    Text("twrite, t.write  ex: twrite = 1"),
+    Text("virt             ex: virt > 1 gib"),
+    Text("rss              ex: rss > 100 mib"),
+    Text("shared           ex: shared > 10 mib"),
+    Text("swap             ex: swap > 0"),
+    Text("pss              ex: pss < 100 mib"),
+    Text("user            ex: user = root"),
+    Text("state            ex: state = running"),
+    Text("cgroup           ex: cgroup = system.slice"),
+    Text("container        ex: container = 3f4e1d2c"),
+    Text("unit             ex: unit = sshd.service"),
+    Text(""),
+    Text("Comparison operators:"),
+    Text("=                ex: cpu = 1"),
+    Text(">                ex: cpu > 1"),
+    Text("<                ex: cpu < 1"),
+    Text(">=               ex: cpu >= 1"),
+    Text("<=               ex: cpu <= 1"),
+    Text(""),
+    Text("Logical operators:"),
+    Text("and, &&, <Space> ex: btm and cpu > 1 and mem > 1"),
+    Text("or, ||           ex: btm or firefox"),
+    Text(""),
+    Text("Supported units:"),
+    Text("B                ex: read > 1 b"),
+    Text("KB               ex: read > 1 kb"),
+    Text("MB               ex: read > 1 mb"),
+    Text("TB               ex: read > 1 tb"),
+    Text("KiB              ex: read > 1 kib"),
+    Text("MiB              ex: read > 1 mib"),
+    Text("GiB              ex: read > 1 gib"),
+    Text("TiB              ex: read > 1 tib"),
 ];
 
-pub const PROCESS_HELP_TEXT: [&str; 15] = [
-    "3 - Process widget",
-    "dd, F9           Kill the selected process",
-    "c                Sort by CPU usage, press again to reverse sorting order",
-    "m                Sort by memory usage, press again to reverse sorting order",
-    "p                Sort by PID name, press again to reverse sorting order",
-    "n                Sort by process name, press again to reverse sorting order",
-    "Tab              Group/un-group processes with the same name",
-    "Ctrl-f, /        Open process search widget",
-    "P                Toggle between showing the full command or just the process name",
-    "s, F6            Open process sort widget",
-    "I                Invert current sort",
-    "%                Toggle between values and percentages for memory usage",
-    "t, F5            Toggle tree mode",
-    "+, -, click      Collapse/expand a branch while in tree mode",
-    "click on header  Sorts the entries by that column, click again to invert the sort",
+pub const SORT_HELP_TEXT: &[HelpEntry] = &[
+    Text("5 - Sort widget\n"),
+    Keys(&[Action::Down], &[], "Scroll down in list"),
+    Keys(&[Action::Up], &[], "Scroll up in list"),
+    Text("Mouse scroll     Scroll through sort widget"),
+    Text("Esc              Close the sort widget"),
+    Text("Enter            Sort by current selected column"),
 ];
 
-pub const SEARCH_HELP_TEXT: [&str; 48] = [
-    "4 - Process search widget",
-    "Esc              Close the search widget (retains the filter)",
-    "Ctrl-a           Skip to the start of the search query",
-    "Ctrl-e           Skip to the end of the search query",
-    "Ctrl-u           Clear the current search query",
-    "Ctrl-w           Delete a word behind the cursor",
-    "Ctrl-h           Delete the character behind the cursor",
-    "Backspace        Delete the character behind the cursor",
-    "Delete           Delete the character at the cursor",
-    "Alt-c, F1        Toggle matching case",
-    "Alt-w, F2        Toggle matching the entire word",
-    "Alt-r, F3        Toggle using regex",
-    "Left, Alt-h      Move cursor left",
-    "Right, Alt-l     Move cursor right",
-    "",
-    "Supported search types:",
-    "<by name/cmd>    ex: btm",
-    "pid              ex: pid 825",
-    "cpu, cpu%        ex: cpu > 4.2",
-    "mem, mem%        ex: mem < 4.2",
-    "memb             ex: memb < 100 kb",
-    "read, r/s        ex: read >= 1 b",
-    "write, w/s       ex: write <= 1 tb",
-    "tread, t.read    ex: tread = 1",
-    "twrite, t.write  ex: twrite = 1",
-    "user            ex: user = root",
-    "state            ex: state = running",
-    "",
-    "Comparison operators:",
-    "=                ex: cpu = 1",
-    ">                ex: cpu > 1",
-    "<                ex: cpu < 1",
-    ">=               ex: cpu >= 1",
-    "<=               ex: cpu <= 1",
-    "",
-    "Logical operators:",
-    "and, &&, <Space> ex: btm and cpu > 1 and mem > 1",
-    "or, ||           ex: btm or firefox",
-    "",
-    "Supported units:",
-    "B                ex: read > 1 b",
-    "KB               ex: read > 1 kb",
-    "MB               ex: read > 1 mb",
-    "TB               ex: read > 1 tb",
-    "KiB              ex: read > 1 kib",
-    "MiB              ex: read > 1 mib",
-    "GiB              ex: read > 1 gib",
-    "TiB              ex: read > 1 tib",
+pub const BATTERY_HELP_TEXT: &[HelpEntry] = &[
+    Text("6 - Battery widget"),
+    Keys(&[Action::Left], &[], "Go to previous battery"),
+    Keys(&[Action::Right], &[], "Go to next battery"),
 ];
 
-pub const SORT_HELP_TEXT: [&str; 6] = [
-    "5 - Sort widget\n",
-    "Down, 'j'        Scroll down in list",
-    "Up, 'k'          Scroll up in list",
-    "Mouse scroll     Scroll through sort widget",
-    "Esc              Close the sort widget",
-    "Enter            Sort by current selected column",
+pub const BASIC_MEM_HELP_TEXT: &[HelpEntry] = &[
+    Text("7 - Basic memory widget"),
+    Keys(
+        &[Action::TogglePercentages],
+        &[],
+        "Toggle between values and percentages for memory usage",
+    ),
 ];
 
-pub const BATTERY_HELP_TEXT: [&str; 3] = [
-    "6 - Battery widget",
-    "Left             Go to previous battery",
-    "Right            Go to next battery",
+pub const NET_INTERFACES_HELP_TEXT: &[HelpEntry] = &[
+    Text("8 - Network interfaces widget"),
+    Text("Enter            Toggle graphing the selected interface in the network graph"),
 ];
 
-pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
-    "7 - Basic memory widget",
-    "%                Toggle between values and percentages for memory usage",
+pub const CGROUPS_HELP_TEXT: &[HelpEntry] = &[
+    Text("9 - Cgroups widget"),
+    Keys(
+        &[Action::ZoomIn, Action::ZoomOut],
+        &["Enter"],
+        "Collapse/expand the selected group",
+    ),
 ];
 
-pub const HELP_TEXT: &[&[&str]] = &[
-    &HELP_CONTENTS_TEXT,
-    &GENERAL_HELP_TEXT,
-    &CPU_HELP_TEXT,
-    &PROCESS_HELP_TEXT,
-    &SEARCH_HELP_TEXT,
-    &SORT_HELP_TEXT,
-    &BATTERY_HELP_TEXT,
-    &BASIC_MEM_HELP_TEXT,
+/// The sections of the help menu, where the keys of each action are filled in from the current key bindings.
+pub const HELP_TEXT: &[&[HelpEntry]] = &[
+    HELP_CONTENTS_TEXT,
+    GENERAL_HELP_TEXT,
+    CPU_HELP_TEXT,
+    PROCESS_HELP_TEXT,
+    SEARCH_HELP_TEXT,
+    SORT_HELP_TEXT,
+    BATTERY_HELP_TEXT,
+    BASIC_MEM_HELP_TEXT,
+    NET_INTERFACES_HELP_TEXT,
+    CGROUPS_HELP_TEXT,
 ];
 
 // Default layouts
@@ -487,6 +705,10 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #show_table_scroll_position = false
 # Show processes as their commands by default in the process widget.
 #process_command = false
+# Show the proportional set size (PSS) of processes in the process widget. Only supported on Linux.
+#show_pss = false
+# Show a small graph of the recent CPU usage of each process in the process widget.
+#show_cpu_history = false
 # Displays the network widget with binary prefixes.
 #network_use_binary_prefix = false
 # Displays the network widget using bytes.
@@ -508,10 +730,16 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #avg_cpu_color="Red"
 # Represents the colour the core will use in the CPU legend and graph.
 #cpu_core_colors=["LightMagenta", "LightYellow", "LightCyan", "LightGreen", "LightBlue", "LightRed", "Cyan", "Green", "Blue", "Red"]
+# Represents the colours of the user, system, IRQ, steal and IO wait time of a single CPU in the CPU graph.
+#cpu_time_colors=["LightGreen", "LightRed", "LightYellow", "LightMagenta", "LightBlue"]
 # Represents the colour RAM will use in the memory legend and graph.
 #ram_color="LightMagenta"
 # Represents the colour SWAP will use in the memory legend and graph.
 #swap_color="LightYellow"
+# Represents the colour the buffers will use in the memory legend and graph.
+#buffers_color="LightCyan"
+# Represents the colour the page cache will use in the memory legend and graph.
+#cache_color="LightGreen"
 # Represents the colour rx will use in the network legend and graph.
 #rx_color="LightCyan"
 # Represents the colour tx will use in the network legend and graph.
@@ -532,6 +760,13 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #high_battery_color="green"
 #medium_battery_color="yellow"
 #low_battery_color="red"
+# Represents the colours of temperatures at or above their warning and critical temperatures
+#temp_warning_color="yellow"
+#temp_critical_color="red"
+# Represents the colour of processes that are stopped
+#stopped_process_color="magenta"
+# Represents the colour of processes that are marked
+#marked_process_color="LightYellow"
 
 # Layout - layouts follow a pattern like this:
 # [[row]] represents a row in the application.
@@ -588,12 +823,34 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #case_sensitive = false
 #whole_word = false
 
+#[sensor_filter]
+#is_list_ignored = true
+#list = ["RAPL"]
+#regex = false
+#case_sensitive = false
+#whole_word = false
+
 #[net_filter]
 #is_list_ignored = true
 #list = ["virbr0.*"]
 #regex = true
 #case_sensitive = false
 #whole_word = false
+
+# Temperatures at or above a sensor's warning or critical temperature are highlighted.  These replace the limits
+# reported by the sensors whose names match, and are in the unit set by temperature_type.
+#[[temp_threshold]]
+#sensor = "k10temp"
+#warning = 75
+#critical = 90
+#regex = false
+#case_sensitive = false
+#whole_word = false
+
+# Actions can be bound to different keys.  The keys given to an action replace its defaults.
+#[keybindings]
+#freeze = "F"
+#kill_process = ["d d", "F9"]
 "##;
 
 pub const CONFIG_TOP_HEAD: &str = r##"# This is bottom's config file.
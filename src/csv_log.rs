@@ -0,0 +1,371 @@
+//! Logs harvested data to CSV (or TSV) files, one per subsystem, so it can be graphed or analyzed afterwards.
+//!
+//! Each file gets one row per entry per collection, starting with the time the data was collected.
+
+use std::{
+    borrow::Cow,
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+    sync::mpsc,
+    thread,
+    time::Instant,
+};
+
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    app::data_harvester::{disks, temperature::TemperatureType, Data},
+    utils::error::{self, BottomError},
+};
+
+const CPU_HEADER: &[&str] = &["time", "cpu", "usage_percent"];
+const MEM_HEADER: &[&str] = &[
+    "time",
+    "mem_used_bytes",
+    "mem_total_bytes",
+    "mem_used_percent",
+    "swap_used_bytes",
+    "swap_total_bytes",
+    "swap_used_percent",
+];
+const NET_HEADER: &[&str] = &[
+    "time",
+    "rx_bits_per_sec",
+    "tx_bits_per_sec",
+    "total_rx_bits",
+    "total_tx_bits",
+];
+const DISKS_HEADER: &[&str] = &[
+    "time",
+    "disk",
+    "mount",
+    "used_bytes",
+    "free_bytes",
+    "total_bytes",
+    "total_read_bytes",
+    "total_write_bytes",
+];
+const TEMPS_HEADER: &[&str] = &["time", "sensor", "temperature", "unit"];
+const PROCESSES_HEADER: &[&str] = &[
+    "time",
+    "pid",
+    "parent_pid",
+    "name",
+    "command",
+    "cpu_percent",
+    "mem_percent",
+    "mem_bytes",
+    "read_bytes_per_sec",
+    "write_bytes_per_sec",
+    "total_read_bytes",
+    "total_write_bytes",
+    "state",
+];
+
+/// Quotes a field if it contains the delimiter, a quote, or a newline.
+fn escape_field(field: &str, delimiter: char) -> Cow<'_, str> {
+    if field.contains(&[delimiter, '"', '\n', '\r'][..]) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Formats an optional value, leaving the field empty if there is none.
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// A single log file.
+struct LogFile {
+    writer: BufWriter<File>,
+    delimiter: char,
+}
+
+impl LogFile {
+    /// Opens a log file to append to, writing the header if the file is new.
+    fn open(path: &Path, header: &[&str], delimiter: char) -> error::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let is_new = file.metadata()?.len() == 0;
+
+        let mut log_file = LogFile {
+            writer: BufWriter::new(file),
+            delimiter,
+        };
+        if is_new {
+            log_file.write_row(header)?;
+            log_file.writer.flush()?;
+        }
+
+        Ok(log_file)
+    }
+
+    fn write_row<S: AsRef<str>>(&mut self, fields: &[S]) -> error::Result<()> {
+        for (index, field) in fields.iter().enumerate() {
+            if index > 0 {
+                write!(self.writer, "{}", self.delimiter)?;
+            }
+            write!(
+                self.writer,
+                "{}",
+                escape_field(field.as_ref(), self.delimiter)
+            )?;
+        }
+        writeln!(self.writer)?;
+
+        Ok(())
+    }
+}
+
+/// Logs harvested data to a directory of CSV or TSV files.
+pub struct CsvLog {
+    temperature_type: TemperatureType,
+    cpu: LogFile,
+    mem: LogFile,
+    net: LogFile,
+    disks: LogFile,
+    temps: LogFile,
+    processes: LogFile,
+}
+
+impl CsvLog {
+    /// Creates the directory if needed, and opens all the log files in it. If `delimiter` is a tab, the files
+    /// are given a `.tsv` extension, and otherwise a `.csv` extension.
+    pub fn new(
+        dir: &Path, delimiter: char, temperature_type: TemperatureType,
+    ) -> error::Result<Self> {
+        fs::create_dir_all(dir)?;
+
+        let extension = if delimiter == '\t' { "tsv" } else { "csv" };
+        let open = |name: &str, header: &[&str]| {
+            LogFile::open(
+                &dir.join(format!("{}.{}", name, extension)),
+                header,
+                delimiter,
+            )
+        };
+
+        Ok(CsvLog {
+            temperature_type,
+            cpu: open("cpu", CPU_HEADER)?,
+            mem: open("mem", MEM_HEADER)?,
+            net: open("net", NET_HEADER)?,
+            disks: open("disks", DISKS_HEADER)?,
+            temps: open("temps", TEMPS_HEADER)?,
+            processes: open("processes", PROCESSES_HEADER)?,
+        })
+    }
+
+    /// Appends the rows for a single harvest to each file.
+    pub fn log(&mut self, data: &Data) -> error::Result<()> {
+        let time = (OffsetDateTime::now_utc()
+            - Instant::now().saturating_duration_since(data.last_collection_time))
+        .format(&Rfc3339)
+        .map_err(|err| BottomError::ConversionError(err.to_string()))?;
+
+        if let Some(cpu) = &data.cpu {
+            for cpu_data in cpu {
+                let cpu_label = match cpu_data.cpu_count {
+                    Some(count) => count.to_string(),
+                    None => cpu_data.cpu_prefix.to_lowercase(),
+                };
+                self.cpu
+                    .write_row(&[time.clone(), cpu_label, cpu_data.cpu_usage.to_string()])?;
+            }
+        }
+
+        if data.memory.is_some() || data.swap.is_some() {
+            let mut row = vec![time.clone()];
+            for harvest in [&data.memory, &data.swap] {
+                match harvest {
+                    Some(harvest) => row.extend([
+                        (harvest.mem_used_in_kib * 1024).to_string(),
+                        (harvest.mem_total_in_kib * 1024).to_string(),
+                        optional(harvest.use_percent),
+                    ]),
+                    None => row.extend([String::new(), String::new(), String::new()]),
+                }
+            }
+            self.mem.write_row(&row)?;
+        }
+
+        if let Some(network) = &data.network {
+            self.net.write_row(&[
+                time.clone(),
+                network.rx.to_string(),
+                network.tx.to_string(),
+                network.total_rx.to_string(),
+                network.total_tx.to_string(),
+            ])?;
+        }
+
+        if let Some(disk_harvest) = &data.disks {
+            for disk in disk_harvest {
+                let io = data
+                    .io
+                    .as_ref()
+                    .and_then(|io| disks::get_disk_io(io, &disk.name))
+                    .and_then(Option::as_ref);
+                self.disks.write_row(&[
+                    time.clone(),
+                    disk.name.clone(),
+                    disk.mount_point.clone(),
+                    optional(disk.used_space),
+                    optional(disk.free_space),
+                    optional(disk.total_space),
+                    optional(io.map(|io| io.read_bytes)),
+                    optional(io.map(|io| io.write_bytes)),
+                ])?;
+            }
+        }
+
+        if let Some(temperature_sensors) = &data.temperature_sensors {
+            let unit = match self.temperature_type {
+                TemperatureType::Celsius => "C",
+                TemperatureType::Kelvin => "K",
+                TemperatureType::Fahrenheit => "F",
+            };
+            for sensor in temperature_sensors {
+                self.temps.write_row(&[
+                    time.clone(),
+                    sensor.name.clone(),
+                    sensor.temperature.to_string(),
+                    unit.to_string(),
+                ])?;
+            }
+        }
+
+        if let Some(processes) = &data.list_of_processes {
+            for process in processes {
+                self.processes.write_row(&[
+                    time.clone(),
+                    process.pid.to_string(),
+                    optional(process.parent_pid),
+                    process.name.clone(),
+                    process.command.clone(),
+                    process.cpu_usage_percent.to_string(),
+                    process.mem_usage_percent.to_string(),
+                    process.mem_usage_bytes.to_string(),
+                    process.read_bytes_per_sec.to_string(),
+                    process.write_bytes_per_sec.to_string(),
+                    process.total_read_bytes.to_string(),
+                    process.total_write_bytes.to_string(),
+                    process.process_state.0.clone(),
+                ])?;
+            }
+        }
+
+        for log_file in [
+            &mut self.cpu,
+            &mut self.mem,
+            &mut self.net,
+            &mut self.disks,
+            &mut self.temps,
+            &mut self.processes,
+        ] {
+            log_file.writer.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Logs every harvest sent through `receiver` on a separate thread, so writing to the files never holds up
+/// drawing. The thread stops once the sender is dropped, or if writing fails.
+pub fn create_csv_log_thread(
+    mut csv_log: CsvLog, receiver: mpsc::Receiver<Box<Data>>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        while let Ok(data) = receiver.recv() {
+            if csv_log.log(&data).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use crate::{
+        app::data_harvester::{cpu::CpuData, processes::ProcessHarvest},
+        utils::test_util::FakeDir,
+    };
+
+    #[test]
+    fn test_escape_field() {
+        assert_eq!(escape_field("plain", ','), "plain");
+        assert_eq!(escape_field("a,b", ','), "\"a,b\"");
+        assert_eq!(escape_field("a,b", '\t'), "a,b");
+        assert_eq!(escape_field("say \"hi\"", '\t'), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_log() {
+        let delay = Duration::from_secs(5 * 60);
+        let last_collection_time = match Instant::now().checked_sub(delay) {
+            Some(last_collection_time) => last_collection_time,
+            // The monotonic clock can be younger than the delay on a freshly booted machine.
+            None => return,
+        };
+        let dir = FakeDir::default();
+
+        let data = Data {
+            last_collection_time,
+            cpu: Some(vec![CpuData {
+                cpu_prefix: "CPU".to_string(),
+                cpu_count: Some(0),
+                cpu_id: Some(0),
+                cpu_usage: 50.0,
+                breakdown: None,
+                frequency: None,
+                topology: None,
+            }]),
+            list_of_processes: Some(vec![ProcessHarvest {
+                pid: 1,
+                name: "init".to_string(),
+                command: "/sbin/init --flag, with comma".to_string(),
+                ..ProcessHarvest::default()
+            }]),
+            ..Data::default()
+        };
+
+        let mut csv_log = CsvLog::new(dir.path(), ',', TemperatureType::Celsius).unwrap();
+        csv_log.log(&data).unwrap();
+        drop(csv_log);
+
+        // Reopening the files shouldn't write the header again.
+        let mut csv_log = CsvLog::new(dir.path(), ',', TemperatureType::Celsius).unwrap();
+        csv_log.log(&data).unwrap();
+
+        let cpu = fs::read_to_string(dir.join("cpu.csv")).unwrap();
+        let processes = fs::read_to_string(dir.join("processes.csv")).unwrap();
+        let mem = fs::read_to_string(dir.join("mem.csv")).unwrap();
+
+        let cpu_lines = cpu.lines().collect::<Vec<_>>();
+        assert_eq!(cpu_lines.len(), 3);
+        assert_eq!(cpu_lines[0], "time,cpu,usage_percent");
+        assert!(cpu_lines[1].ends_with(",0,50"));
+
+        // Rows are stamped with when the data was collected, five minutes ago, not when they were logged.
+        let to_seconds = |time: OffsetDateTime| time.format(&Rfc3339).unwrap()[..19].to_string();
+        let logged = &cpu_lines[1][..19];
+        let now = OffsetDateTime::now_utc();
+        assert!(logged >= to_seconds(now - delay - Duration::from_secs(60)).as_str());
+        assert!(logged <= to_seconds(now - delay + Duration::from_secs(60)).as_str());
+
+        assert_eq!(processes.lines().count(), 3);
+        assert!(processes
+            .lines()
+            .nth(1)
+            .unwrap()
+            .contains(",1,,init,\"/sbin/init --flag, with comma\","));
+
+        // No memory was harvested, so only the header should be there.
+        assert_eq!(mem.lines().count(), 1);
+    }
+}
@@ -5,12 +5,13 @@ use crate::components::text_table::CellContent;
 use crate::components::time_graph::Point;
 use crate::{app::AxisScaling, units::data_units::DataUnit, Pid};
 use crate::{
-    app::{data_farmer, data_harvester, App},
+    app::{data_farmer, data_harvester, App, CpuGrouping},
     utils::gen_util::*,
 };
 
 use concat_string::concat_string;
 use fxhash::FxHashMap;
+use std::collections::{BTreeMap, HashSet};
 
 #[derive(Default, Debug)]
 pub struct ConvertedBatteryData {
@@ -60,6 +61,56 @@ pub struct ConvertedNetworkData {
     // mean_tx: f64,
 }
 
+#[derive(Default, Debug)]
+pub struct ConvertedInterfaceData {
+    pub name: String,
+    pub rx: Vec<Point>,
+    pub tx: Vec<Point>,
+    pub rx_display: String,
+    pub tx_display: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ConvertedDiskIoData {
+    pub name: String,
+    /// Read and write rates are in bytes per second.
+    pub read: Vec<Point>,
+    pub write: Vec<Point>,
+    pub read_label: String,
+    pub write_label: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ConvertedTempGraphData {
+    pub name: String,
+    pub temp_data: Vec<Point>,
+    /// The last recorded temperature, as displayed on the legend.
+    pub legend_value: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ConvertedPressureData {
+    /// The resource and kind of stall, like `MEM full`.
+    pub name: &'static str,
+    /// The share of the last 10 seconds that tasks were stalled, over time.
+    pub pressure_data: Vec<Point>,
+    /// The latest 10, 60 and 300 second averages, as displayed on the legend.
+    pub legend_value: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ConvertedMemBreakdown {
+    /// The used memory plus buffers, as a percentage of the total RAM over time, so that it stacks on top of
+    /// the RAM line.
+    pub buffers_data: Vec<Point>,
+    /// The used memory plus buffers and cache, as a percentage of the total RAM over time.
+    pub cache_data: Vec<Point>,
+    pub buffers_label: String,
+    pub cache_label: String,
+    /// The name and size of each part of the breakdown, as displayed in basic mode.
+    pub details: Vec<(&'static str, String)>,
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ConvertedCpuData {
     pub cpu_name: String,
@@ -68,6 +119,41 @@ pub struct ConvertedCpuData {
     pub cpu_data: Vec<Point>,
     /// Represents the value displayed on the legend.
     pub legend_value: String,
+    /// The index of the CPU in the harvested data, which its time breakdown is read with. This is `None` for the
+    /// "All" row and for groups.
+    pub cpu_index: Option<usize>,
+    /// The latest share of time spent on each kind of work, as displayed on the legend of the graph.
+    pub breakdown_legend_values: Vec<String>,
+    /// The current frequency, or nothing if it isn't known.
+    pub frequency_value: String,
+    /// The name of the group if this row is the average of a group of CPUs rather than a single CPU.
+    pub group_name: Option<String>,
+}
+
+#[derive(Default, Debug)]
+pub struct ConvertedCpuFrequencyData {
+    pub name: String,
+    /// The frequency in GHz, over time.
+    pub frequency_data: Vec<Point>,
+    /// The highest frequency the CPU can run at in GHz, if known.
+    pub max_frequency: Option<f64>,
+    /// The current frequency and how many times the CPU was throttled, as displayed on the legend.
+    pub legend_value: String,
+}
+
+/// The kinds of CPU time, in the order they are stacked on the CPU graph. Everything but the last adds up to
+/// the usage of the CPU.
+pub const CPU_TIME_KINDS: [&str; 5] = ["USR", "SYS", "IRQ", "STEAL", "IOW"];
+
+/// The share of time spent on each kind of work, in the order of [`CPU_TIME_KINDS`].
+fn cpu_time_values(breakdown: &data_harvester::cpu::CpuTimeBreakdown) -> [f64; 5] {
+    [
+        breakdown.user,
+        breakdown.system,
+        breakdown.irq,
+        breakdown.steal,
+        breakdown.io_wait,
+    ]
 }
 
 #[derive(Default)]
@@ -78,8 +164,24 @@ pub struct ConvertedData {
     pub total_tx_display: String,
     pub network_data_rx: Vec<Point>,
     pub network_data_tx: Vec<Point>,
+    /// The interfaces graphed on their own, in the order they were picked.
+    pub interface_graph_data: Vec<ConvertedInterfaceData>,
     pub disk_data: TableData,
+    /// The I/O of each disk, in the order they were harvested.
+    pub disk_io_data: Vec<ConvertedDiskIoData>,
+    pub net_interface_data: TableData,
+    /// The name of the interface in each row of `net_interface_data`.
+    pub net_interface_names: Vec<String>,
+    pub cgroup_data: TableData,
+    /// The path of the group in each row of `cgroup_data`.
+    pub cgroup_paths: Vec<String>,
     pub temp_sensor_data: TableData,
+    /// How close the temperature in each row of `temp_sensor_data` is to its limits.
+    pub temp_sensor_levels: Vec<data_harvester::temperature::TempLevel>,
+    pub sensor_data: TableData,
+    pub temp_graph_data: Vec<ConvertedTempGraphData>,
+    pub pressure_data: Vec<ConvertedPressureData>,
+    pub cpu_frequency_data: Vec<ConvertedCpuFrequencyData>,
 
     /// A mapping from a process name to any PID with that name.
     pub process_name_pid_map: FxHashMap<String, Vec<Pid>>,
@@ -92,38 +194,63 @@ pub struct ConvertedData {
 
     pub mem_data: Vec<Point>, // TODO: Switch this and all data points over to a better data structure...
     pub swap_data: Vec<Point>,
+    /// Where the RAM is going, if it is known.
+    pub mem_breakdown: Option<ConvertedMemBreakdown>,
     pub load_avg_data: [f32; 3],
     pub cpu_data: Vec<ConvertedCpuData>,
     pub battery_data: Vec<ConvertedBatteryData>,
 }
 
-pub fn convert_temp_row(app: &App) -> TableData {
+/// Returns the symbol of a temperature unit.
+pub fn temperature_unit(temp_type: &data_harvester::temperature::TemperatureType) -> &'static str {
+    match temp_type {
+        data_harvester::temperature::TemperatureType::Celsius => "°C",
+        data_harvester::temperature::TemperatureType::Kelvin => "K",
+        data_harvester::temperature::TemperatureType::Fahrenheit => "°F",
+    }
+}
+
+/// Formats a temperature for display, rounding up to a whole degree.
+fn temperature_string(
+    temperature: f32, temp_type: &data_harvester::temperature::TemperatureType,
+) -> String {
+    concat_string!(
+        (temperature.ceil() as u64).to_string(),
+        temperature_unit(temp_type)
+    )
+}
+
+/// Returns the temperature table, along with how close the temperature in each row is to its limits.
+pub fn convert_temp_row(app: &App) -> (TableData, Vec<data_harvester::temperature::TempLevel>) {
     let current_data = &app.data_collection;
     let temp_type = &app.app_config_fields.temperature_type;
-    let mut col_widths = vec![0; 2];
+    let thresholds = &app.app_config_fields.temp_thresholds;
+    let mut col_widths = vec![0; 4];
+    let mut levels = Vec::with_capacity(current_data.temp_harvest.len());
+
+    let limit_string = |limit: Option<f32>| {
+        limit.map_or_else(
+            || "N/A".to_string(),
+            |limit| temperature_string(limit, temp_type),
+        )
+    };
 
     let mut sensor_vector: Vec<TableRow> = current_data
         .temp_harvest
         .iter()
         .map(|temp_harvest| {
+            let (warning, critical) = temp_harvest.limits(thresholds);
             let row = vec![
                 CellContent::Simple(temp_harvest.name.clone().into()),
-                CellContent::Simple(
-                    concat_string!(
-                        (temp_harvest.temperature.ceil() as u64).to_string(),
-                        match temp_type {
-                            data_harvester::temperature::TemperatureType::Celsius => "°C",
-                            data_harvester::temperature::TemperatureType::Kelvin => "K",
-                            data_harvester::temperature::TemperatureType::Fahrenheit => "°F",
-                        }
-                    )
-                    .into(),
-                ),
+                CellContent::Simple(temperature_string(temp_harvest.temperature, temp_type).into()),
+                CellContent::Simple(limit_string(warning).into()),
+                CellContent::Simple(limit_string(critical).into()),
             ];
 
             col_widths.iter_mut().zip(&row).for_each(|(curr, r)| {
                 *curr = std::cmp::max(*curr, r.len());
             });
+            levels.push(temp_harvest.level(thresholds));
 
             TableRow::Raw(row)
         })
@@ -133,6 +260,63 @@ pub fn convert_temp_row(app: &App) -> TableData {
         sensor_vector.push(TableRow::Raw(vec![
             CellContent::Simple("No Sensors Found".into()),
             CellContent::Simple("".into()),
+            CellContent::Simple("".into()),
+            CellContent::Simple("".into()),
+        ]));
+    }
+
+    (
+        TableData {
+            data: sensor_vector,
+            col_widths,
+        },
+        levels,
+    )
+}
+
+/// Formats the reading of a sensor in the unit of its type.
+fn sensor_value_string(sensor: &data_harvester::sensors::SensorHarvest) -> String {
+    use data_harvester::sensors::SensorType;
+
+    let precision = match sensor.sensor_type {
+        SensorType::Fan => 0,
+        SensorType::Voltage | SensorType::Current => 2,
+        SensorType::Power => 1,
+    };
+    format!(
+        "{:.*} {}",
+        precision,
+        sensor.value,
+        sensor.sensor_type.unit()
+    )
+}
+
+pub fn convert_sensor_rows(current_data: &data_farmer::DataCollection) -> TableData {
+    let mut col_widths = vec![0; 3];
+
+    let mut sensor_vector: Vec<TableRow> = current_data
+        .sensor_harvest
+        .iter()
+        .map(|sensor| {
+            let row = vec![
+                CellContent::Simple(sensor.name.clone().into()),
+                CellContent::Simple(sensor.sensor_type.name().into()),
+                CellContent::Simple(sensor_value_string(sensor).into()),
+            ];
+
+            col_widths.iter_mut().zip(&row).for_each(|(curr, r)| {
+                *curr = std::cmp::max(*curr, r.len());
+            });
+
+            TableRow::Raw(row)
+        })
+        .collect();
+
+    if sensor_vector.is_empty() {
+        sensor_vector.push(TableRow::Raw(vec![
+            CellContent::Simple("No Sensors Found".into()),
+            CellContent::Simple("".into()),
+            CellContent::Simple("".into()),
         ]));
     }
 
@@ -204,8 +388,223 @@ pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> TableData
     }
 }
 
+pub fn convert_net_interface_row(
+    current_data: &data_farmer::DataCollection, network_unit_type: &DataUnit,
+    network_use_binary_prefix: bool,
+) -> (TableData, Vec<String>) {
+    fn pair(rx: Option<u64>, tx: Option<u64>) -> String {
+        let count = |count: Option<u64>| count.map_or_else(|| "N/A".to_string(), |c| c.to_string());
+        concat_string!(count(rx), "/", count(tx))
+    }
+
+    let mut interface_vector: Vec<TableRow> = Vec::new();
+    let mut col_widths = vec![0; 8];
+
+    let interfaces = &current_data.network_harvest.interfaces;
+    for interface in interfaces {
+        let row = vec![
+            CellContent::Simple(interface.name.clone().into()),
+            CellContent::Simple(
+                network_rate_string(interface.rx, network_unit_type, network_use_binary_prefix)
+                    .into(),
+            ),
+            CellContent::Simple(
+                network_rate_string(interface.tx, network_unit_type, network_use_binary_prefix)
+                    .into(),
+            ),
+            CellContent::Simple(
+                network_total_string(interface.total_rx, network_use_binary_prefix).into(),
+            ),
+            CellContent::Simple(
+                network_total_string(interface.total_tx, network_use_binary_prefix).into(),
+            ),
+            CellContent::Simple(
+                pair(Some(interface.packets_rx), Some(interface.packets_tx)).into(),
+            ),
+            CellContent::Simple(pair(Some(interface.errors_rx), Some(interface.errors_tx)).into()),
+            CellContent::Simple(pair(interface.drops_rx, interface.drops_tx).into()),
+        ];
+        col_widths.iter_mut().zip(&row).for_each(|(curr, r)| {
+            *curr = std::cmp::max(*curr, r.len());
+        });
+        interface_vector.push(TableRow::Raw(row));
+    }
+
+    if interface_vector.is_empty() {
+        interface_vector.push(TableRow::Raw(vec![
+            CellContent::Simple("No Interfaces Found".into()),
+            CellContent::Simple("".into()),
+        ]));
+    }
+
+    (
+        TableData {
+            data: interface_vector,
+            col_widths,
+        },
+        interfaces
+            .iter()
+            .map(|interface| interface.name.clone())
+            .collect(),
+    )
+}
+
+/// Lays out the cgroups as a tree, like the process tree. Only the top row of a collapsed group is shown, which
+/// still covers its children as the usage of a group includes theirs.
+pub fn convert_cgroup_rows(
+    current_data: &data_farmer::DataCollection, collapsed_paths: &HashSet<String>,
+) -> (TableData, Vec<String>) {
+    const BRANCH_ENDING: char = '└';
+    const BRANCH_VERTICAL: char = '│';
+    const BRANCH_SPLIT: char = '├';
+    const BRANCH_HORIZONTAL: char = '─';
+
+    let cgroups = &current_data.cgroup_harvest;
+    let paths = cgroups
+        .iter()
+        .map(|cgroup| cgroup.path.as_str())
+        .collect::<HashSet<_>>();
+
+    // The harvest is sorted by path, so siblings stay sorted by name.
+    let mut children: FxHashMap<&str, Vec<&data_harvester::cgroups::CgroupHarvest>> =
+        FxHashMap::default();
+    let mut roots = vec![];
+    for cgroup in cgroups {
+        match cgroup.parent_path() {
+            Some(parent) if paths.contains(parent) => {
+                children.entry(parent).or_default().push(cgroup)
+            }
+            _ => roots.push(cgroup),
+        }
+    }
+
+    let mut cgroup_vector: Vec<TableRow> = Vec::new();
+    let mut cgroup_paths = Vec::new();
+    let mut col_widths = vec![0; 8];
+
+    // Each group is stacked along with the indent of its branch, whether it is the last of its siblings, and
+    // whether it is at the top of the tree.
+    let mut stack = roots
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, cgroup)| (*cgroup, String::default(), index == 0, true))
+        .collect::<Vec<_>>();
+
+    while let Some((cgroup, indent, is_last, is_top)) = stack.pop() {
+        let is_collapsed = collapsed_paths.contains(&cgroup.path);
+        let prefix = format!(
+            "{}{}",
+            if is_top {
+                String::default()
+            } else {
+                format!(
+                    "{}{}{} ",
+                    indent,
+                    if is_last { BRANCH_ENDING } else { BRANCH_SPLIT },
+                    BRANCH_HORIZONTAL
+                )
+            },
+            if is_collapsed { "+ " } else { "" }
+        );
+
+        let row = vec![
+            CellContent::Simple(concat_string!(prefix, cgroup.name()).into()),
+            CellContent::Simple(format!("{:.1}%", cgroup.cpu_usage_percent).into()),
+            CellContent::Simple(
+                cgroup
+                    .mem_current
+                    .map_or_else(|| "N/A".to_string(), binary_byte_string)
+                    .into(),
+            ),
+            CellContent::Simple(
+                cgroup
+                    .mem_max
+                    .map_or_else(|| "max".to_string(), binary_byte_string)
+                    .into(),
+            ),
+            CellContent::Simple(match (cgroup.mem_current, cgroup.mem_max) {
+                (Some(current), Some(max)) if max > 0 => {
+                    format!("{:.0}%", current as f64 / max as f64 * 100_f64).into()
+                }
+                _ => "N/A".into(),
+            }),
+            CellContent::Simple(dec_bytes_per_second_string(cgroup.read_bytes_per_sec).into()),
+            CellContent::Simple(dec_bytes_per_second_string(cgroup.write_bytes_per_sec).into()),
+            CellContent::Simple(
+                cgroup
+                    .pids_current
+                    .map_or_else(|| "N/A".to_string(), |pids| pids.to_string())
+                    .into(),
+            ),
+        ];
+        col_widths.iter_mut().zip(&row).for_each(|(curr, r)| {
+            *curr = std::cmp::max(*curr, r.len());
+        });
+        cgroup_vector.push(TableRow::Raw(row));
+        cgroup_paths.push(cgroup.path.clone());
+
+        if let (false, Some(children)) = (is_collapsed, children.get(cgroup.path.as_str())) {
+            let child_indent = if is_top {
+                String::default()
+            } else if is_last {
+                concat_string!(indent, "   ")
+            } else {
+                format!("{}{}  ", indent, BRANCH_VERTICAL)
+            };
+            stack.extend(
+                children
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(index, child)| (*child, child_indent.clone(), index == 0, false)),
+            );
+        }
+    }
+
+    if cgroup_vector.is_empty() {
+        cgroup_vector.push(TableRow::Raw(vec![
+            CellContent::Simple("No Cgroups Found".into()),
+            CellContent::Simple("".into()),
+        ]));
+    }
+
+    (
+        TableData {
+            data: cgroup_vector,
+            col_widths,
+        },
+        cgroup_paths,
+    )
+}
+
+/// The legend values of the time breakdown of a CPU, or nothing if it isn't known.
+fn cpu_breakdown_legend_values(cpu: Option<&data_harvester::cpu::CpuData>) -> Vec<String> {
+    match cpu.and_then(|cpu| cpu.breakdown.as_ref()) {
+        Some(breakdown) => cpu_time_values(breakdown)
+            .iter()
+            .map(|value| format!("{:3.0}%", value.round()))
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// Formats a frequency in kHz as GHz.
+fn frequency_string(khz: u64) -> String {
+    format!("{:.1}GHz", khz as f64 / 1_000_000.0)
+}
+
+/// The current frequency of a CPU, or nothing if it isn't known.
+fn cpu_frequency_value(cpu: Option<&data_harvester::cpu::CpuData>) -> String {
+    match cpu.and_then(|cpu| cpu.frequency) {
+        Some(frequency) => frequency_string(frequency.current_khz),
+        None => String::new(),
+    }
+}
+
 pub fn convert_cpu_data_points(
     current_data: &data_farmer::DataCollection, existing_cpu_data: &mut Vec<ConvertedCpuData>,
+    grouping: CpuGrouping, expanded_groups: &HashSet<String>,
 ) {
     let current_time = if let Some(frozen_instant) = current_data.frozen_instant {
         frozen_instant
@@ -213,6 +612,12 @@ pub fn convert_cpu_data_points(
         current_data.current_instant
     };
 
+    // Grouped rows don't line up with the harvested CPUs, so they can't be updated in place.
+    if grouping != CpuGrouping::None || existing_cpu_data.iter().any(|cpu| cpu.group_name.is_some())
+    {
+        existing_cpu_data.clear();
+    }
+
     // Initialize cpu_data_vector if the lengths don't match...
     if let Some((_time, data)) = &current_data.timed_data_vec.last() {
         if data.cpu_data.len() + 1 != existing_cpu_data.len() {
@@ -221,6 +626,7 @@ pub fn convert_cpu_data_points(
                 short_cpu_name: "".to_string(),
                 cpu_data: vec![],
                 legend_value: String::new(),
+                ..ConvertedCpuData::default()
             }];
 
             existing_cpu_data.extend(
@@ -249,6 +655,12 @@ pub fn convert_cpu_data_points(
                         },
                         legend_value: format!("{:.0}%", cpu_usage.round()),
                         cpu_data: vec![],
+                        cpu_index: Some(itx),
+                        breakdown_legend_values: cpu_breakdown_legend_values(
+                            current_data.cpu_harvest.get(itx),
+                        ),
+                        frequency_value: cpu_frequency_value(current_data.cpu_harvest.get(itx)),
+                        group_name: None,
                     })
                     .collect::<Vec<ConvertedCpuData>>(),
             );
@@ -257,9 +669,13 @@ pub fn convert_cpu_data_points(
                 .iter_mut()
                 .skip(1)
                 .zip(&data.cpu_data)
-                .for_each(|(cpu, cpu_usage)| {
+                .enumerate()
+                .for_each(|(itx, (cpu, cpu_usage))| {
                     cpu.cpu_data = vec![];
                     cpu.legend_value = format!("{:.0}%", cpu_usage.round());
+                    cpu.breakdown_legend_values =
+                        cpu_breakdown_legend_values(current_data.cpu_harvest.get(itx));
+                    cpu.frequency_value = cpu_frequency_value(current_data.cpu_harvest.get(itx));
                 });
         }
     }
@@ -277,6 +693,143 @@ pub fn convert_cpu_data_points(
             break;
         }
     }
+
+    if grouping != CpuGrouping::None {
+        group_cpu_data(
+            existing_cpu_data,
+            &current_data.cpu_harvest,
+            grouping,
+            expanded_groups,
+        );
+    }
+}
+
+/// The group a CPU is in, as a key to sort the groups by and the name and short name of the group, or `None` if
+/// the CPU isn't in one.
+fn cpu_group(
+    cpu: &data_harvester::cpu::CpuData, grouping: CpuGrouping, has_multiple_packages: bool,
+) -> Option<((u32, u32), String, String)> {
+    cpu.cpu_count?;
+    let topology = cpu.topology?;
+
+    match grouping {
+        CpuGrouping::None => None,
+        CpuGrouping::Node => {
+            let node_id = topology.node_id?;
+            Some((
+                (0, node_id),
+                format!("Node {}", node_id),
+                format!("N{}", node_id),
+            ))
+        }
+        CpuGrouping::Socket => {
+            let package_id = topology.package_id?;
+            Some((
+                (0, package_id),
+                format!("Socket {}", package_id),
+                format!("S{}", package_id),
+            ))
+        }
+        CpuGrouping::Core => {
+            let (package_id, core_id) = (topology.package_id?, topology.core_id?);
+            if has_multiple_packages {
+                Some((
+                    (package_id, core_id),
+                    format!("S{} Core {}", package_id, core_id),
+                    format!("S{}C{}", package_id, core_id),
+                ))
+            } else {
+                Some((
+                    (package_id, core_id),
+                    format!("Core {}", core_id),
+                    format!("C{}", core_id),
+                ))
+            }
+        }
+    }
+}
+
+/// Replaces the rows of CPUs that are in a group with a row for the average of the group, followed by the CPUs
+/// themselves if the group is expanded. The "All" and average rows stay at the top, and CPUs that aren't in a
+/// group, such as when the topology isn't known, go at the bottom.
+fn group_cpu_data(
+    cpu_data: &mut Vec<ConvertedCpuData>, cpu_harvest: &[data_harvester::cpu::CpuData],
+    grouping: CpuGrouping, expanded_groups: &HashSet<String>,
+) {
+    let has_multiple_packages = {
+        let mut package_ids = cpu_harvest
+            .iter()
+            .filter_map(|cpu| cpu.topology.and_then(|topology| topology.package_id));
+        let first_package_id = package_ids.next();
+        package_ids.any(|package_id| Some(package_id) != first_package_id)
+    };
+
+    let mut rows = std::mem::take(cpu_data).into_iter();
+    cpu_data.extend(rows.next()); // The "All" row.
+
+    let mut groups: BTreeMap<(u32, u32), (String, String, Vec<_>)> = BTreeMap::new();
+    let mut ungrouped = vec![];
+    for (row, cpu) in rows.zip(cpu_harvest) {
+        match cpu_group(cpu, grouping, has_multiple_packages) {
+            Some((key, name, short_name)) => groups
+                .entry(key)
+                .or_insert_with(|| (name, short_name, vec![]))
+                .2
+                .push((row, cpu)),
+            None if cpu.cpu_count.is_none() => cpu_data.push(row),
+            None => ungrouped.push(row),
+        }
+    }
+
+    for (name, short_name, members) in groups.into_values() {
+        let is_expanded = expanded_groups.contains(&name);
+
+        // Average the usage at each point in time that every CPU in the group has.
+        let (first_row, _) = &members[0];
+        let group_points = first_row
+            .cpu_data
+            .iter()
+            .enumerate()
+            .map(|(itx, (time, _))| {
+                let values = members
+                    .iter()
+                    .filter_map(|(row, _)| row.cpu_data.get(itx).filter(|point| point.0 == *time))
+                    .map(|point| point.1)
+                    .collect::<Vec<_>>();
+                (*time, values.iter().sum::<f64>() / values.len() as f64)
+            })
+            .collect();
+
+        let usage =
+            members.iter().map(|(_, cpu)| cpu.cpu_usage).sum::<f64>() / members.len() as f64;
+        let frequencies = members
+            .iter()
+            .filter_map(|(_, cpu)| cpu.frequency.map(|frequency| frequency.current_khz))
+            .collect::<Vec<_>>();
+
+        cpu_data.push(ConvertedCpuData {
+            cpu_name: concat_string!(if is_expanded { "- " } else { "+ " }, name),
+            short_cpu_name: short_name,
+            cpu_data: group_points,
+            legend_value: format!("{:.0}%", usage.round()),
+            frequency_value: if frequencies.is_empty() {
+                String::new()
+            } else {
+                frequency_string(frequencies.iter().sum::<u64>() / frequencies.len() as u64)
+            },
+            group_name: Some(name),
+            ..ConvertedCpuData::default()
+        });
+
+        if is_expanded {
+            cpu_data.extend(members.into_iter().map(|(row, _)| ConvertedCpuData {
+                cpu_name: concat_string!("  ", row.cpu_name),
+                ..row
+            }));
+        }
+    }
+
+    cpu_data.extend(ungrouped);
 }
 
 pub fn convert_mem_data_points(current_data: &data_farmer::DataCollection) -> Vec<Point> {
@@ -393,12 +946,100 @@ pub fn convert_mem_labels(
     )
 }
 
-pub fn get_rx_tx_data_points(
-    current_data: &data_farmer::DataCollection, network_scale_type: &AxisScaling,
-    network_unit_type: &DataUnit, network_use_binary_prefix: bool,
-) -> (Vec<Point>, Vec<Point>) {
-    let mut rx: Vec<Point> = Vec::new();
-    let mut tx: Vec<Point> = Vec::new();
+/// Converts the breakdown of RAM usage, if there is one, into the buffers and cache stacked on top of the used
+/// memory, and their labels.
+pub fn convert_mem_breakdown(
+    current_data: &data_farmer::DataCollection,
+) -> Option<ConvertedMemBreakdown> {
+    let memory = &current_data.memory_harvest;
+    let breakdown = memory.breakdown.as_ref()?;
+    // Nothing was read if `/proc/meminfo` is missing all of the fields, so there is nothing to show either.
+    if memory.mem_total_in_kib == 0 || *breakdown == Default::default() {
+        return None;
+    }
+
+    let (buffers_data, cache_data) = get_paired_points(current_data, |data| {
+        let used = data.mem_data?;
+        let (buffers, cache) = data.mem_breakdown_data?;
+        Some((used + buffers, used + buffers + cache))
+    });
+
+    let size = |kib: u64| binary_byte_string(kib * 1024);
+    let label = |kib: u64| {
+        format!(
+            "{:3.0}%   {}",
+            kib as f64 / memory.mem_total_in_kib as f64 * 100.0,
+            size(kib)
+        )
+    };
+
+    let mut details = vec![
+        ("Used", size(memory.mem_used_in_kib)),
+        ("Buf", size(breakdown.buffers_in_kib)),
+        ("Cache", size(breakdown.cache_in_kib())),
+        ("Avail", size(breakdown.available_in_kib)),
+        ("Slab", size(breakdown.slab_in_kib)),
+        ("Dirty", size(breakdown.dirty_in_kib)),
+        ("WB", size(breakdown.writeback_in_kib)),
+    ];
+    if breakdown.huge_pages_total > 0 {
+        let huge_pages_used = breakdown
+            .huge_pages_total
+            .saturating_sub(breakdown.huge_pages_free)
+            * breakdown.huge_page_size_in_kib;
+        details.push((
+            "Huge",
+            format!(
+                "{}/{}",
+                size(huge_pages_used),
+                size(breakdown.huge_pages_in_kib())
+            ),
+        ));
+    }
+
+    Some(ConvertedMemBreakdown {
+        buffers_data,
+        cache_data,
+        buffers_label: label(breakdown.buffers_in_kib),
+        cache_label: label(breakdown.cache_in_kib()),
+        details,
+    })
+}
+
+/// Scales a network value in bits to how it is drawn on the network graph.
+fn scale_network_value(
+    value: f64, network_scale_type: &AxisScaling, network_unit_type: &DataUnit,
+    network_use_binary_prefix: bool,
+) -> f64 {
+    match network_scale_type {
+        AxisScaling::Log => {
+            if network_use_binary_prefix {
+                match network_unit_type {
+                    // As dividing by 8 is equal to subtracting 4 in base 2!
+                    DataUnit::Byte => value.log2() - 4.0,
+                    DataUnit::Bit => value.log2(),
+                }
+            } else {
+                match network_unit_type {
+                    DataUnit::Byte => (value / 8.0).log10(),
+                    DataUnit::Bit => value.log10(),
+                }
+            }
+        }
+        AxisScaling::Linear => match network_unit_type {
+            DataUnit::Byte => value / 8.0,
+            DataUnit::Bit => value,
+        },
+    }
+}
+
+/// Returns the values picked out of each entry by `get_value`, each paired with how long before the current
+/// time (or the frozen time) the entry was collected. Entries where `get_value` returns [`None`] are skipped.
+fn get_timed_values<T>(
+    current_data: &data_farmer::DataCollection,
+    get_value: impl Fn(&data_farmer::TimedData) -> Option<T>,
+) -> Vec<(f64, T)> {
+    let mut values: Vec<(f64, T)> = Vec::new();
 
     let current_time = if let Some(frozen_instant) = current_data.frozen_instant {
         frozen_instant
@@ -407,41 +1048,272 @@ pub fn get_rx_tx_data_points(
     };
 
     for (time, data) in &current_data.timed_data_vec {
-        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
-
-        let (rx_data, tx_data) = match network_scale_type {
-            AxisScaling::Log => {
-                if network_use_binary_prefix {
-                    match network_unit_type {
-                        DataUnit::Byte => {
-                            // As dividing by 8 is equal to subtracting 4 in base 2!
-                            ((data.rx_data).log2() - 4.0, (data.tx_data).log2() - 4.0)
-                        }
-                        DataUnit::Bit => ((data.rx_data).log2(), (data.tx_data).log2()),
-                    }
-                } else {
-                    match network_unit_type {
-                        DataUnit::Byte => {
-                            ((data.rx_data / 8.0).log10(), (data.tx_data / 8.0).log10())
-                        }
-                        DataUnit::Bit => ((data.rx_data).log10(), (data.tx_data).log10()),
-                    }
-                }
-            }
-            AxisScaling::Linear => match network_unit_type {
-                DataUnit::Byte => (data.rx_data / 8.0, data.tx_data / 8.0),
-                DataUnit::Bit => (data.rx_data, data.tx_data),
-            },
-        };
+        if let Some(value) = get_value(data) {
+            let time_from_start: f64 =
+                (current_time.duration_since(*time).as_millis() as f64).floor();
 
-        rx.push((-time_from_start, rx_data));
-        tx.push((-time_from_start, tx_data));
+            values.push((-time_from_start, value));
+        }
         if *time == current_time {
             break;
         }
     }
 
-    (rx, tx)
+    values
+}
+
+/// Returns two series of points to graph, using `get_values` to pick a pair of values out of each entry.
+/// Entries where `get_values` returns [`None`] are skipped.
+fn get_paired_points(
+    current_data: &data_farmer::DataCollection,
+    get_values: impl Fn(&data_farmer::TimedData) -> Option<(f64, f64)>,
+) -> (Vec<Point>, Vec<Point>) {
+    get_timed_values(current_data, get_values)
+        .into_iter()
+        .map(|(time, (first, second))| ((time, first), (time, second)))
+        .unzip()
+}
+
+/// Returns the RX and TX points to graph, using `get_rx_tx` to pick them out of each entry. Entries where
+/// `get_rx_tx` returns [`None`] are skipped.
+fn get_network_points(
+    current_data: &data_farmer::DataCollection, network_scale_type: &AxisScaling,
+    network_unit_type: &DataUnit, network_use_binary_prefix: bool,
+    get_rx_tx: impl Fn(&data_farmer::TimedData) -> Option<(f64, f64)>,
+) -> (Vec<Point>, Vec<Point>) {
+    let scale = |value| {
+        scale_network_value(
+            value,
+            network_scale_type,
+            network_unit_type,
+            network_use_binary_prefix,
+        )
+    };
+
+    get_paired_points(current_data, |data| {
+        get_rx_tx(data).map(|(rx, tx)| (scale(rx), scale(tx)))
+    })
+}
+
+pub fn get_rx_tx_data_points(
+    current_data: &data_farmer::DataCollection, network_scale_type: &AxisScaling,
+    network_unit_type: &DataUnit, network_use_binary_prefix: bool,
+) -> (Vec<Point>, Vec<Point>) {
+    get_network_points(
+        current_data,
+        network_scale_type,
+        network_unit_type,
+        network_use_binary_prefix,
+        |data| Some((data.rx_data, data.tx_data)),
+    )
+}
+
+/// Converts the data of each graphed interface, in the order they are given.
+pub fn convert_interface_data_points(
+    current_data: &data_farmer::DataCollection, graphed_interfaces: &[String],
+    network_scale_type: &AxisScaling, network_unit_type: &DataUnit,
+    network_use_binary_prefix: bool,
+) -> Vec<ConvertedInterfaceData> {
+    graphed_interfaces
+        .iter()
+        .map(|name| {
+            let (rx, tx) = get_network_points(
+                current_data,
+                network_scale_type,
+                network_unit_type,
+                network_use_binary_prefix,
+                |data| data.interface_data.get(name).copied(),
+            );
+            let (rx_display, tx_display) = match current_data
+                .network_harvest
+                .interfaces
+                .iter()
+                .find(|interface| &interface.name == name)
+            {
+                Some(interface) => (
+                    network_rate_string(interface.rx, network_unit_type, network_use_binary_prefix),
+                    network_rate_string(interface.tx, network_unit_type, network_use_binary_prefix),
+                ),
+                None => ("N/A".to_string(), "N/A".to_string()),
+            };
+
+            ConvertedInterfaceData {
+                name: name.clone(),
+                rx,
+                tx,
+                rx_display,
+                tx_display,
+            }
+        })
+        .collect()
+}
+
+/// Converts the read and write history of each disk. Disks that show up more than once (for example, if they
+/// are mounted in several places) are only converted once.
+pub fn convert_disk_io_data_points(
+    current_data: &data_farmer::DataCollection,
+) -> Vec<ConvertedDiskIoData> {
+    let mut disk_io_data: Vec<ConvertedDiskIoData> = Vec::new();
+
+    for (disk, (read_label, write_label)) in current_data
+        .disk_harvest
+        .iter()
+        .zip(&current_data.io_labels)
+    {
+        if disk_io_data.iter().any(|disk_io| disk_io.name == disk.name) {
+            continue;
+        }
+
+        let (read, write) =
+            get_paired_points(current_data, |data| data.io_data.get(&disk.name).copied());
+
+        disk_io_data.push(ConvertedDiskIoData {
+            name: disk.name.clone(),
+            read,
+            write,
+            read_label: read_label.clone(),
+            write_label: write_label.clone(),
+        });
+    }
+
+    disk_io_data
+}
+
+/// Converts the temperature history of each sensor. Sensors that show up more than once are only converted
+/// once.
+pub fn convert_temp_graph_data_points(app: &App) -> Vec<ConvertedTempGraphData> {
+    let current_data = &app.data_collection;
+    let mut temp_graph_data: Vec<ConvertedTempGraphData> = Vec::new();
+
+    for sensor in &current_data.temp_harvest {
+        if temp_graph_data
+            .iter()
+            .any(|temp_graph| temp_graph.name == sensor.name)
+        {
+            continue;
+        }
+
+        temp_graph_data.push(ConvertedTempGraphData {
+            name: sensor.name.clone(),
+            temp_data: get_timed_values(current_data, |data| {
+                data.temp_data.get(&sensor.name).copied()
+            }),
+            legend_value: temperature_string(
+                sensor.temperature,
+                &app.app_config_fields.temperature_type,
+            ),
+        });
+    }
+
+    temp_graph_data
+}
+
+/// Converts the pressure history of each resource that is currently reported. The `full` pressure of the CPU
+/// is left out, as the kernel always reports it as zero for the whole system.
+pub fn convert_pressure_data_points(
+    current_data: &data_farmer::DataCollection,
+) -> Vec<ConvertedPressureData> {
+    use data_harvester::pressure::{PressureHarvest, PressureValues};
+
+    type GetValues = fn(&PressureHarvest) -> Option<PressureValues>;
+    const PRESSURES: [(&str, GetValues); 5] = [
+        ("CPU some", |pressure| pressure.cpu.map(|cpu| cpu.some)),
+        ("MEM some", |pressure| {
+            pressure.memory.map(|memory| memory.some)
+        }),
+        ("MEM full", |pressure| {
+            pressure.memory.and_then(|memory| memory.full)
+        }),
+        ("IO some", |pressure| pressure.io.map(|io| io.some)),
+        ("IO full", |pressure| pressure.io.and_then(|io| io.full)),
+    ];
+
+    PRESSURES
+        .iter()
+        .filter_map(|(name, get_values)| {
+            let latest = get_values(&current_data.pressure_harvest)?;
+            Some(ConvertedPressureData {
+                name,
+                pressure_data: get_timed_values(current_data, |data| {
+                    data.pressure_data
+                        .as_ref()
+                        .and_then(get_values)
+                        .map(|values| values.avg10)
+                }),
+                legend_value: format!(
+                    "{:.1}% {:.1}% {:.1}%",
+                    latest.avg10, latest.avg60, latest.avg300
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Converts how the time of the CPU at `cpu_index` in the harvested data was spent into a series of points for
+/// each kind of time in the order of [`CPU_TIME_KINDS`], stacked on top of each other. This is only done for the
+/// CPU that is selected, so it is empty if the breakdown isn't known.
+pub fn convert_cpu_breakdown_points(
+    current_data: &data_farmer::DataCollection, cpu_index: usize,
+) -> Vec<Vec<Point>> {
+    let values = get_timed_values(current_data, |data| {
+        data.cpu_breakdown_data.get(cpu_index).copied().flatten()
+    });
+    if values.is_empty() {
+        return vec![];
+    }
+
+    let mut breakdown_points = vec![Vec::with_capacity(values.len()); CPU_TIME_KINDS.len()];
+    for (time, breakdown) in values {
+        let mut stacked_value = 0.0;
+        for (points, value) in breakdown_points.iter_mut().zip(cpu_time_values(&breakdown)) {
+            stacked_value += value;
+            points.push((time, stacked_value));
+        }
+    }
+
+    breakdown_points
+}
+
+/// Converts the frequency history of each CPU whose frequency is currently reported.
+pub fn convert_cpu_frequency_data_points(
+    current_data: &data_farmer::DataCollection,
+) -> Vec<ConvertedCpuFrequencyData> {
+    current_data
+        .cpu_harvest
+        .iter()
+        .enumerate()
+        .filter_map(|(itx, cpu)| {
+            let frequency = cpu.frequency?;
+            let throttle_count = frequency
+                .core_throttle_count
+                .unwrap_or(0)
+                .max(frequency.package_throttle_count.unwrap_or(0));
+
+            Some(ConvertedCpuFrequencyData {
+                name: match cpu.cpu_count {
+                    Some(cpu_count) => format!("{}{}", cpu.cpu_prefix, cpu_count),
+                    None => cpu.cpu_prefix.clone(),
+                },
+                frequency_data: get_timed_values(current_data, |data| {
+                    data.cpu_frequency_data
+                        .get(itx)
+                        .copied()
+                        .flatten()
+                        .map(|khz| khz / 1_000_000.0)
+                }),
+                max_frequency: frequency.max_khz.map(|khz| khz as f64 / 1_000_000.0),
+                legend_value: if throttle_count > 0 {
+                    format!(
+                        "{} ({} throttles)",
+                        frequency_string(frequency.current_khz),
+                        throttle_count
+                    )
+                } else {
+                    frequency_string(frequency.current_khz)
+                },
+            })
+        })
+        .collect()
 }
 
 pub fn convert_network_data_points(
@@ -572,6 +1444,34 @@ pub fn convert_network_data_points(
     }
 }
 
+/// Returns a network rate given in bits per second as a string, in the configured unit.
+pub fn network_rate_string(
+    bits_per_second: u64, network_unit_type: &DataUnit, network_use_binary_prefix: bool,
+) -> String {
+    let (value, unit) = match network_unit_type {
+        DataUnit::Byte => (bits_per_second / 8, "B/s"),
+        DataUnit::Bit => (bits_per_second, "b/s"),
+    };
+    let converted = if network_use_binary_prefix {
+        get_binary_prefix(value, unit)
+    } else {
+        get_decimal_prefix(value, unit)
+    };
+
+    format!("{:.1}{}", converted.0, converted.1)
+}
+
+/// Returns a network total given in bits as a string. Totals are always shown in bytes.
+pub fn network_total_string(total_bits: u64, network_use_binary_prefix: bool) -> String {
+    let converted = if network_use_binary_prefix {
+        get_binary_bytes(total_bits / 8)
+    } else {
+        get_decimal_bytes(total_bits / 8)
+    };
+
+    format!("{:.1}{}", converted.0, converted.1)
+}
+
 /// Returns a string given a value that is converted to the closest binary variant.
 /// If the value is greater than a gibibyte, then it will return a decimal place.
 pub fn binary_byte_string(value: u64) -> String {
@@ -706,4 +1606,345 @@ mod test {
             "10.4TB/s".to_string()
         );
     }
+
+    #[test]
+    fn test_network_strings() {
+        assert_eq!(network_rate_string(0, &DataUnit::Bit, false), "0.0b/s");
+        assert_eq!(network_rate_string(8000, &DataUnit::Byte, false), "1.0KB/s");
+        assert_eq!(network_rate_string(8192, &DataUnit::Bit, true), "8.0Kib/s");
+        assert_eq!(network_total_string(8 * MEGA_LIMIT, false), "1.0MB");
+        assert_eq!(network_total_string(8 * KIBI_LIMIT, true), "1.0KiB");
+    }
+
+    #[test]
+    fn test_mem_breakdown() {
+        use data_harvester::memory::{MemBreakdown, MemHarvest};
+
+        let mut data = data_farmer::DataCollection::default();
+        assert!(convert_mem_breakdown(&data).is_none());
+
+        data.memory_harvest = MemHarvest {
+            mem_total_in_kib: 1000 * 1024,
+            mem_used_in_kib: 500 * 1024,
+            use_percent: Some(50.0),
+            breakdown: Some(MemBreakdown {
+                buffers_in_kib: 50 * 1024,
+                cached_in_kib: 200 * 1024,
+                ..MemBreakdown::default()
+            }),
+        };
+        data.timed_data_vec.push((
+            data.current_instant,
+            data_farmer::TimedData {
+                mem_data: Some(50.0),
+                mem_breakdown_data: Some((5.0, 20.0)),
+                ..Default::default()
+            },
+        ));
+
+        let breakdown = convert_mem_breakdown(&data).unwrap();
+        assert_eq!(breakdown.buffers_data, vec![(0.0, 55.0)]);
+        assert_eq!(breakdown.cache_data, vec![(0.0, 75.0)]);
+        assert_eq!(breakdown.buffers_label, "  5%   50MiB");
+        assert_eq!(breakdown.cache_label, " 20%   200MiB");
+        assert_eq!(breakdown.details[0], ("Used", "500MiB".to_string()));
+        assert!(breakdown.details.iter().all(|(name, _)| *name != "Huge"));
+
+        // The free huge pages can be read after more have been freed, so they may outnumber the total.
+        data.memory_harvest.breakdown = Some(MemBreakdown {
+            huge_pages_total: 1,
+            huge_pages_free: 2,
+            huge_page_size_in_kib: 2048,
+            ..MemBreakdown::default()
+        });
+        let breakdown = convert_mem_breakdown(&data).unwrap();
+        assert_eq!(
+            breakdown.details.last().unwrap(),
+            &("Huge", "0B/2MiB".to_string())
+        );
+
+        data.memory_harvest.breakdown = Some(MemBreakdown::default());
+        assert!(convert_mem_breakdown(&data).is_none());
+    }
+
+    #[test]
+    fn test_cpu_frequency_data() {
+        use data_harvester::cpu::{frequency::CpuFrequency, CpuData};
+
+        let mut data = data_farmer::DataCollection {
+            cpu_harvest: vec![
+                CpuData {
+                    cpu_prefix: "AVG".to_string(),
+                    ..CpuData::default()
+                },
+                CpuData {
+                    cpu_prefix: "CPU".to_string(),
+                    cpu_count: Some(0),
+                    frequency: Some(CpuFrequency {
+                        current_khz: 2_400_000,
+                        max_khz: Some(4_200_000),
+                        core_throttle_count: Some(3),
+                        ..CpuFrequency::default()
+                    }),
+                    ..CpuData::default()
+                },
+            ],
+            ..Default::default()
+        };
+        data.timed_data_vec.push((
+            data.current_instant,
+            data_farmer::TimedData {
+                cpu_frequency_data: vec![None, Some(2_400_000.0)],
+                ..Default::default()
+            },
+        ));
+
+        let frequencies = convert_cpu_frequency_data_points(&data);
+        assert_eq!(frequencies.len(), 1);
+        assert_eq!(frequencies[0].name, "CPU0");
+        assert_eq!(frequencies[0].frequency_data, vec![(0.0, 2.4)]);
+        assert_eq!(frequencies[0].max_frequency, Some(4.2));
+        assert_eq!(frequencies[0].legend_value, "2.4GHz (3 throttles)");
+
+        assert_eq!(cpu_frequency_value(data.cpu_harvest.first()), "");
+        assert_eq!(cpu_frequency_value(data.cpu_harvest.get(1)), "2.4GHz");
+    }
+
+    #[test]
+    fn test_cpu_breakdown_points() {
+        use data_harvester::cpu::CpuTimeBreakdown;
+
+        let mut data = data_farmer::DataCollection::default();
+        data.timed_data_vec.push((
+            data.current_instant,
+            data_farmer::TimedData {
+                cpu_breakdown_data: vec![
+                    None,
+                    Some(CpuTimeBreakdown {
+                        user: 10.0,
+                        system: 5.0,
+                        irq: 1.0,
+                        steal: 0.0,
+                        io_wait: 4.0,
+                    }),
+                ],
+                ..Default::default()
+            },
+        ));
+
+        assert!(convert_cpu_breakdown_points(&data, 0).is_empty());
+        assert!(convert_cpu_breakdown_points(&data, 2).is_empty());
+        assert_eq!(
+            convert_cpu_breakdown_points(&data, 1),
+            vec![
+                vec![(0.0, 10.0)],
+                vec![(0.0, 15.0)],
+                vec![(0.0, 16.0)],
+                vec![(0.0, 16.0)],
+                vec![(0.0, 20.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cpu_grouping() {
+        use data_harvester::cpu::{topology::CpuTopology, CpuData};
+
+        let mut data = data_farmer::DataCollection {
+            cpu_harvest: vec![CpuData {
+                cpu_prefix: "AVG".to_string(),
+                cpu_usage: 30.0,
+                ..CpuData::default()
+            }],
+            ..Default::default()
+        };
+        for (cpu_count, (node_id, core_id, cpu_usage)) in [(0, 0, 10.0), (0, 0, 30.0), (1, 1, 50.0)]
+            .iter()
+            .enumerate()
+        {
+            data.cpu_harvest.push(CpuData {
+                cpu_prefix: "CPU".to_string(),
+                cpu_count: Some(cpu_count),
+                cpu_usage: *cpu_usage,
+                topology: Some(CpuTopology {
+                    package_id: Some(0),
+                    core_id: Some(*core_id),
+                    node_id: Some(*node_id),
+                }),
+                ..CpuData::default()
+            });
+        }
+        data.cpu_harvest.push(CpuData {
+            cpu_prefix: "CPU".to_string(),
+            cpu_count: Some(3),
+            cpu_usage: 70.0,
+            ..CpuData::default()
+        });
+        data.timed_data_vec.push((
+            data.current_instant,
+            data_farmer::TimedData {
+                cpu_data: data.cpu_harvest.iter().map(|cpu| cpu.cpu_usage).collect(),
+                ..Default::default()
+            },
+        ));
+
+        let rows = |cpu_data: &mut Vec<ConvertedCpuData>, grouping, expanded_groups: &[&str]| {
+            let expanded_groups = expanded_groups
+                .iter()
+                .map(|group| group.to_string())
+                .collect();
+            convert_cpu_data_points(&data, cpu_data, grouping, &expanded_groups);
+            cpu_data
+                .iter()
+                .map(|cpu| {
+                    (
+                        cpu.cpu_name.clone(),
+                        cpu.legend_value.clone(),
+                        cpu.cpu_data.clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let row = |name: &str, usage: f64| {
+            (
+                name.to_string(),
+                format!("{:.0}%", usage),
+                vec![(0.0, usage)],
+            )
+        };
+        let mut cpu_data = vec![];
+
+        assert_eq!(
+            rows(&mut cpu_data, CpuGrouping::Node, &[]),
+            vec![
+                ("All".to_string(), String::new(), vec![]),
+                row("AVG", 30.0),
+                row("+ Node 0", 20.0),
+                row("+ Node 1", 50.0),
+                row("CPU3", 70.0),
+            ]
+        );
+        assert_eq!(cpu_data[2].short_cpu_name, "N0");
+        assert_eq!(cpu_data[2].group_name.as_deref(), Some("Node 0"));
+
+        assert_eq!(
+            rows(&mut cpu_data, CpuGrouping::Core, &["Core 0"]),
+            vec![
+                ("All".to_string(), String::new(), vec![]),
+                row("AVG", 30.0),
+                row("- Core 0", 20.0),
+                row("  CPU0", 10.0),
+                row("  CPU1", 30.0),
+                row("+ Core 1", 50.0),
+                row("CPU3", 70.0),
+            ]
+        );
+
+        // Going back to no grouping rebuilds the rows of every CPU.
+        assert_eq!(rows(&mut cpu_data, CpuGrouping::None, &[]).len(), 6);
+        assert_eq!(cpu_data[2].cpu_name, "CPU0");
+        assert!(cpu_data.iter().all(|cpu| cpu.group_name.is_none()));
+    }
+
+    #[test]
+    fn test_sensor_rows() {
+        use data_harvester::sensors::{SensorHarvest, SensorType};
+
+        let mut data = data_farmer::DataCollection::default();
+        assert_eq!(
+            convert_sensor_rows(&data).data[0].row()[0].main_text(),
+            "No Sensors Found"
+        );
+
+        data.sensor_harvest = [
+            (SensorType::Fan, 1234.4),
+            (SensorType::Voltage, 1.0564),
+            (SensorType::Current, 0.5),
+            (SensorType::Power, 35.0),
+        ]
+        .iter()
+        .map(|(sensor_type, value)| SensorHarvest {
+            name: "sensor".to_string(),
+            device: "hwmon0".to_string(),
+            sensor_type: *sensor_type,
+            value: *value,
+        })
+        .collect();
+
+        let rows = convert_sensor_rows(&data);
+        let cells = rows
+            .data
+            .iter()
+            .map(|row| {
+                (
+                    row.row()[1].main_text().to_string(),
+                    row.row()[2].main_text().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            [
+                ("Fan", "1234 RPM"),
+                ("Voltage", "1.06 V"),
+                ("Current", "0.50 A"),
+                ("Power", "35.0 W"),
+            ]
+            .iter()
+            .map(|(kind, value)| (kind.to_string(), value.to_string()))
+            .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_cgroup_tree() {
+        let data = data_farmer::DataCollection {
+            cgroup_harvest: [
+                "/",
+                "/ci.slice",
+                "/ci.slice/job-1.scope",
+                "/ci.slice/job-2.scope",
+                "/system.slice",
+                "/system.slice/ssh.service",
+            ]
+            .iter()
+            .map(|path| data_harvester::cgroups::CgroupHarvest {
+                path: path.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+            ..Default::default()
+        };
+
+        let names = |collapsed_paths: &[&str]| {
+            let collapsed_paths = collapsed_paths
+                .iter()
+                .map(|path| path.to_string())
+                .collect();
+            let (table_data, paths) = convert_cgroup_rows(&data, &collapsed_paths);
+            assert_eq!(table_data.data.len(), paths.len());
+            table_data
+                .data
+                .iter()
+                .map(|row| row.row()[0].main_text().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(&[]),
+            vec![
+                "/",
+                "├─ ci.slice",
+                "│  ├─ job-1.scope",
+                "│  └─ job-2.scope",
+                "└─ system.slice",
+                "   └─ ssh.service",
+            ]
+        );
+        assert_eq!(
+            names(&["/ci.slice"]),
+            vec!["/", "├─ + ci.slice", "└─ system.slice", "   └─ ssh.service"]
+        );
+        assert_eq!(names(&["/"]), vec!["+ /"]);
+    }
 }
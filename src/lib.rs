@@ -33,11 +33,7 @@ use crossterm::{
     terminal::{disable_raw_mode, LeaveAlternateScreen},
 };
 
-use app::{
-    data_harvester,
-    layout_manager::{UsedWidgets, WidgetDirection},
-    App,
-};
+use app::{data_harvester, keybindings::Action, layout_manager::UsedWidgets, App};
 use constants::*;
 use data_conversion::*;
 use options::*;
@@ -48,13 +44,18 @@ pub mod utils {
     pub mod error;
     pub mod gen_util;
     pub mod logging;
+    #[cfg(test)]
+    pub mod test_util;
 }
 pub mod canvas;
 pub mod clap;
 pub mod components;
 pub mod constants;
+pub mod csv_log;
 pub mod data_conversion;
+pub mod metrics;
 pub mod options;
+pub mod replay;
 pub mod units;
 
 #[cfg(target_family = "windows")]
@@ -68,6 +69,9 @@ pub enum BottomEvent<I, J> {
     KeyInput(I),
     MouseInput(J),
     Update(Box<data_harvester::Data>),
+    /// The details of the process in the details dialog, which are sent apart from [`BottomEvent::Update`] so they
+    /// never reach the recorder or the loggers.
+    ProcessDetails(Box<data_harvester::processes::ProcessDetails>),
     Clean,
 }
 
@@ -77,6 +81,9 @@ pub enum ThreadControlEvent {
     UpdateConfig(Box<app::AppConfigFields>),
     UpdateUsedWidgets(Box<UsedWidgets>),
     UpdateUpdateTime(u64),
+    UpdateDetailsPid(Option<Pid>),
+    UpdateThreadPids(fxhash::FxHashSet<Pid>),
+    Replay(replay::ReplayControl),
 }
 
 pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
@@ -101,6 +108,28 @@ pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
 
 pub fn handle_key_event_or_break(
     event: KeyEvent, app: &mut App, reset_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) -> bool {
+    let details_pid = app.process_details_state.pid();
+    let thread_pids = app.expanded_thread_pids();
+    let should_break = handle_key_event(event, app, reset_sender);
+
+    // Details are only collected for the process being looked at, so let the collection thread know if it changed.
+    let new_details_pid = app.process_details_state.pid();
+    if new_details_pid != details_pid {
+        let _ = reset_sender.send(ThreadControlEvent::UpdateDetailsPid(new_details_pid));
+    }
+
+    // The same goes for threads, which are only collected for processes showing them.
+    let new_thread_pids = app.expanded_thread_pids();
+    if new_thread_pids != thread_pids {
+        let _ = reset_sender.send(ThreadControlEvent::UpdateThreadPids(new_thread_pids));
+    }
+
+    should_break
+}
+
+fn handle_key_event(
+    event: KeyEvent, app: &mut App, reset_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
 ) -> bool {
     // debug!("KeyEvent: {:?}", event);
 
@@ -109,85 +138,105 @@ pub fn handle_key_event_or_break(
     // We can solve this (when we do paste probably) while keeping the throttle (mainly meant for movement)
     // by throttling after *bulk+singular* actions, not just singular ones.
 
+    // Ctrl-c always quits, so there's a way out no matter how the keys are bound.
+    if event.modifiers == KeyModifiers::CONTROL && event.code == KeyCode::Char('c') {
+        return true;
+    }
+
+    // Typing and editing in the search widget comes before any key bindings - otherwise you couldn't search
+    // with q.
+    if app.is_in_search_widget() && handle_search_key_event(event, app) {
+        return false;
+    }
+
+    if let Some(action) = app.get_key_action(event.into()) {
+        return handle_action(action, app, reset_sender);
+    }
+
     if event.modifiers.is_empty() {
-        // Required catch for searching - otherwise you couldn't search with q.
-        if event.code == KeyCode::Char('q') && !app.is_in_search_widget() {
-            return true;
-        }
         match event.code {
-            KeyCode::End => app.skip_to_last(),
-            KeyCode::Home => app.skip_to_first(),
-            KeyCode::Up => app.on_up_key(),
-            KeyCode::Down => app.on_down_key(),
-            KeyCode::Left => app.on_left_key(),
-            KeyCode::Right => app.on_right_key(),
             KeyCode::Char(caught_char) => app.on_char_key(caught_char),
             KeyCode::Esc => app.on_esc(),
             KeyCode::Enter => app.on_enter(),
             KeyCode::Tab => app.on_tab(),
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Handles the keys used to edit a search query, returning whether the key was one of them.
+fn handle_search_key_event(event: KeyEvent, app: &mut App) -> bool {
+    if event.modifiers.is_empty() || event.modifiers == KeyModifiers::SHIFT {
+        match event.code {
+            KeyCode::Char(caught_char) => app.on_char_key(caught_char),
+            KeyCode::Left if event.modifiers.is_empty() => app.on_left_key(),
+            KeyCode::Right if event.modifiers.is_empty() => app.on_right_key(),
             KeyCode::Backspace => app.on_backspace(),
             KeyCode::Delete => app.on_delete(),
             KeyCode::F(1) => app.toggle_ignore_case(),
             KeyCode::F(2) => app.toggle_search_whole_word(),
             KeyCode::F(3) => app.toggle_search_regex(),
-            KeyCode::F(5) => app.toggle_tree_mode(),
-            KeyCode::F(6) => app.toggle_sort(),
-            KeyCode::F(9) => app.start_killing_process(),
-            KeyCode::PageDown => app.on_page_down(),
-            KeyCode::PageUp => app.on_page_up(),
-            _ => {}
+            _ => return false,
+        }
+    } else if let KeyModifiers::ALT = event.modifiers {
+        match event.code {
+            KeyCode::Char('c') | KeyCode::Char('C') => app.toggle_ignore_case(),
+            KeyCode::Char('w') | KeyCode::Char('W') => app.toggle_search_whole_word(),
+            KeyCode::Char('r') | KeyCode::Char('R') => app.toggle_search_regex(),
+            KeyCode::Char('h') => app.on_left_key(),
+            KeyCode::Char('l') => app.on_right_key(),
+            _ => return false,
+        }
+    } else if let KeyModifiers::CONTROL = event.modifiers {
+        match event.code {
+            KeyCode::Char('a') => app.skip_cursor_beginning(),
+            KeyCode::Char('e') => app.skip_cursor_end(),
+            KeyCode::Char('u') => app.clear_search(),
+            KeyCode::Char('w') => app.clear_previous_word(),
+            KeyCode::Char('h') => app.on_backspace(),
+            // Can't do now, CTRL+BACKSPACE doesn't work and graphemes
+            // are hard to iter while truncating last (eloquently).
+            // KeyCode::Backspace => app.skip_word_backspace(),
+            _ => return false,
         }
     } else {
-        // Otherwise, track the modifier as well...
-        if let KeyModifiers::ALT = event.modifiers {
-            match event.code {
-                KeyCode::Char('c') | KeyCode::Char('C') => app.toggle_ignore_case(),
-                KeyCode::Char('w') | KeyCode::Char('W') => app.toggle_search_whole_word(),
-                KeyCode::Char('r') | KeyCode::Char('R') => app.toggle_search_regex(),
-                KeyCode::Char('h') => app.on_left_key(),
-                KeyCode::Char('l') => app.on_right_key(),
-                _ => {}
-            }
-        } else if let KeyModifiers::CONTROL = event.modifiers {
-            if event.code == KeyCode::Char('c') {
-                return true;
-            }
+        return false;
+    }
 
-            match event.code {
-                KeyCode::Char('f') => app.on_slash(),
-                KeyCode::Left => app.move_widget_selection(&WidgetDirection::Left),
-                KeyCode::Right => app.move_widget_selection(&WidgetDirection::Right),
-                KeyCode::Up => app.move_widget_selection(&WidgetDirection::Up),
-                KeyCode::Down => app.move_widget_selection(&WidgetDirection::Down),
-                KeyCode::Char('r') => {
-                    if reset_sender.send(ThreadControlEvent::Reset).is_ok() {
-                        app.reset();
-                    }
-                }
-                KeyCode::Char('a') => app.skip_cursor_beginning(),
-                KeyCode::Char('e') => app.skip_cursor_end(),
-                KeyCode::Char('u') => app.clear_search(),
-                KeyCode::Char('w') => app.clear_previous_word(),
-                KeyCode::Char('h') => app.on_backspace(),
-                // KeyCode::Char('j') => {}, // Move down
-                // KeyCode::Char('k') => {}, // Move up
-                // KeyCode::Char('h') => {}, // Move right
-                // KeyCode::Char('l') => {}, // Move left
-                // Can't do now, CTRL+BACKSPACE doesn't work and graphemes
-                // are hard to iter while truncating last (eloquently).
-                // KeyCode::Backspace => app.skip_word_backspace(),
-                _ => {}
-            }
-        } else if let KeyModifiers::SHIFT = event.modifiers {
-            match event.code {
-                KeyCode::Left => app.move_widget_selection(&WidgetDirection::Left),
-                KeyCode::Right => app.move_widget_selection(&WidgetDirection::Right),
-                KeyCode::Up => app.move_widget_selection(&WidgetDirection::Up),
-                KeyCode::Down => app.move_widget_selection(&WidgetDirection::Down),
-                KeyCode::Char(caught_char) => app.on_char_key(caught_char),
-                _ => {}
+    true
+}
+
+/// Performs an action that a key was bound to, returning whether to quit.
+fn handle_action(
+    action: Action, app: &mut App, reset_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) -> bool {
+    let control = match action {
+        Action::Quit => return true,
+        Action::Reset => {
+            if reset_sender.send(ThreadControlEvent::Reset).is_ok() {
+                app.reset();
             }
+            return false;
+        }
+        Action::ReplayPause => replay::ReplayControl::TogglePause,
+        Action::ReplaySpeedUp => replay::ReplayControl::SpeedUp,
+        Action::ReplaySlowDown => replay::ReplayControl::SlowDown,
+        Action::ReplaySeekForward => replay::ReplayControl::SeekForward,
+        Action::ReplaySeekBackward => replay::ReplayControl::SeekBackward,
+        _ => {
+            app.on_action(action);
+            return false;
+        }
+    };
+
+    if app.is_replaying {
+        // Seeking resends the data leading up to the new position, so clear out what's there.
+        if let replay::ReplayControl::SeekForward | replay::ReplayControl::SeekBackward = control {
+            app.data_collection.reset();
         }
+        let _ = reset_sender.send(ThreadControlEvent::Replay(control));
     }
 
     false
@@ -328,8 +377,23 @@ pub fn update_data(app: &mut App) {
     }
 
     if app.cpu_state.force_update.is_some() {
-        convert_cpu_data_points(&app.data_collection, &mut app.converted_data.cpu_data);
+        convert_cpu_data_points(
+            &app.data_collection,
+            &mut app.converted_data.cpu_data,
+            app.cpu_state.grouping,
+            &app.cpu_state.expanded_groups,
+        );
         app.converted_data.load_avg_data = app.data_collection.load_avg_harvest;
+
+        // Grouping CPUs or collapsing a group can leave fewer rows than where a legend was scrolled to.
+        let cpu_row_count = app.converted_data.cpu_data.len();
+        for cpu_widget_state in app.cpu_state.widget_states.values_mut() {
+            let table_state = &mut cpu_widget_state.table_state;
+            if table_state.current_scroll_position >= cpu_row_count {
+                table_state.current_scroll_position = cpu_row_count.saturating_sub(1);
+            }
+        }
+
         app.cpu_state.force_update = None;
     }
 
@@ -337,6 +401,7 @@ pub fn update_data(app: &mut App) {
     if app.mem_state.force_update.is_some() {
         app.converted_data.mem_data = convert_mem_data_points(&app.data_collection);
         app.converted_data.swap_data = convert_swap_data_points(&app.data_collection);
+        app.converted_data.mem_breakdown = convert_mem_breakdown(&app.data_collection);
         app.mem_state.force_update = None;
     }
 
@@ -349,8 +414,53 @@ pub fn update_data(app: &mut App) {
         );
         app.converted_data.network_data_rx = rx;
         app.converted_data.network_data_tx = tx;
+        app.converted_data.interface_graph_data = convert_interface_data_points(
+            &app.data_collection,
+            &app.net_state.graphed_interfaces,
+            &app.app_config_fields.network_scale_type,
+            &app.app_config_fields.network_unit_type,
+            app.app_config_fields.network_use_binary_prefix,
+        );
         app.net_state.force_update = None;
     }
+
+    if app.temp_graph_state.force_update.is_some() {
+        app.converted_data.temp_graph_data = convert_temp_graph_data_points(app);
+        app.temp_graph_state.force_update = None;
+    }
+
+    if app.disk_io_state.force_update.is_some() {
+        app.converted_data.disk_io_data = convert_disk_io_data_points(&app.data_collection);
+        app.disk_io_state.force_update = None;
+    }
+
+    if app.pressure_state.force_update.is_some() {
+        app.converted_data.pressure_data = convert_pressure_data_points(&app.data_collection);
+        app.pressure_state.force_update = None;
+    }
+
+    if app.cpu_freq_state.force_update.is_some() {
+        app.converted_data.cpu_frequency_data =
+            convert_cpu_frequency_data_points(&app.data_collection);
+        app.cpu_freq_state.force_update = None;
+    }
+
+    if app.cgroup_state.force_update {
+        let (cgroup_data, cgroup_paths) =
+            convert_cgroup_rows(&app.data_collection, &app.cgroup_state.collapsed_paths);
+
+        // Collapsing a group can leave fewer rows than where a table was scrolled to.
+        for cgroup_widget_state in app.cgroup_state.widget_states.values_mut() {
+            let table_state = &mut cgroup_widget_state.table_state;
+            if table_state.current_scroll_position >= cgroup_data.data.len() {
+                table_state.current_scroll_position = cgroup_data.data.len().saturating_sub(1);
+            }
+        }
+
+        app.converted_data.cgroup_data = cgroup_data;
+        app.converted_data.cgroup_paths = cgroup_paths;
+        app.cgroup_state.force_update = false;
+    }
 }
 
 pub fn create_input_thread(
@@ -396,6 +506,38 @@ pub fn create_input_thread(
     })
 }
 
+/// Collects data without drawing anything, and prints it to stdout as JSON.
+pub fn print_snapshot(
+    app_config_fields: &app::AppConfigFields, filters: app::DataFilters,
+) -> error::Result<()> {
+    let mut data_state = data_harvester::DataCollector::new(filters);
+
+    // Everything is harvested regardless of the layout, as there is nothing being drawn.
+    data_state.set_data_collection(UsedWidgets::all());
+    data_state.set_temperature_type(app_config_fields.temperature_type.clone());
+    data_state.set_use_current_cpu_total(app_config_fields.use_current_cpu_total);
+    data_state.set_show_average_cpu(app_config_fields.show_average_cpu);
+    data_state.set_collect_pss(app_config_fields.show_pss);
+
+    data_state.init();
+
+    for _ in 0..SNAPSHOT_UPDATE_PASSES {
+        thread::sleep(Duration::from_millis(
+            app_config_fields.update_rate_in_milliseconds,
+        ));
+        futures::executor::block_on(data_state.update_data());
+    }
+
+    let mut stdout = stdout();
+    writeln!(stdout, "{}", serde_json::to_string(&data_state.data)?)?;
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Spawns the thread that harvests data and sends it to `sender`. Each of `harvest_senders` is also sent a copy of
+/// every harvest, so it can be recorded or logged on its own thread.
+#[allow(clippy::too_many_arguments)]
 pub fn create_collection_thread(
     sender: std::sync::mpsc::Sender<
         BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>,
@@ -404,10 +546,12 @@ pub fn create_collection_thread(
     termination_ctrl_lock: Arc<Mutex<bool>>, termination_ctrl_cvar: Arc<Condvar>,
     app_config_fields: &app::AppConfigFields, filters: app::DataFilters,
     used_widget_set: UsedWidgets,
+    mut harvest_senders: Vec<std::sync::mpsc::Sender<Box<data_harvester::Data>>>,
 ) -> std::thread::JoinHandle<()> {
     let temp_type = app_config_fields.temperature_type.clone();
     let use_current_cpu_total = app_config_fields.use_current_cpu_total;
     let show_average_cpu = app_config_fields.show_average_cpu;
+    let show_pss = app_config_fields.show_pss;
     let update_rate_in_milliseconds = app_config_fields.update_rate_in_milliseconds;
 
     thread::spawn(move || {
@@ -417,6 +561,7 @@ pub fn create_collection_thread(
         data_state.set_temperature_type(temp_type);
         data_state.set_use_current_cpu_total(use_current_cpu_total);
         data_state.set_show_average_cpu(show_average_cpu);
+        data_state.set_collect_pss(show_pss);
 
         data_state.init();
 
@@ -442,6 +587,7 @@ pub fn create_collection_thread(
                         data_state
                             .set_use_current_cpu_total(app_config_fields.use_current_cpu_total);
                         data_state.set_show_average_cpu(app_config_fields.show_average_cpu);
+                        data_state.set_collect_pss(app_config_fields.show_pss);
                     }
                     ThreadControlEvent::UpdateUsedWidgets(used_widget_set) => {
                         data_state.set_data_collection(*used_widget_set);
@@ -449,6 +595,13 @@ pub fn create_collection_thread(
                     ThreadControlEvent::UpdateUpdateTime(new_time) => {
                         update_time = new_time;
                     }
+                    ThreadControlEvent::UpdateDetailsPid(details_pid) => {
+                        data_state.set_details_pid(details_pid);
+                    }
+                    ThreadControlEvent::UpdateThreadPids(thread_pids) => {
+                        data_state.set_thread_pids(thread_pids);
+                    }
+                    ThreadControlEvent::Replay(_) => {}
                 }
             }
             futures::executor::block_on(data_state.update_data());
@@ -462,12 +615,28 @@ pub fn create_collection_thread(
                 }
             }
 
+            // Anything that has stopped listening is dropped.
+            harvest_senders.retain(|harvest_sender| {
+                harvest_sender
+                    .send(Box::new(data_state.data.clone()))
+                    .is_ok()
+            });
+
             let event = BottomEvent::Update(Box::from(data_state.data));
             data_state.data = data_harvester::Data::default();
             if sender.send(event).is_err() {
                 break;
             }
 
+            if let Some(process_details) = data_state.process_details.take() {
+                if sender
+                    .send(BottomEvent::ProcessDetails(Box::new(process_details)))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+
             if let Ok((is_terminated, _wait_timeout_result)) = termination_ctrl_cvar.wait_timeout(
                 termination_ctrl_lock.lock().unwrap(),
                 Duration::from_millis(update_time),
@@ -0,0 +1,611 @@
+//! Serves harvested data over HTTP in the OpenMetrics text format, without drawing anything.
+
+use std::{
+    fmt::Write as _,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, Arc, Condvar, Mutex},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    app::{
+        data_harvester::{sensors::SensorType, temperature::TemperatureType, Data},
+        layout_manager::UsedWidgets,
+        AppConfigFields, DataFilters,
+    },
+    create_collection_thread,
+    utils::error::{self, BottomError},
+    BottomEvent,
+};
+
+const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Options for what gets exported as metrics.
+#[derive(Clone, Debug)]
+pub struct MetricsOptions {
+    /// How many processes to export, sorted by CPU usage. If 0, no processes are exported.
+    pub num_processes: usize,
+
+    /// The temperature unit that temperatures are harvested in, used for the metric name.
+    pub temperature_type: TemperatureType,
+}
+
+/// Starts the collection thread and serves the latest harvest at `addr` until the program is killed.
+pub fn serve_metrics(
+    addr: &str, app_config_fields: &AppConfigFields, filters: DataFilters, options: MetricsOptions,
+) -> error::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .map_err(|err| BottomError::GenericError(format!("Could not bind to {}: {}", addr, err)))?;
+
+    #[allow(clippy::mutex_atomic)]
+    let thread_termination_lock = Arc::new(Mutex::new(false));
+    let thread_termination_cvar = Arc::new(Condvar::new());
+
+    let (sender, receiver) = mpsc::channel();
+    let (_collection_thread_ctrl_sender, collection_thread_ctrl_receiver) = mpsc::channel();
+    let _collection_thread = create_collection_thread(
+        sender,
+        collection_thread_ctrl_receiver,
+        thread_termination_lock,
+        thread_termination_cvar,
+        app_config_fields,
+        filters,
+        UsedWidgets::all(),
+        Vec::new(),
+    );
+
+    // Only the most recent harvest is kept around; older ones are just dropped.
+    let latest_data: Arc<Mutex<Option<Box<Data>>>> = Arc::new(Mutex::new(None));
+    {
+        let latest_data = latest_data.clone();
+        thread::spawn(move || {
+            while let Ok(event) = receiver.recv() {
+                if let BottomEvent::Update(data) = event {
+                    if let Ok(mut latest_data) = latest_data.lock() {
+                        *latest_data = Some(data);
+                    }
+                }
+            }
+        });
+    }
+
+    for stream in listener.incoming().flatten() {
+        // Each connection is handled on its own thread, so a slow client can't hold up other scrapers. A bad
+        // connection shouldn't take the entire exporter down, so errors are just ignored.
+        let latest_data = latest_data.clone();
+        let options = options.clone();
+        thread::spawn(move || {
+            let _ = handle_connection(stream, &latest_data, &options);
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_connection(
+    mut stream: TcpStream, latest_data: &Mutex<Option<Box<Data>>>, options: &MetricsOptions,
+) -> error::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(5)))?;
+
+    let mut request_line = String::new();
+    BufReader::new(&mut stream).read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    let (status, content_type, body) = if method != "GET" {
+        ("405 Method Not Allowed", "text/plain", String::new())
+    } else if path == "/metrics" || path == "/" {
+        let body = match latest_data.lock() {
+            Ok(data) => match data.as_ref() {
+                Some(data) => encode(data, options),
+                None => "# EOF\n".to_string(),
+            },
+            Err(_) => "# EOF\n".to_string(),
+        };
+        ("200 OK", CONTENT_TYPE, body)
+    } else {
+        ("404 Not Found", "text/plain", String::new())
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+/// Escapes a label value as per the OpenMetrics spec.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Formats a sample value as per the OpenMetrics spec, which spells infinities and NaN differently from Rust.
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// A helper to write out metric families.
+struct MetricsWriter {
+    output: String,
+}
+
+impl MetricsWriter {
+    fn family(&mut self, name: &str, metric_type: &str, help: &str) {
+        let _ = writeln!(self.output, "# TYPE {} {}", name, metric_type);
+        let _ = writeln!(self.output, "# HELP {} {}", name, help);
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.output.push_str(name);
+        if !labels.is_empty() {
+            self.output.push('{');
+            for (index, (key, val)) in labels.iter().enumerate() {
+                if index > 0 {
+                    self.output.push(',');
+                }
+                let _ = write!(self.output, "{}=\"{}\"", key, escape_label(val));
+            }
+            self.output.push('}');
+        }
+        let _ = writeln!(self.output, " {}", format_value(value));
+    }
+}
+
+/// Encodes harvested data in the OpenMetrics text format.
+pub fn encode(data: &Data, options: &MetricsOptions) -> String {
+    let mut writer = MetricsWriter {
+        output: String::new(),
+    };
+
+    if let Some(cpu) = &data.cpu {
+        writer.family(
+            "bottom_cpu_usage_percent",
+            "gauge",
+            "CPU usage as a percentage, per core and averaged.",
+        );
+        for cpu_data in cpu {
+            let cpu_label = match cpu_data.cpu_count {
+                Some(count) => count.to_string(),
+                None => cpu_data.cpu_prefix.to_lowercase(),
+            };
+            writer.sample(
+                "bottom_cpu_usage_percent",
+                &[("cpu", &cpu_label)],
+                cpu_data.cpu_usage,
+            );
+        }
+    }
+
+    if let Some(load_avg) = &data.load_avg {
+        writer.family("bottom_load_average", "gauge", "The system load average.");
+        for (period, value) in ["1m", "5m", "15m"].iter().zip(load_avg) {
+            writer.sample(
+                "bottom_load_average",
+                &[("period", period)],
+                f64::from(*value),
+            );
+        }
+    }
+
+    if let Some(pressure) = &data.pressure {
+        let mut kinds = vec![];
+        for (resource, pressure) in [
+            ("cpu", &pressure.cpu),
+            ("memory", &pressure.memory),
+            ("io", &pressure.io),
+        ] {
+            if let Some(pressure) = pressure {
+                kinds.push((resource, "some", pressure.some));
+                if let Some(full) = pressure.full {
+                    kinds.push((resource, "full", full));
+                }
+            }
+        }
+
+        writer.family(
+            "bottom_pressure_stalled_ratio",
+            "gauge",
+            "The share of time that tasks were stalled on a resource, averaged over a window.",
+        );
+        for (resource, kind, values) in &kinds {
+            for (window, avg) in [
+                ("10s", values.avg10),
+                ("60s", values.avg60),
+                ("300s", values.avg300),
+            ] {
+                writer.sample(
+                    "bottom_pressure_stalled_ratio",
+                    &[("resource", resource), ("kind", kind), ("window", window)],
+                    avg / 100.0,
+                );
+            }
+        }
+
+        writer.family(
+            "bottom_pressure_stalled_seconds",
+            "counter",
+            "Total time that tasks were stalled on a resource.",
+        );
+        for (resource, kind, values) in &kinds {
+            writer.sample(
+                "bottom_pressure_stalled_seconds_total",
+                &[("resource", resource), ("kind", kind)],
+                values.total as f64 / 1_000_000.0,
+            );
+        }
+    }
+
+    for (name, harvest) in [("memory", &data.memory), ("swap", &data.swap)] {
+        if let Some(harvest) = harvest {
+            let total_name = format!("bottom_{}_total_bytes", name);
+            writer.family(&total_name, "gauge", &format!("Total {} in bytes.", name));
+            writer.sample(&total_name, &[], (harvest.mem_total_in_kib * 1024) as f64);
+
+            let used_name = format!("bottom_{}_used_bytes", name);
+            writer.family(&used_name, "gauge", &format!("Used {} in bytes.", name));
+            writer.sample(&used_name, &[], (harvest.mem_used_in_kib * 1024) as f64);
+        }
+    }
+
+    if let Some(disks) = &data.disks {
+        for (suffix, help) in [
+            ("used", "Used disk space in bytes."),
+            ("free", "Free disk space in bytes."),
+            ("total", "Total disk space in bytes."),
+        ] {
+            let name = format!("bottom_disk_{}_bytes", suffix);
+            writer.family(&name, "gauge", help);
+            for disk in disks {
+                let value = match suffix {
+                    "used" => disk.used_space,
+                    "free" => disk.free_space,
+                    _ => disk.total_space,
+                };
+                if let Some(value) = value {
+                    writer.sample(
+                        &name,
+                        &[("disk", &disk.name), ("mount", &disk.mount_point)],
+                        value as f64,
+                    );
+                }
+            }
+        }
+    }
+
+    if let Some(io) = &data.io {
+        let mut devices = io.iter().collect::<Vec<_>>();
+        devices.sort_by(|a, b| a.0.cmp(b.0));
+
+        writer.family(
+            "bottom_disk_read_bytes",
+            "counter",
+            "Total bytes read from a disk.",
+        );
+        for (device, io_data) in &devices {
+            if let Some(io_data) = io_data {
+                writer.sample(
+                    "bottom_disk_read_bytes_total",
+                    &[("device", device)],
+                    io_data.read_bytes as f64,
+                );
+            }
+        }
+
+        writer.family(
+            "bottom_disk_written_bytes",
+            "counter",
+            "Total bytes written to a disk.",
+        );
+        for (device, io_data) in &devices {
+            if let Some(io_data) = io_data {
+                writer.sample(
+                    "bottom_disk_written_bytes_total",
+                    &[("device", device)],
+                    io_data.write_bytes as f64,
+                );
+            }
+        }
+    }
+
+    if let Some(network) = &data.network {
+        writer.family(
+            "bottom_network_rx_bits_per_second",
+            "gauge",
+            "The current network receive rate in bits per second.",
+        );
+        writer.sample("bottom_network_rx_bits_per_second", &[], network.rx as f64);
+        writer.family(
+            "bottom_network_tx_bits_per_second",
+            "gauge",
+            "The current network transmit rate in bits per second.",
+        );
+        writer.sample("bottom_network_tx_bits_per_second", &[], network.tx as f64);
+        writer.family(
+            "bottom_network_rx_bits",
+            "counter",
+            "Total bits received over the network.",
+        );
+        writer.sample("bottom_network_rx_bits_total", &[], network.total_rx as f64);
+        writer.family(
+            "bottom_network_tx_bits",
+            "counter",
+            "Total bits transmitted over the network.",
+        );
+        writer.sample("bottom_network_tx_bits_total", &[], network.total_tx as f64);
+    }
+
+    if let Some(temperatures) = &data.temperature_sensors {
+        let name = match options.temperature_type {
+            TemperatureType::Celsius => "bottom_temperature_celsius",
+            TemperatureType::Kelvin => "bottom_temperature_kelvin",
+            TemperatureType::Fahrenheit => "bottom_temperature_fahrenheit",
+        };
+        writer.family(name, "gauge", "The temperature of a sensor.");
+        for temperature in temperatures {
+            writer.sample(
+                name,
+                &[("sensor", &temperature.name)],
+                f64::from(temperature.temperature),
+            );
+        }
+    }
+
+    if let Some(sensors) = &data.sensors {
+        for (sensor_type, name, help) in [
+            (
+                SensorType::Fan,
+                "bottom_fan_speed_rpm",
+                "The speed of a fan.",
+            ),
+            (
+                SensorType::Voltage,
+                "bottom_voltage_volts",
+                "The voltage of a sensor.",
+            ),
+            (
+                SensorType::Current,
+                "bottom_current_amperes",
+                "The current through a sensor.",
+            ),
+            (
+                SensorType::Power,
+                "bottom_power_watts",
+                "The power used, as reported by a sensor.",
+            ),
+        ] {
+            let mut sensors = sensors
+                .iter()
+                .filter(|sensor| sensor.sensor_type == sensor_type)
+                .peekable();
+            if sensors.peek().is_some() {
+                writer.family(name, "gauge", help);
+                for sensor in sensors {
+                    writer.sample(
+                        name,
+                        &[("sensor", &sensor.name), ("device", &sensor.device)],
+                        sensor.value,
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "battery")]
+    if let Some(batteries) = &data.list_of_batteries {
+        let batteries = batteries
+            .iter()
+            .enumerate()
+            .map(|(index, battery)| (index.to_string(), battery))
+            .collect::<Vec<_>>();
+
+        writer.family(
+            "bottom_battery_charge_percent",
+            "gauge",
+            "The charge of a battery as a percentage.",
+        );
+        for (index, battery) in &batteries {
+            writer.sample(
+                "bottom_battery_charge_percent",
+                &[("battery", index)],
+                battery.charge_percent,
+            );
+        }
+
+        writer.family(
+            "bottom_battery_power_watts",
+            "gauge",
+            "The power consumption rate of a battery in watts.",
+        );
+        for (index, battery) in &batteries {
+            writer.sample(
+                "bottom_battery_power_watts",
+                &[("battery", index)],
+                battery.power_consumption_rate_watts,
+            );
+        }
+
+        writer.family(
+            "bottom_battery_health_percent",
+            "gauge",
+            "The health of a battery as a percentage.",
+        );
+        for (index, battery) in &batteries {
+            writer.sample(
+                "bottom_battery_health_percent",
+                &[("battery", index)],
+                battery.health_percent,
+            );
+        }
+    }
+
+    if options.num_processes > 0 {
+        if let Some(processes) = &data.list_of_processes {
+            let mut processes = processes.iter().collect::<Vec<_>>();
+            processes.sort_by(|a, b| {
+                b.cpu_usage_percent
+                    .partial_cmp(&a.cpu_usage_percent)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            processes.truncate(options.num_processes);
+
+            let processes = processes
+                .into_iter()
+                .map(|process| (process.pid.to_string(), process))
+                .collect::<Vec<_>>();
+
+            writer.family(
+                "bottom_process_cpu_usage_percent",
+                "gauge",
+                "The CPU usage of a process as a percentage.",
+            );
+            for (pid, process) in &processes {
+                writer.sample(
+                    "bottom_process_cpu_usage_percent",
+                    &[("pid", pid), ("name", &process.name)],
+                    process.cpu_usage_percent,
+                );
+            }
+
+            writer.family(
+                "bottom_process_memory_bytes",
+                "gauge",
+                "The resident memory of a process in bytes.",
+            );
+            for (pid, process) in &processes {
+                writer.sample(
+                    "bottom_process_memory_bytes",
+                    &[("pid", pid), ("name", &process.name)],
+                    process.mem_usage_bytes as f64,
+                );
+            }
+        }
+    }
+
+    writer.output.push_str("# EOF\n");
+    writer.output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::app::data_harvester::{
+        cpu::CpuData,
+        memory::MemHarvest,
+        pressure::{Pressure, PressureHarvest, PressureValues},
+        sensors::SensorHarvest,
+        temperature::TempHarvest,
+    };
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(1.5), "1.5");
+        assert_eq!(format_value(40.0), "40");
+        assert_eq!(format_value(f64::NAN), "NaN");
+        assert_eq!(format_value(f64::INFINITY), "+Inf");
+        assert_eq!(format_value(f64::NEG_INFINITY), "-Inf");
+    }
+
+    #[test]
+    fn test_encode() {
+        let data = Data {
+            cpu: Some(vec![
+                CpuData {
+                    cpu_prefix: "AVG".to_string(),
+                    cpu_count: None,
+                    cpu_id: None,
+                    cpu_usage: 12.5,
+                    breakdown: None,
+                    frequency: None,
+                    topology: None,
+                },
+                CpuData {
+                    cpu_prefix: "CPU".to_string(),
+                    cpu_count: Some(0),
+                    cpu_id: Some(0),
+                    cpu_usage: 25.0,
+                    breakdown: None,
+                    frequency: None,
+                    topology: None,
+                },
+            ]),
+            memory: Some(MemHarvest {
+                mem_total_in_kib: 2,
+                mem_used_in_kib: 1,
+                use_percent: Some(50.0),
+                breakdown: None,
+            }),
+            temperature_sensors: Some(vec![TempHarvest {
+                name: "a \"quoted\" sensor".to_string(),
+                temperature: 40.0,
+                high: None,
+                critical: None,
+            }]),
+            pressure: Some(PressureHarvest {
+                io: Some(Pressure {
+                    some: PressureValues {
+                        avg10: 25.0,
+                        avg60: 0.0,
+                        avg300: 0.0,
+                        total: 1_500_000,
+                    },
+                    full: None,
+                }),
+                ..PressureHarvest::default()
+            }),
+            sensors: Some(vec![SensorHarvest {
+                name: "nct6775: fan1".to_string(),
+                device: "hwmon3".to_string(),
+                sensor_type: SensorType::Fan,
+                value: 1200.0,
+            }]),
+            ..Data::default()
+        };
+        let options = MetricsOptions {
+            num_processes: 0,
+            temperature_type: TemperatureType::Celsius,
+        };
+
+        let encoded = encode(&data, &options);
+        assert!(encoded.contains("bottom_cpu_usage_percent{cpu=\"avg\"} 12.5\n"));
+        assert!(encoded.contains("bottom_cpu_usage_percent{cpu=\"0\"} 25\n"));
+        assert!(encoded.contains("# TYPE bottom_memory_total_bytes gauge\n"));
+        assert!(encoded.contains("bottom_memory_used_bytes 1024\n"));
+        assert!(
+            encoded.contains("bottom_temperature_celsius{sensor=\"a \\\"quoted\\\" sensor\"} 40\n")
+        );
+        assert!(encoded.contains(
+            "bottom_pressure_stalled_ratio{resource=\"io\",kind=\"some\",window=\"10s\"} 0.25\n"
+        ));
+        assert!(encoded.contains(
+            "bottom_pressure_stalled_seconds_total{resource=\"io\",kind=\"some\"} 1.5\n"
+        ));
+        assert!(encoded
+            .contains("bottom_fan_speed_rpm{sensor=\"nct6775: fan1\",device=\"hwmon3\"} 1200\n"));
+        assert!(!encoded.contains("bottom_power_watts"));
+        assert!(!encoded.contains("kind=\"full\""));
+        assert!(!encoded.contains("bottom_process_"));
+        assert!(encoded.ends_with("# EOF\n"));
+    }
+}
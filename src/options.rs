@@ -11,8 +11,12 @@ use std::{
 
 use crate::{
     app::{
+        keybindings::{ConfigKeys, KeyBindings},
         layout_manager::*,
-        widgets::{DiskWidgetState, ProcWidget, ProcWidgetMode, TempWidgetState},
+        widgets::{
+            CgroupWidgetState, DiskWidgetState, NetInterfaceWidgetState, ProcWidget,
+            ProcWidgetMode, ProcWidgetOptions, SensorWidgetState, TempWidgetState,
+        },
         *,
     },
     canvas::ColourScheme,
@@ -38,6 +42,9 @@ pub struct Config {
     pub mount_filter: Option<IgnoreList>,
     pub temp_filter: Option<IgnoreList>,
     pub net_filter: Option<IgnoreList>,
+    pub sensor_filter: Option<IgnoreList>,
+    pub temp_threshold: Option<Vec<ConfigTempThreshold>>,
+    pub keybindings: Option<HashMap<String, ConfigKeys>>,
 }
 
 impl Config {
@@ -160,6 +167,12 @@ pub struct ConfigFlags {
     #[builder(default, setter(strip_option))]
     pub process_command: Option<bool>,
 
+    #[builder(default, setter(strip_option))]
+    pub show_pss: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub show_cpu_history: Option<bool>,
+
     #[builder(default, setter(strip_option))]
     pub disable_advanced_kill: Option<bool>,
 
@@ -197,8 +210,11 @@ pub struct ConfigColours {
     pub all_cpu_color: Option<String>,
     pub avg_cpu_color: Option<String>,
     pub cpu_core_colors: Option<Vec<String>>,
+    pub cpu_time_colors: Option<Vec<String>>,
     pub ram_color: Option<String>,
     pub swap_color: Option<String>,
+    pub buffers_color: Option<String>,
+    pub cache_color: Option<String>,
     pub rx_color: Option<String>,
     pub tx_color: Option<String>,
     pub rx_total_color: Option<String>, // These only affect basic mode.
@@ -214,6 +230,10 @@ pub struct ConfigColours {
     pub high_battery_color: Option<String>,
     pub medium_battery_color: Option<String>,
     pub low_battery_color: Option<String>,
+    pub temp_warning_color: Option<String>,
+    pub temp_critical_color: Option<String>,
+    pub stopped_process_color: Option<String>,
+    pub marked_process_color: Option<String>,
 }
 
 impl ConfigColours {
@@ -248,6 +268,21 @@ pub struct IgnoreList {
     pub whole_word: bool,
 }
 
+/// A user's own warning and critical temperatures for the sensors matching `sensor`, which is matched the same way
+/// as the entries of an [`IgnoreList`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ConfigTempThreshold {
+    pub sensor: String,
+    pub warning: Option<f32>,
+    pub critical: Option<f32>,
+    #[serde(default = "bool::default")]
+    pub regex: bool,
+    #[serde(default = "bool::default")]
+    pub case_sensitive: bool,
+    #[serde(default = "bool::default")]
+    pub whole_word: bool,
+}
+
 pub fn build_app(
     matches: &clap::ArgMatches, config: &mut Config, widget_layout: &BottomLayout,
     default_widget_id: u64, default_widget_type_option: &Option<BottomWidgetType>,
@@ -272,6 +307,13 @@ pub fn build_app(
     let mut proc_state_map: HashMap<u64, ProcWidget> = HashMap::new();
     let mut temp_state_map: HashMap<u64, TempWidgetState> = HashMap::new();
     let mut disk_state_map: HashMap<u64, DiskWidgetState> = HashMap::new();
+    let mut disk_io_state_map: HashMap<u64, DiskIoWidgetState> = HashMap::new();
+    let mut temp_graph_state_map: HashMap<u64, TempGraphWidgetState> = HashMap::new();
+    let mut net_interface_state_map: HashMap<u64, NetInterfaceWidgetState> = HashMap::new();
+    let mut cgroup_state_map: HashMap<u64, CgroupWidgetState> = HashMap::new();
+    let mut sensor_state_map: HashMap<u64, SensorWidgetState> = HashMap::new();
+    let mut pressure_state_map: HashMap<u64, PressureWidgetState> = HashMap::new();
+    let mut cpu_freq_state_map: HashMap<u64, CpuFreqWidgetState> = HashMap::new();
     let mut battery_state_map: HashMap<u64, BatteryWidgetState> = HashMap::new();
 
     let autohide_timer = if autohide_time {
@@ -288,6 +330,17 @@ pub fn build_app(
     let show_memory_as_values = get_mem_as_value(matches, config);
     let is_default_tree = get_is_default_tree(matches, config);
     let is_default_command = get_is_default_process_command(matches, config);
+    let show_pss = get_show_pss(matches, config);
+    let show_cpu_history = get_show_cpu_history(matches, config);
+    let proc_widget_options = ProcWidgetOptions {
+        is_case_sensitive,
+        is_match_whole_word,
+        is_use_regex,
+        show_memory_as_values,
+        is_command: is_default_command,
+        show_pss,
+        show_cpu_history,
+    };
     let is_advanced_kill = !get_is_advanced_kill_disabled(matches, config);
 
     let network_unit_type = get_network_unit_type(matches, config);
@@ -364,19 +417,46 @@ pub fn build_app(
 
                             proc_state_map.insert(
                                 widget.widget_id,
-                                ProcWidget::init(
-                                    mode,
-                                    is_case_sensitive,
-                                    is_match_whole_word,
-                                    is_use_regex,
-                                    show_memory_as_values,
-                                    is_default_command,
-                                ),
+                                ProcWidget::init(mode, proc_widget_options),
                             );
                         }
                         Disk => {
                             disk_state_map.insert(widget.widget_id, DiskWidgetState::default());
                         }
+                        TempGraph => {
+                            temp_graph_state_map.insert(
+                                widget.widget_id,
+                                TempGraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        DiskIo => {
+                            disk_io_state_map.insert(
+                                widget.widget_id,
+                                DiskIoWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        NetInterfaces => {
+                            net_interface_state_map
+                                .insert(widget.widget_id, NetInterfaceWidgetState::default());
+                        }
+                        Cgroups => {
+                            cgroup_state_map.insert(widget.widget_id, CgroupWidgetState::default());
+                        }
+                        Sensors => {
+                            sensor_state_map.insert(widget.widget_id, SensorWidgetState::default());
+                        }
+                        Pressure => {
+                            pressure_state_map.insert(
+                                widget.widget_id,
+                                PressureWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        CpuFreq => {
+                            cpu_freq_state_map.insert(
+                                widget.widget_id,
+                                CpuFreqWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
                         Temp => {
                             temp_state_map.insert(widget.widget_id, TempWidgetState::default());
                         }
@@ -441,20 +521,31 @@ pub fn build_app(
         // no_write: get_no_write(matches, config),
         no_write: false,
         show_table_scroll_position: get_show_table_scroll_position(matches, config),
+        show_pss,
         is_advanced_kill,
         network_scale_type,
         network_unit_type,
         network_use_binary_prefix,
+        temp_thresholds: get_temp_thresholds(&config.temp_threshold)
+            .context("Update 'temp_threshold' in your config file.")?,
     };
 
     let used_widgets = UsedWidgets {
-        use_cpu: used_widget_set.get(&Cpu).is_some() || used_widget_set.get(&BasicCpu).is_some(),
+        use_cpu: used_widget_set.get(&Cpu).is_some()
+            || used_widget_set.get(&BasicCpu).is_some()
+            || used_widget_set.contains(&CpuFreq),
         use_mem: used_widget_set.get(&Mem).is_some() || used_widget_set.get(&BasicMem).is_some(),
-        use_net: used_widget_set.get(&Net).is_some() || used_widget_set.get(&BasicNet).is_some(),
+        use_net: used_widget_set.get(&Net).is_some()
+            || used_widget_set.get(&BasicNet).is_some()
+            || used_widget_set.contains(&NetInterfaces),
         use_proc: used_widget_set.get(&Proc).is_some(),
-        use_disk: used_widget_set.get(&Disk).is_some(),
-        use_temp: used_widget_set.get(&Temp).is_some(),
+        use_disk: used_widget_set.get(&Disk).is_some() || used_widget_set.contains(&DiskIo),
+        use_temp: used_widget_set.get(&Temp).is_some() || used_widget_set.contains(&TempGraph),
         use_battery: used_widget_set.get(&Battery).is_some(),
+        use_cgroup: used_widget_set.contains(&Cgroups),
+        use_sensors: used_widget_set.contains(&Sensors),
+        use_pressure: used_widget_set.contains(&Pressure),
+        use_cpu_freq: used_widget_set.contains(&CpuFreq),
     };
 
     let disk_filter =
@@ -465,6 +556,11 @@ pub fn build_app(
         get_ignore_list(&config.temp_filter).context("Update 'temp_filter' in your config file")?;
     let net_filter =
         get_ignore_list(&config.net_filter).context("Update 'net_filter' in your config file")?;
+    let sensor_filter = get_ignore_list(&config.sensor_filter)
+        .context("Update 'sensor_filter' in your config file")?;
+
+    let key_bindings = KeyBindings::new(&config.keybindings)
+        .context("Update 'keybindings' in your config file.")?;
 
     // One more thing - we have to update the search settings of our proc_state_map, and create the hashmaps if needed!
     // Note that if you change your layout, this might not actually match properly... not sure if/where we should deal with that...
@@ -515,6 +611,13 @@ pub fn build_app(
         .net_state(NetState::init(net_state_map))
         .proc_state(ProcState::init(proc_state_map))
         .disk_state(DiskState::init(disk_state_map))
+        .disk_io_state(DiskIoState::init(disk_io_state_map))
+        .temp_graph_state(TempGraphState::init(temp_graph_state_map))
+        .net_interface_state(NetInterfaceState::init(net_interface_state_map))
+        .cgroup_state(CgroupState::init(cgroup_state_map))
+        .sensor_state(SensorState::init(sensor_state_map))
+        .pressure_state(PressureState::init(pressure_state_map))
+        .cpu_freq_state(CpuFreqState::init(cpu_freq_state_map))
         .temp_state(TempState::init(temp_state_map))
         .battery_state(BatteryState::init(battery_state_map))
         .basic_table_widget_state(basic_table_widget_state)
@@ -526,7 +629,9 @@ pub fn build_app(
             mount_filter,
             temp_filter,
             net_filter,
+            sensor_filter,
         })
+        .key_bindings(key_bindings)
         .config(config.clone())
         .config_path(config_path)
         .build())
@@ -948,31 +1053,39 @@ fn get_no_write(matches: &clap::ArgMatches, config: &Config) -> bool {
     false
 }
 
+/// Builds the regex for an entry of a filter or threshold from its matching options.
+fn get_name_regex(
+    name: &str, regex: bool, case_sensitive: bool, whole_word: bool,
+) -> std::result::Result<Regex, regex::Error> {
+    let escaped_string: String;
+    let res = format!(
+        "{}{}{}{}",
+        if whole_word { "^" } else { "" },
+        if case_sensitive { "" } else { "(?i)" },
+        if regex {
+            name
+        } else {
+            escaped_string = regex::escape(name);
+            &escaped_string
+        },
+        if whole_word { "$" } else { "" },
+    );
+
+    Regex::new(&res)
+}
+
 fn get_ignore_list(ignore_list: &Option<IgnoreList>) -> error::Result<Option<Filter>> {
     if let Some(ignore_list) = ignore_list {
         let list: Result<Vec<_>, _> = ignore_list
             .list
             .iter()
             .map(|name| {
-                let escaped_string: String;
-                let res = format!(
-                    "{}{}{}{}",
-                    if ignore_list.whole_word { "^" } else { "" },
-                    if ignore_list.case_sensitive {
-                        ""
-                    } else {
-                        "(?i)"
-                    },
-                    if ignore_list.regex {
-                        name
-                    } else {
-                        escaped_string = regex::escape(name);
-                        &escaped_string
-                    },
-                    if ignore_list.whole_word { "$" } else { "" },
-                );
-
-                Regex::new(&res)
+                get_name_regex(
+                    name,
+                    ignore_list.regex,
+                    ignore_list.case_sensitive,
+                    ignore_list.whole_word,
+                )
             })
             .collect();
 
@@ -985,6 +1098,36 @@ fn get_ignore_list(ignore_list: &Option<IgnoreList>) -> error::Result<Option<Fil
     }
 }
 
+fn get_temp_thresholds(
+    thresholds: &Option<Vec<ConfigTempThreshold>>,
+) -> error::Result<Vec<data_harvester::temperature::TempThreshold>> {
+    thresholds
+        .iter()
+        .flatten()
+        .map(|threshold| {
+            if let (Some(warning), Some(critical)) = (threshold.warning, threshold.critical) {
+                if warning > critical {
+                    return Err(BottomError::ConfigError(format!(
+                        "the warning temperature of \"{}\" is above its critical temperature.",
+                        threshold.sensor
+                    )));
+                }
+            }
+
+            Ok(data_harvester::temperature::TempThreshold {
+                sensor: get_name_regex(
+                    &threshold.sensor,
+                    threshold.regex,
+                    threshold.case_sensitive,
+                    threshold.whole_word,
+                )?,
+                warning: threshold.warning,
+                critical: threshold.critical,
+            })
+        })
+        .collect()
+}
+
 pub fn get_color_scheme(
     matches: &clap::ArgMatches, config: &Config,
 ) -> error::Result<ColourScheme> {
@@ -1056,6 +1199,28 @@ fn get_is_default_process_command(matches: &clap::ArgMatches, config: &Config) -
     false
 }
 
+fn get_show_pss(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("show_pss") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(show_pss) = flags.show_pss {
+            return show_pss;
+        }
+    }
+    false
+}
+
+fn get_show_cpu_history(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("show_cpu_history") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(show_cpu_history) = flags.show_cpu_history {
+            return show_cpu_history;
+        }
+    }
+    false
+}
+
 fn get_is_advanced_kill_disabled(matches: &clap::ArgMatches, config: &Config) -> bool {
     if matches.is_present("disable_advanced_kill") {
         return true;
@@ -52,8 +52,15 @@ impl Row {
                         }
 
                         children.push(match widget_type {
-                            BottomWidgetType::Cpu => {
-                                let cpu_id = *iter_id;
+                            BottomWidgetType::Cpu
+                            | BottomWidgetType::DiskIo
+                            | BottomWidgetType::TempGraph => {
+                                let graph_id = *iter_id;
+                                let legend_type = match widget_type {
+                                    BottomWidgetType::Cpu => BottomWidgetType::CpuLegend,
+                                    BottomWidgetType::DiskIo => BottomWidgetType::DiskIoLegend,
+                                    _ => BottomWidgetType::TempGraphLegend,
+                                };
                                 *iter_id += 1;
                                 BottomCol::builder()
                                     .col_width_ratio(width_ratio)
@@ -63,7 +70,7 @@ impl Row {
                                             .children(vec![
                                                 BottomWidget::builder()
                                                     .width_ratio(3)
-                                                    .widget_type(BottomWidgetType::CpuLegend)
+                                                    .widget_type(legend_type)
                                                     .widget_id(*iter_id)
                                                     .canvas_handle_width(true)
                                                     .parent_reflector(Some((
@@ -73,8 +80,8 @@ impl Row {
                                                     .build(),
                                                 BottomWidget::builder()
                                                     .width_ratio(17)
-                                                    .widget_type(BottomWidgetType::Cpu)
-                                                    .widget_id(cpu_id)
+                                                    .widget_type(widget_type.clone())
+                                                    .widget_id(graph_id)
                                                     .flex_grow(true)
                                                     .build(),
                                             ])
@@ -85,13 +92,13 @@ impl Row {
                                             .children(vec![
                                                 BottomWidget::builder()
                                                     .width_ratio(17)
-                                                    .widget_type(BottomWidgetType::Cpu)
-                                                    .widget_id(cpu_id)
+                                                    .widget_type(widget_type.clone())
+                                                    .widget_id(graph_id)
                                                     .flex_grow(true)
                                                     .build(),
                                                 BottomWidget::builder()
                                                     .width_ratio(3)
-                                                    .widget_type(BottomWidgetType::CpuLegend)
+                                                    .widget_type(legend_type)
                                                     .widget_id(*iter_id)
                                                     .canvas_handle_width(true)
                                                     .parent_reflector(Some((
@@ -188,8 +195,15 @@ impl Row {
                             }
 
                             match widget_type {
-                                BottomWidgetType::Cpu => {
-                                    let cpu_id = *iter_id;
+                                BottomWidgetType::Cpu
+                                | BottomWidgetType::DiskIo
+                                | BottomWidgetType::TempGraph => {
+                                    let graph_id = *iter_id;
+                                    let legend_type = match widget_type {
+                                        BottomWidgetType::Cpu => BottomWidgetType::CpuLegend,
+                                        BottomWidgetType::DiskIo => BottomWidgetType::DiskIoLegend,
+                                        _ => BottomWidgetType::TempGraphLegend,
+                                    };
                                     *iter_id += 1;
                                     if left_legend {
                                         col_row_children.push(
@@ -199,7 +213,7 @@ impl Row {
                                                 .children(vec![
                                                     BottomWidget::builder()
                                                         .width_ratio(3)
-                                                        .widget_type(BottomWidgetType::CpuLegend)
+                                                        .widget_type(legend_type)
                                                         .widget_id(*iter_id)
                                                         .canvas_handle_width(true)
                                                         .parent_reflector(Some((
@@ -209,8 +223,8 @@ impl Row {
                                                         .build(),
                                                     BottomWidget::builder()
                                                         .width_ratio(17)
-                                                        .widget_type(BottomWidgetType::Cpu)
-                                                        .widget_id(cpu_id)
+                                                        .widget_type(widget_type.clone())
+                                                        .widget_id(graph_id)
                                                         .flex_grow(true)
                                                         .build(),
                                                 ])
@@ -224,13 +238,13 @@ impl Row {
                                                 .children(vec![
                                                     BottomWidget::builder()
                                                         .width_ratio(17)
-                                                        .widget_type(BottomWidgetType::Cpu)
-                                                        .widget_id(cpu_id)
+                                                        .widget_type(widget_type.clone())
+                                                        .widget_id(graph_id)
                                                         .flex_grow(true)
                                                         .build(),
                                                     BottomWidget::builder()
                                                         .width_ratio(3)
-                                                        .widget_type(BottomWidgetType::CpuLegend)
+                                                        .widget_type(legend_type)
                                                         .widget_id(*iter_id)
                                                         .canvas_handle_width(true)
                                                         .parent_reflector(Some((
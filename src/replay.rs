@@ -0,0 +1,375 @@
+//! Records harvested data to a file, and replays recorded data as if it was being harvested live.
+//!
+//! Recordings are stored as one JSON object per line, each holding a harvest and how long after the
+//! start of the recording it was made.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::data_harvester::Data,
+    constants::{
+        RECORDING_FLUSH_MILLISECONDS, REPLAY_MAX_SPEED, REPLAY_MIN_SPEED, REPLAY_SEEK_MILLISECONDS,
+        TICK_RATE_IN_MILLISECONDS,
+    },
+    utils::error::{self, BottomError},
+    BottomEvent, ThreadControlEvent,
+};
+
+/// Controls for a running replay.
+#[derive(Debug)]
+pub enum ReplayControl {
+    TogglePause,
+    SpeedUp,
+    SlowDown,
+    SeekForward,
+    SeekBackward,
+}
+
+/// A single recorded harvest.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Frame<D> {
+    /// How long after the start of the recording this harvest was made.
+    pub offset_ms: u64,
+    pub data: D,
+}
+
+/// Appends harvested data to a recording file.
+pub struct Recorder {
+    writer: BufWriter<File>,
+    start_time: Instant,
+    last_flush_time: Instant,
+}
+
+impl Recorder {
+    pub fn new(path: &Path) -> error::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Recorder {
+            writer: BufWriter::new(file),
+            start_time: Instant::now(),
+            last_flush_time: Instant::now(),
+        })
+    }
+
+    pub fn record(&mut self, data: &Data) -> error::Result<()> {
+        let frame = Frame {
+            offset_ms: data
+                .last_collection_time
+                .saturating_duration_since(self.start_time)
+                .as_millis() as u64,
+            data,
+        };
+        serde_json::to_writer(&mut self.writer, &frame)?;
+        writeln!(self.writer)?;
+
+        // Flush every so often so a recording is still mostly usable if bottom is killed.
+        if self.last_flush_time.elapsed() >= Duration::from_millis(RECORDING_FLUSH_MILLISECONDS) {
+            self.writer.flush()?;
+            self.last_flush_time = Instant::now();
+        }
+
+        Ok(())
+    }
+}
+
+/// Records every harvest sent through `receiver` on a separate thread, so writing to the file never holds up
+/// drawing. The thread stops once the sender is dropped, or if writing fails, and flushes the file either way.
+pub fn create_record_thread(
+    mut recorder: Recorder, receiver: mpsc::Receiver<Box<Data>>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        while let Ok(data) = receiver.recv() {
+            if recorder.record(&data).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// Reads all frames from a recording file.
+pub fn read_recording(path: &Path) -> error::Result<Vec<Frame<Data>>> {
+    let lines = BufReader::new(File::open(path)?)
+        .lines()
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let num_lines = lines.len();
+
+    let mut frames: Vec<Frame<Data>> = Vec::with_capacity(num_lines);
+    let mut base_offset = 0;
+    let mut prev_offset = 0;
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<Frame<Data>>(line) {
+            Ok(frame) => {
+                // Recording to an existing file starts the offsets from zero again, so continue on from the
+                // end of the previous recording instead.
+                if frame.offset_ms < prev_offset {
+                    base_offset = frames.last().map(|prev| prev.offset_ms).unwrap_or(0);
+                }
+                prev_offset = frame.offset_ms;
+
+                frames.push(Frame {
+                    offset_ms: base_offset + frame.offset_ms,
+                    data: frame.data,
+                });
+            }
+            // The last line may be cut off if bottom was killed while writing it.
+            Err(_) if index + 1 == num_lines => {}
+            Err(err) => {
+                return Err(BottomError::ConversionError(format!(
+                    "line {} of the recording is invalid: {}",
+                    index + 1,
+                    err
+                )));
+            }
+        }
+    }
+
+    if frames.is_empty() {
+        return Err(BottomError::ConversionError(
+            "the recording has no data".to_string(),
+        ));
+    }
+
+    Ok(frames)
+}
+
+/// The playback state of a replay.
+struct Replay {
+    frames: Vec<Frame<Data>>,
+    /// How many frames have been played so far.
+    position: usize,
+    speed: f64,
+    is_paused: bool,
+}
+
+impl Replay {
+    /// How long to wait before playing the next frame, if there is one.
+    fn time_until_next(&self) -> Option<Duration> {
+        let next = self.frames.get(self.position)?;
+        let gap = match self.position.checked_sub(1) {
+            Some(prev) => next.offset_ms - self.frames[prev].offset_ms,
+            None => 0,
+        };
+
+        Some(Duration::from_millis((gap as f64 / self.speed) as u64))
+    }
+
+    /// The offset of the last played frame.
+    fn current_offset(&self) -> u64 {
+        match self.position.checked_sub(1) {
+            Some(prev) => self.frames[prev].offset_ms,
+            None => 0,
+        }
+    }
+
+    /// Moves the position to the last frame at or before the current offset plus `delta_ms`,
+    /// always keeping at least one frame played.
+    fn seek(&mut self, delta_ms: i64) {
+        let target = (self.current_offset() as i64 + delta_ms).max(0) as u64;
+        self.position = self
+            .frames
+            .partition_point(|frame| frame.offset_ms <= target)
+            .max(1);
+    }
+
+    /// Returns the indices of the played frames that are within `window_ms` of the current offset.
+    fn history(&self, window_ms: u64) -> std::ops::Range<usize> {
+        let start_offset = self.current_offset().saturating_sub(window_ms);
+        let start =
+            self.frames[..self.position].partition_point(|frame| frame.offset_ms < start_offset);
+
+        start..self.position
+    }
+}
+
+/// Replays recorded frames, sending them as [`BottomEvent::Update`]s at the same pace they were recorded.
+///
+/// After seeking, the frames within `history_ms` of the new position are sent at once, with their collection
+/// times set in the past, so graphs still show what led up to that point.
+pub fn create_replay_thread(
+    sender: mpsc::Sender<BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>>,
+    control_receiver: mpsc::Receiver<ThreadControlEvent>, termination_ctrl_lock: Arc<Mutex<bool>>,
+    frames: Vec<Frame<Data>>, history_ms: u64,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut replay = Replay {
+            frames,
+            position: 0,
+            speed: 1.0,
+            is_paused: false,
+        };
+        let mut next_frame_time = Instant::now();
+
+        loop {
+            if let Ok(is_terminated) = termination_ctrl_lock.try_lock() {
+                if *is_terminated {
+                    drop(is_terminated);
+                    break;
+                }
+            }
+
+            let tick = Duration::from_millis(TICK_RATE_IN_MILLISECONDS);
+            let timeout = if replay.is_paused || replay.position == replay.frames.len() {
+                tick
+            } else {
+                next_frame_time
+                    .saturating_duration_since(Instant::now())
+                    .min(tick)
+            };
+
+            match control_receiver.recv_timeout(timeout) {
+                Ok(ThreadControlEvent::Replay(control)) => {
+                    match control {
+                        ReplayControl::TogglePause => replay.is_paused = !replay.is_paused,
+                        ReplayControl::SpeedUp => {
+                            replay.speed = (replay.speed * 2.0).min(REPLAY_MAX_SPEED);
+                        }
+                        ReplayControl::SlowDown => {
+                            replay.speed = (replay.speed / 2.0).max(REPLAY_MIN_SPEED);
+                        }
+                        ReplayControl::SeekForward | ReplayControl::SeekBackward => {
+                            let delta = REPLAY_SEEK_MILLISECONDS as i64;
+                            replay.seek(if let ReplayControl::SeekForward = control {
+                                delta
+                            } else {
+                                -delta
+                            });
+
+                            let now = Instant::now();
+                            let current_offset = replay.current_offset();
+                            for frame in &replay.frames[replay.history(history_ms)] {
+                                let mut data = frame.data.clone();
+                                data.last_collection_time = now
+                                    .checked_sub(Duration::from_millis(
+                                        current_offset - frame.offset_ms,
+                                    ))
+                                    .unwrap_or(now);
+                                if sender.send(BottomEvent::Update(Box::new(data))).is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(wait) = replay.time_until_next() {
+                        next_frame_time = Instant::now() + wait;
+                    }
+                    continue;
+                }
+                Ok(_) | Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            if replay.is_paused || Instant::now() < next_frame_time {
+                continue;
+            }
+
+            if let Some(frame) = replay.frames.get(replay.position) {
+                let mut data = frame.data.clone();
+                data.last_collection_time = Instant::now();
+                if sender.send(BottomEvent::Update(Box::new(data))).is_err() {
+                    break;
+                }
+
+                replay.position += 1;
+                if let Some(wait) = replay.time_until_next() {
+                    next_frame_time = Instant::now() + wait;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::FakeDir;
+
+    fn replay_with_offsets(offsets: &[u64]) -> Replay {
+        Replay {
+            frames: offsets
+                .iter()
+                .map(|&offset_ms| Frame {
+                    offset_ms,
+                    data: Data::default(),
+                })
+                .collect(),
+            position: 0,
+            speed: 1.0,
+            is_paused: false,
+        }
+    }
+
+    #[test]
+    fn test_replay_timing() {
+        let mut replay = replay_with_offsets(&[0, 1000, 3000]);
+        assert_eq!(replay.time_until_next(), Some(Duration::from_millis(0)));
+
+        replay.position = 2;
+        replay.speed = 4.0;
+        assert_eq!(replay.time_until_next(), Some(Duration::from_millis(500)));
+
+        replay.position = 3;
+        assert_eq!(replay.time_until_next(), None);
+    }
+
+    #[test]
+    fn test_replay_seek() {
+        let offsets = (0..60).map(|second| second * 1000).collect::<Vec<_>>();
+        let mut replay = replay_with_offsets(&offsets);
+
+        replay.seek(-10000);
+        assert_eq!(replay.position, 1);
+
+        replay.seek(25500);
+        assert_eq!(replay.current_offset(), 25000);
+        assert_eq!(replay.history(5000), 20..26);
+
+        replay.seek(100000);
+        assert_eq!(replay.position, 60);
+    }
+
+    #[test]
+    fn test_recording_round_trip() {
+        let dir = FakeDir::default();
+        let path = dir.join("recording");
+
+        let mut recorder = Recorder::new(&path).unwrap();
+        let data = Data {
+            load_avg: Some([1.0, 2.0, 3.0]),
+            ..Data::default()
+        };
+        recorder.record(&data).unwrap();
+        recorder.record(&Data::default()).unwrap();
+        drop(recorder);
+
+        // A cut off line at the end should be ignored.
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"offset_ms\":")
+            .unwrap();
+
+        let frames = read_recording(&path).unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].data.load_avg, Some([1.0, 2.0, 3.0]));
+        assert!(frames[1].data.load_avg.is_none());
+    }
+}
@@ -98,6 +98,12 @@ impl From<std::string::FromUtf8Error> for BottomError {
     }
 }
 
+impl From<serde_json::Error> for BottomError {
+    fn from(err: serde_json::Error) -> Self {
+        BottomError::ConversionError(err.to_string())
+    }
+}
+
 impl From<regex::Error> for BottomError {
     fn from(err: regex::Error) -> Self {
         // We only really want the last part of it... so we'll do it the ugly way:
@@ -0,0 +1,34 @@
+//! Helpers shared by tests.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A temporary directory to lay out fake files in, like the ones the kernel exposes under `/sys` and `/proc`. It is
+/// removed when dropped.
+pub struct FakeDir(tempfile::TempDir);
+
+impl Default for FakeDir {
+    fn default() -> Self {
+        FakeDir(tempfile::tempdir().unwrap())
+    }
+}
+
+impl FakeDir {
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    /// The path of `path` inside the directory.
+    pub fn join(&self, path: &str) -> PathBuf {
+        self.path().join(path)
+    }
+
+    /// Writes `contents` to `path` inside the directory, creating any directories along the way.
+    pub fn write(&self, path: &str, contents: &str) {
+        let path = self.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+}
@@ -176,3 +176,28 @@ fn test_battery_flag() {
             ));
     }
 }
+
+#[test]
+fn test_snapshot() {
+    btm_command()
+        .arg("-C")
+        .arg("./tests/empty_config.toml")
+        .arg("--snapshot")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("{").and(predicate::str::contains("\"cpu\":")));
+}
+
+#[test]
+fn test_invalid_metrics_processes() {
+    btm_command()
+        .arg("-C")
+        .arg("./tests/empty_config.toml")
+        .arg("--serve_metrics")
+        .arg("127.0.0.1:0")
+        .arg("--metrics_processes")
+        .arg("abc")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be a non-negative integer"));
+}
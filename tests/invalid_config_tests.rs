@@ -141,3 +141,35 @@ fn test_invalid_default_widget_count() {
         .failure()
         .stderr(predicate::str::contains("invalid number"));
 }
+
+#[test]
+fn test_invalid_keybinding_action() {
+    btm_command()
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_keybinding_action.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid key binding action"));
+}
+
+#[test]
+fn test_conflicting_keybindings() {
+    btm_command()
+        .arg("-C")
+        .arg("./tests/invalid_configs/conflicting_keybindings.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is bound to both"));
+}
+
+#[test]
+fn test_invalid_temp_threshold() {
+    btm_command()
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_temp_threshold.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "is above its critical temperature",
+        ));
+}
@@ -470,3 +470,101 @@ fn test_proc_custom_layout() {
         Some(13)
     );
 }
+
+#[test]
+/// Tests that the network interfaces table can be used in a layout and moved to like any other widget.
+fn test_net_interfaces_layout() {
+    let net_interfaces_layout = r##"
+    [[row]]
+        [[row.child]]
+            type="net"
+    [[row]]
+        [[row.child]]
+            type="interfaces"
+    "##;
+    let rows = toml::from_str::<Config>(net_interfaces_layout)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    let net_widget = &ret_bottom_layout.rows[0].children[0].children[0].children[0];
+    let interfaces_widget = &ret_bottom_layout.rows[1].children[0].children[0].children[0];
+    assert_eq!(
+        interfaces_widget.widget_type,
+        BottomWidgetType::NetInterfaces
+    );
+    assert_eq!(net_widget.down_neighbour, Some(interfaces_widget.widget_id));
+    assert_eq!(interfaces_widget.up_neighbour, Some(net_widget.widget_id));
+}
+
+#[test]
+/// Tests that the disk I/O graph gets a legend next to it, like the CPU graph.
+fn test_disk_io_layout() {
+    let disk_io_layout = r##"
+    [[row]]
+        [[row.child]]
+            type="disk_io"
+    "##;
+    let rows = toml::from_str::<Config>(disk_io_layout)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    let col_row = &ret_bottom_layout.rows[0].children[0].children[0];
+    let graph_widget = &col_row.children[0];
+    let legend_widget = &col_row.children[1];
+    assert_eq!(graph_widget.widget_type, BottomWidgetType::DiskIo);
+    assert_eq!(legend_widget.widget_type, BottomWidgetType::DiskIoLegend);
+    assert_eq!(legend_widget.widget_id, graph_widget.widget_id + 1);
+    assert_eq!(graph_widget.right_neighbour, Some(legend_widget.widget_id));
+    assert_eq!(legend_widget.left_neighbour, Some(graph_widget.widget_id));
+}
+
+#[test]
+/// Tests that the temperature graph gets a legend next to it, and that the legend goes on the left if asked.
+fn test_temp_graph_layout() {
+    let temp_graph_layout = r##"
+    [[row]]
+        [[row.child]]
+            type="temp_graph"
+    "##;
+    let rows = toml::from_str::<Config>(temp_graph_layout)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, true);
+
+    let col_row = &ret_bottom_layout.rows[0].children[0].children[0];
+    let legend_widget = &col_row.children[0];
+    let graph_widget = &col_row.children[1];
+    assert_eq!(legend_widget.widget_type, BottomWidgetType::TempGraphLegend);
+    assert_eq!(graph_widget.widget_type, BottomWidgetType::TempGraph);
+    assert_eq!(legend_widget.widget_id, graph_widget.widget_id + 1);
+    assert_eq!(graph_widget.left_neighbour, Some(legend_widget.widget_id));
+}
+
+#[test]
+/// Tests that the cgroups table can be used in a layout and moved to like any other widget.
+fn test_cgroups_layout() {
+    let cgroups_layout = r##"
+    [[row]]
+        [[row.child]]
+            type="cpu"
+    [[row]]
+        [[row.child]]
+            type="cgroups"
+    "##;
+    let rows = toml::from_str::<Config>(cgroups_layout)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    let cpu_widget = &ret_bottom_layout.rows[0].children[0].children[0].children[0];
+    let cgroups_widget = &ret_bottom_layout.rows[1].children[0].children[0].children[0];
+    assert_eq!(cgroups_widget.widget_type, BottomWidgetType::Cgroups);
+    assert_eq!(cpu_widget.down_neighbour, Some(cgroups_widget.widget_id));
+    assert_eq!(cgroups_widget.up_neighbour, Some(cpu_widget.widget_id));
+}